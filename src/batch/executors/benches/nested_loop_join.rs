@@ -55,6 +55,8 @@ fn create_nested_loop_join_executor(
         "NestedLoopJoinExecutor".into(),
         CHUNK_SIZE,
         MemoryContext::none(),
+        false,
+        0,
         ShutdownToken::empty(),
     ))
 }
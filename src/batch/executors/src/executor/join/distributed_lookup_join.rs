@@ -170,6 +170,9 @@ impl BoxedExecutorBuilder for DistributedLookupJoinExecutorBuilder {
         let lookup_prefix_len: usize =
             distributed_lookup_join_node.get_lookup_prefix_len() as usize;
 
+        let lookup_batch_size: usize =
+            distributed_lookup_join_node.get_lookup_batch_size() as usize;
+
         let mut inner_side_key_idxs = vec![];
         for inner_side_key in distributed_lookup_join_node.get_inner_side_key() {
             inner_side_key_idxs.push(*inner_side_key as usize)
@@ -223,6 +226,7 @@ impl BoxedExecutorBuilder for DistributedLookupJoinExecutorBuilder {
                 inner_side_key_idxs,
                 null_safe,
                 lookup_prefix_len,
+                lookup_batch_size,
                 chunk_builder: DataChunkBuilder::new(original_schema.data_types(), chunk_size),
                 schema: actual_schema,
                 output_indices,
@@ -248,6 +252,7 @@ struct DistributedLookupJoinExecutorArgs<S: StateStore> {
     inner_side_key_idxs: Vec<usize>,
     null_safe: Vec<bool>,
     lookup_prefix_len: usize,
+    lookup_batch_size: usize,
     chunk_builder: DataChunkBuilder,
     schema: Schema,
     output_indices: Vec<usize>,
@@ -273,6 +278,7 @@ impl<S: StateStore> HashKeyDispatcher for DistributedLookupJoinExecutorArgs<S> {
             inner_side_key_idxs: self.inner_side_key_idxs,
             null_safe: self.null_safe,
             lookup_prefix_len: self.lookup_prefix_len,
+            lookup_batch_size: self.lookup_batch_size,
             chunk_builder: self.chunk_builder,
             schema: self.schema,
             output_indices: self.output_indices,
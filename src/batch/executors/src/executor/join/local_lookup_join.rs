@@ -373,6 +373,8 @@ impl BoxedExecutorBuilder for LocalLookupJoinExecutorBuilder {
 
         let lookup_prefix_len: usize = lookup_join_node.get_lookup_prefix_len() as usize;
 
+        let lookup_batch_size: usize = lookup_join_node.get_lookup_batch_size() as usize;
+
         let mut inner_side_key_idxs = vec![];
         for inner_side_key in lookup_join_node.get_inner_side_key() {
             inner_side_key_idxs.push(*inner_side_key as usize)
@@ -444,6 +446,7 @@ impl BoxedExecutorBuilder for LocalLookupJoinExecutorBuilder {
             inner_side_key_idxs,
             null_safe,
             lookup_prefix_len,
+            lookup_batch_size,
             chunk_builder: DataChunkBuilder::new(original_schema.data_types(), chunk_size),
             schema: actual_schema,
             output_indices,
@@ -468,6 +471,7 @@ struct LocalLookupJoinExecutorArgs<B: LookupExecutorBuilder> {
     inner_side_key_idxs: Vec<usize>,
     null_safe: Vec<bool>,
     lookup_prefix_len: usize,
+    lookup_batch_size: usize,
     chunk_builder: DataChunkBuilder,
     schema: Schema,
     output_indices: Vec<usize>,
@@ -493,6 +497,7 @@ impl<B: LookupExecutorBuilder> HashKeyDispatcher for LocalLookupJoinExecutorArgs
             inner_side_key_idxs: self.inner_side_key_idxs,
             null_safe: self.null_safe,
             lookup_prefix_len: self.lookup_prefix_len,
+            lookup_batch_size: self.lookup_batch_size,
             chunk_builder: self.chunk_builder,
             schema: self.schema,
             output_indices: self.output_indices,
@@ -597,6 +602,7 @@ mod tests {
             inner_side_key_idxs: vec![0],
             null_safe: vec![null_safe],
             lookup_prefix_len: 1,
+            lookup_batch_size: 0,
             chunk_builder: DataChunkBuilder::new(original_schema.data_types(), CHUNK_SIZE),
             schema: original_schema.clone(),
             output_indices: (0..original_schema.len()).collect(),
@@ -51,6 +51,10 @@ pub struct LookupJoinBase<K, B: LookupExecutorBuilder> {
     pub inner_side_key_idxs: Vec<usize>,
     pub null_safe: Vec<bool>,
     pub lookup_prefix_len: usize,
+    /// The number of outer side rows to accumulate keys from before issuing a batched lookup
+    /// into the inner side table. Falls back to [`AT_LEAST_OUTER_SIDE_ROWS`] when `0` (e.g. a
+    /// plan produced before this field existed).
+    pub lookup_batch_size: usize,
     pub chunk_builder: DataChunkBuilder,
     pub schema: Schema,
     pub output_indices: Vec<usize>,
@@ -62,6 +66,7 @@ pub struct LookupJoinBase<K, B: LookupExecutorBuilder> {
     pub _phantom: PhantomData<K>,
 }
 
+/// Fallback outer-side batch size, used when [`LookupJoinBase::lookup_batch_size`] is `0`.
 const AT_LEAST_OUTER_SIDE_ROWS: usize = 512;
 
 impl<K: HashKey, B: LookupExecutorBuilder> LookupJoinBase<K, B> {
@@ -77,8 +82,13 @@ impl<K: HashKey, B: LookupExecutorBuilder> LookupJoinBase<K, B> {
 
         let null_matched = K::Bitmap::from_bool_vec(self.null_safe);
 
+        let lookup_batch_size = if self.lookup_batch_size == 0 {
+            AT_LEAST_OUTER_SIDE_ROWS
+        } else {
+            self.lookup_batch_size
+        };
         let mut outer_side_batch_read_stream: BoxedDataChunkListStream =
-            utils::batch_read(self.outer_side_input.execute(), AT_LEAST_OUTER_SIDE_ROWS);
+            utils::batch_read(self.outer_side_input.execute(), lookup_batch_size);
 
         while let Some(chunk_list) = outer_side_batch_read_stream.next().await {
             let chunk_list = chunk_list?;
@@ -66,6 +66,17 @@ pub struct NestedLoopJoinExecutor {
     /// Memory context used for recording memory usage of executor.
     mem_context: MemoryContext,
 
+    /// Whether the plan enables spilling of the build (left) side.
+    ///
+    /// This executor does not spill. Unlike `HashJoinExecutor`, the build side cannot be
+    /// partitioned by key, so every outer row needs the whole build side available. Exceeding
+    /// the memory context's limit fails the query with `BatchError::OutOfMemory`, regardless of
+    /// `allow_spill`.
+    allow_spill: bool,
+    /// The memory limit, in bytes, for the build side. When `allow_spill` is set and the cached
+    /// build side grows past it, only a trace is logged.
+    memory_limit_bytes: u64,
+
     shutdown_rx: ShutdownToken,
 }
 
@@ -94,10 +105,19 @@ impl NestedLoopJoinExecutor {
         // Cache the outputs of left child
         let left: Vec<DataChunk> = {
             let mut ret = Vec::with_capacity(1024);
+            let mut cached_bytes = 0u64;
             #[for_await]
             for chunk in self.left_child.execute() {
                 let c = chunk?;
                 trace!("Estimated chunk size is {:?}", c.estimated_heap_size());
+                cached_bytes += c.estimated_heap_size() as u64;
+                if self.allow_spill && cached_bytes > self.memory_limit_bytes {
+                    trace!(
+                        "Build side of nested loop join exceeded memory_limit_bytes ({} > {}), \
+                         but spilling to the object store is not yet implemented",
+                        cached_bytes, self.memory_limit_bytes
+                    );
+                }
                 if !self.mem_context.add(c.estimated_heap_size() as i64) {
                     Err(BatchError::OutOfMemory(self.mem_context.mem_limit()))?;
                 }
@@ -189,6 +209,8 @@ impl BoxedExecutorBuilder for NestedLoopJoinExecutor {
             identity,
             source.context().get_config().developer.chunk_size,
             mem_context,
+            nested_loop_join_node.allow_spill,
+            nested_loop_join_node.memory_limit_bytes,
             source.shutdown_rx().clone(),
         )))
     }
@@ -205,6 +227,8 @@ impl NestedLoopJoinExecutor {
         identity: String,
         chunk_size: usize,
         mem_context: MemoryContext,
+        allow_spill: bool,
+        memory_limit_bytes: u64,
         shutdown_rx: ShutdownToken,
     ) -> Self {
         // TODO(Bowen): Merge this with derive schema in Logical Join (#790).
@@ -236,6 +260,8 @@ impl NestedLoopJoinExecutor {
             identity,
             chunk_size,
             mem_context,
+            allow_spill,
+            memory_limit_bytes,
             shutdown_rx,
         }
     }
@@ -639,6 +665,8 @@ mod tests {
                 "NestedLoopJoinExecutor".into(),
                 CHUNK_SIZE,
                 MemoryContext::none(),
+                false,
+                0,
                 shutdown_rx,
             ))
         }
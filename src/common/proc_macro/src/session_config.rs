@@ -39,6 +39,7 @@ pub(crate) fn derive_config(input: DeriveInput) -> TokenStream {
     let mut set_match_branches = vec![];
     let mut get_match_branches = vec![];
     let mut reset_match_branches = vec![];
+    let mut describe_match_branches = vec![];
     let mut show_all_list = vec![];
     let mut list_all_list = vec![];
     let mut alias_to_entry_name_branches = vec![];
@@ -226,6 +227,10 @@ pub(crate) fn derive_config(input: DeriveInput) -> TokenStream {
             #entry_name => Ok(self.#reset_func_name(reporter)),
         });
 
+        describe_match_branches.push(quote! {
+            #entry_name => #description,
+        });
+
         let var_info = quote! {
             VariableInfo {
                 name: #entry_name.to_string(),
@@ -308,6 +313,17 @@ pub(crate) fn derive_config(input: DeriveInput) -> TokenStream {
                 }
             }
 
+            /// Describe a parameter by it's name. Returns an empty string for unknown or
+            /// experimental parameters instead of failing, since this is only used to populate
+            /// an informational column (e.g. `SHOW ALL`'s `Description`).
+            pub fn describe(&self, key_name: &str) -> &'static str {
+                let key_name = Self::alias_to_entry_name(key_name);
+                match key_name.as_ref() {
+                    #(#describe_match_branches)*
+                    _ => "",
+                }
+            }
+
             /// Reset a parameter by it's name.
             pub fn reset(&mut self, key_name: &str, reporter: &mut impl ConfigReporter) -> SessionConfigResult<String> {
                 let key_name = Self::alias_to_entry_name(key_name);
@@ -61,6 +61,18 @@ pub struct BatchConfig {
     /// Enable the spill out to disk feature for batch queries.
     #[serde(default = "default::batch::enable_spill")]
     pub enable_spill: bool,
+
+    /// The memory limit, in bytes, for the build side of a `BatchNestedLoopJoin`, passed to the
+    /// executor together with `enable_spill`. The executor does not spill the build side: it
+    /// keeps buffering it in memory and fails with an out-of-memory error once the memory
+    /// context's limit is exceeded.
+    #[serde(default = "default::batch::nested_loop_join_memory_limit_bytes")]
+    pub nested_loop_join_memory_limit_bytes: u64,
+
+    /// The number of probe keys a `BatchLookupJoin` accumulates before issuing a batched lookup
+    /// into the inner table, amortizing round trips to the storage layer.
+    #[serde(default = "default::batch::lookup_join_batch_size")]
+    pub lookup_join_batch_size: u32,
 }
 
 serde_with::with_prefix!(batch_prefix "batch_");
@@ -116,11 +128,20 @@ pub mod default {
             true
         }
 
+        pub fn nested_loop_join_memory_limit_bytes() -> u64 {
+            // 256 MiB
+            256 * 1024 * 1024
+        }
+
         pub fn statement_timeout_in_sec() -> u32 {
             // 1 hour
             60 * 60
         }
 
+        pub fn lookup_join_batch_size() -> u32 {
+            1024
+        }
+
         pub fn mask_worker_temporary_secs() -> usize {
             30
         }
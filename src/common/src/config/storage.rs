@@ -111,6 +111,12 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::min_sstable_size_mb")]
     pub min_sstable_size_mb: u32,
 
+    /// Number of unique keys in an SST above which its bloom filter is built as a 16-bit xor
+    /// filter instead of an 8-bit one, trading filter size for a lower false positive rate on
+    /// large SSTs.
+    #[serde(default = "default::storage::xor16_kv_count_threshold")]
+    pub xor16_kv_count_threshold: usize,
+
     #[serde(default)]
     #[config_doc(nested)]
     pub data_file_cache: FileCacheConfig,
@@ -912,6 +918,10 @@ pub mod default {
             32
         }
 
+        pub fn xor16_kv_count_threshold() -> usize {
+            128 * 1024
+        }
+
         pub fn min_sst_size_for_streaming_upload() -> u64 {
             // 32MB
             32 * 1024 * 1024
@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::BuildHasher;
+
 use itertools::Itertools;
 use parse_display::Display;
 
 use crate::array::{Array, ArrayImpl, DataChunk};
+use crate::bitmap::Bitmap;
 use crate::hash::Crc32HashCode;
 use crate::row::{Row, RowExt};
 use crate::types::{DataType, Datum, DatumRef, ScalarImpl, ScalarRefImpl};
-use crate::util::hash_util::Crc32FastBuilder;
+use crate::util::hash_util::{Crc32FastBuilder, finalize_hashers};
 use crate::util::row_id::compute_vnode_from_row_id;
 
 /// `VirtualNode` (a.k.a. Vnode) is a minimal partition that a set of keys belong to. It is used for
@@ -186,6 +189,28 @@ impl VirtualNode {
         Self::compute_chunk(data_chunk, keys, Self::COUNT_FOR_TEST)
     }
 
+    /// Fast path of [`Self::compute_chunk`] for a distribution key made of a single non-serial
+    /// column, given directly as the concrete `array`. Skips going through
+    /// [`DataChunk::get_hash_values`]'s generic per-column loop and hashes straight from the
+    /// array, which lets the caller avoid downcasting through [`ArrayImpl`] on every row.
+    ///
+    /// Output is bit-identical to [`Self::compute_chunk`] called with `keys` pointing at the same
+    /// single column, including for invisible rows.
+    pub fn compute_chunk_single<A: Array>(
+        array: &A,
+        vis: &Bitmap,
+        vnode_count: usize,
+    ) -> Vec<VirtualNode> {
+        let mut hashers: Vec<_> = (0..array.len())
+            .map(|_| Crc32FastBuilder.build_hasher())
+            .collect();
+        array.hash_vec(&mut hashers, vis);
+        finalize_hashers(&hashers)
+            .into_iter()
+            .map(|hash_code| Crc32HashCode::from(hash_code).to_vnode(vnode_count))
+            .collect()
+    }
+
     // `compute_row` is used to calculate the `VirtualNode` for the corresponding columns in a
     // `Row`. Similar to `compute_chunk`, it also contains special handling for serial columns.
     pub fn compute_row(row: impl Row, indices: &[usize], vnode_count: usize) -> VirtualNode {
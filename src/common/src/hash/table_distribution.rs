@@ -18,8 +18,8 @@ use std::sync::Arc;
 use itertools::Itertools;
 use risingwave_pb::plan_common::StorageTableDesc;
 
-use crate::array::{Array, DataChunk, PrimitiveArray};
-use crate::bitmap::Bitmap;
+use crate::array::{Array, ArrayImpl, DataChunk, PrimitiveArray};
+use crate::bitmap::{Bitmap, BitmapBuilder};
 use crate::hash::{VirtualNode, VnodeCountCompat};
 use crate::row::Row;
 use crate::util::iter_util::ZipEqFast;
@@ -118,6 +118,40 @@ impl TableDistribution {
         }
     }
 
+    /// Distribution that owns the given contiguous, inclusive vnode `ranges`, mainly used by
+    /// tests and tooling that model a worker owning a contiguous slice of vnodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is out of bounds of [`VirtualNode::COUNT_FOR_TEST`], or if any two
+    /// ranges overlap.
+    pub fn from_vnode_ranges(
+        dist_key_in_pk_indices: Vec<usize>,
+        ranges: &[(usize, usize)],
+    ) -> Self {
+        let mut builder = BitmapBuilder::zeroed(VirtualNode::COUNT_FOR_TEST);
+        for &(start, end) in ranges {
+            assert!(
+                start <= end && end < VirtualNode::COUNT_FOR_TEST,
+                "vnode range ({start}, {end}) out of bounds of vnode count {}",
+                VirtualNode::COUNT_FOR_TEST
+            );
+            for vnode in start..=end {
+                assert!(
+                    !builder.is_set(vnode),
+                    "vnode range ({start}, {end}) overlaps with another range at vnode {vnode}"
+                );
+                builder.set(vnode, true);
+            }
+        }
+        Self {
+            compute_vnode: ComputeVnode::DistKeyIndices {
+                vnodes: builder.finish().into(),
+                dist_key_in_pk_indices,
+            },
+        }
+    }
+
     pub fn update_vnode_bitmap(&mut self, new_vnodes: Arc<Bitmap>) -> Arc<Bitmap> {
         match &mut self.compute_vnode {
             ComputeVnode::Singleton => {
@@ -167,6 +201,17 @@ impl TableDistribution {
         }
     }
 
+    /// Get the vnodes that could contain rows matching the given pk prefix, for planning a batch
+    /// scan. If `pk_prefix` fully covers the distribution key, this is exactly the single vnode
+    /// computed from it; otherwise every vnode owned by this distribution is returned, as the
+    /// missing part of the distribution key could hash to any of them.
+    pub fn vnodes_for_pk_prefix(&self, pk_prefix: impl Row) -> Vec<VirtualNode> {
+        match self.try_compute_vnode_by_pk_prefix(pk_prefix) {
+            Some(vnode) => vec![vnode],
+            None => self.vnodes().iter_vnodes().collect(),
+        }
+    }
+
     pub fn try_compute_vnode_by_pk_prefix(&self, pk_prefix: impl Row) -> Option<VirtualNode> {
         match &self.compute_vnode {
             ComputeVnode::Singleton => Some(SINGLETON_VNODE),
@@ -229,7 +274,17 @@ impl TableDistribution {
                     .map(|idx| pk_indices[*idx])
                     .collect_vec();
 
-                VirtualNode::compute_chunk(chunk, &dist_key_indices, vnodes.len())
+                let vnode_of_rows = if let Ok(idx) = dist_key_indices.iter().exactly_one()
+                    && let ArrayImpl::Int64(array) = &**chunk.column_at(*idx)
+                {
+                    // Fast path for a single `i64` distribution key column: hash straight from
+                    // the concrete array instead of going through the general per-column loop.
+                    VirtualNode::compute_chunk_single(array, chunk.visibility(), vnodes.len())
+                } else {
+                    VirtualNode::compute_chunk(chunk, &dist_key_indices, vnodes.len())
+                };
+
+                vnode_of_rows
                     .into_iter()
                     .zip_eq_fast(chunk.visibility().iter())
                     .map(|(vnode, vis)| {
@@ -279,3 +334,80 @@ fn check_vnode_is_set(vnode: VirtualNode, vnodes: &Bitmap) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_vnode_ranges() {
+        let dist = TableDistribution::from_vnode_ranges(vec![0], &[(0, 15), (32, 47)]);
+        let vnodes = dist.vnodes();
+
+        for vnode in 0..VirtualNode::COUNT_FOR_TEST {
+            let expected = (0..=15).contains(&vnode) || (32..=47).contains(&vnode);
+            assert_eq!(
+                vnodes.is_set(vnode),
+                expected,
+                "vnode {vnode} set mismatch"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "overlaps")]
+    fn test_from_vnode_ranges_overlap_panics() {
+        TableDistribution::from_vnode_ranges(vec![0], &[(0, 15), (10, 20)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_from_vnode_ranges_out_of_bounds_panics() {
+        TableDistribution::from_vnode_ranges(vec![0], &[(0, VirtualNode::COUNT_FOR_TEST)]);
+    }
+
+    #[test]
+    fn test_vnodes_for_pk_prefix_fully_covered() {
+        use crate::row::OwnedRow;
+        use crate::types::ScalarImpl;
+
+        let dist = TableDistribution::all(vec![0], VirtualNode::COUNT_FOR_TEST);
+        let pk_prefix = OwnedRow::new(vec![Some(ScalarImpl::Int64(233))]);
+
+        let expected = dist.compute_vnode_by_pk(&pk_prefix);
+        assert_eq!(dist.vnodes_for_pk_prefix(&pk_prefix), vec![expected]);
+    }
+
+    #[test]
+    fn test_vnodes_for_pk_prefix_partial_covers_all_owned_vnodes() {
+        use crate::row::OwnedRow;
+
+        let dist = TableDistribution::from_vnode_ranges(vec![1], &[(0, 15), (32, 47)]);
+
+        let all_owned = dist.vnodes().iter_vnodes().collect_vec();
+        assert_eq!(dist.vnodes_for_pk_prefix(OwnedRow::new(vec![])), all_owned);
+    }
+
+    #[test]
+    fn test_compute_chunk_vnode_single_i64_dist_key_matches_general_path() {
+        use crate::array::DataChunkTestExt;
+
+        let chunk = DataChunk::from_pretty(
+            "I
+             1
+             2 D
+             .
+             233",
+        );
+        let vnode_count = VirtualNode::COUNT_FOR_TEST;
+
+        let fast_path = VirtualNode::compute_chunk_single(
+            chunk.column_at(0).as_int64(),
+            chunk.visibility(),
+            vnode_count,
+        );
+        let general_path = VirtualNode::compute_chunk(&chunk, &[0], vnode_count);
+
+        assert_eq!(fast_path, general_path);
+    }
+}
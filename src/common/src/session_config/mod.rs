@@ -539,4 +539,11 @@ mod test {
         assert_eq!(config.get("test_param_alias").unwrap(), "3");
         assert!(TestConfig::check_no_alter_sys("test_param").unwrap());
     }
+
+    #[test]
+    fn test_describe_known_and_unknown_variable() {
+        let config = SessionConfig::default();
+        assert!(!config.describe("application_name").is_empty());
+        assert_eq!(config.describe("not_a_real_variable"), "");
+    }
 }
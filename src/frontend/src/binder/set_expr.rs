@@ -15,7 +15,6 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
-use risingwave_common::bail_not_implemented;
 use risingwave_common::catalog::Schema;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_common::util::iter_util::ZipEqFast;
@@ -349,15 +348,6 @@ impl Binder {
                             None
                         };
 
-                        if *all {
-                            match op {
-                                SetOperator::Union => {}
-                                SetOperator::Intersect | SetOperator::Except => {
-                                    bail_not_implemented!("{} all", op);
-                                }
-                            }
-                        }
-
                         // Reset context for the set operation.
                         // Consider this case:
                         // select a from t2 union all select b from t2 order by a+1; should throw an
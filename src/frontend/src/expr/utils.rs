@@ -19,18 +19,26 @@ use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_pb::expr::expr_node::Type;
 
 use super::now::RewriteNowToProcTime;
+use super::pure::is_impure;
 use super::{Expr, ExprImpl, ExprRewriter, ExprVisitor, FunctionCall, InputRef};
 use crate::expr::ExprType;
 
+/// Iterative (stack-based) equivalent of a depth-first recursive split, so that a deeply nested
+/// AND/OR tree (e.g. generated by some ORMs) doesn't overflow the call stack. Uses an explicit
+/// work stack instead of recursion; children are pushed in reverse order so they're popped and
+/// visited left-to-right, matching the order a recursive pre-order traversal would produce.
 fn split_expr_by(expr: ExprImpl, op: ExprType, rets: &mut Vec<ExprImpl>) {
-    match expr {
-        ExprImpl::FunctionCall(func_call) if func_call.func_type() == op => {
-            let (_, exprs, _) = func_call.decompose();
-            for expr in exprs {
-                split_expr_by(expr, op, rets);
+    let mut stack = vec![expr];
+    while let Some(expr) = stack.pop() {
+        match expr {
+            ExprImpl::FunctionCall(func_call) if func_call.func_type() == op => {
+                let (_, exprs, _) = func_call.decompose();
+                for expr in exprs.into_iter().rev() {
+                    stack.push(expr);
+                }
             }
+            _ => rets.push(expr),
         }
-        _ => rets.push(expr),
     }
 }
 
@@ -162,6 +170,108 @@ impl ColumnSelfEqualRewriter {
     }
 }
 
+/// Above this many disjuncts, an `OR`-chain of equalities against the same expression is folded
+/// into a single hashed set-membership check by [`merge_eq_to_in`] instead of being left as a
+/// chain of comparisons.
+const IN_LIST_REWRITE_THRESHOLD: usize = 20;
+
+/// Rewrite a long `OR`-chain of `expr = const` comparisons against the same `expr` into a single
+/// `expr IN (const, ...)`, the same shape the binder already produces for a literal `IN` list
+/// (see `Binder::bind_in_list`). This matters because `In` is evaluated against a hashed
+/// `HashSet` (see `InExpression` in `risingwave_expr_impl::scalar::in_`), while a chain of `OR`s
+/// is evaluated as a linear scan of comparisons, which gets expensive for lists some query
+/// builders generate with thousands of elements.
+///
+/// SQL `IN`'s null semantics fall out of the rewrite for free: `expr = NULL` is a legal disjunct
+/// (e.g. coming from `expr IN (.., NULL)`) and is simply carried over as a `NULL` entry in the
+/// hashed set, exactly what `InExpression` expects; a `NULL` `expr` at evaluation time still
+/// short-circuits to `NULL` in both the original chain and the rewritten form.
+pub fn merge_eq_to_in(expr: ExprImpl) -> ExprImpl {
+    InListMerger.rewrite_expr(expr)
+}
+
+struct InListMerger;
+
+impl InListMerger {
+    /// Returns `Some` with the merged `expr IN (..)` if `expr` is a long enough, homogeneous
+    /// `OR`-chain of `<same expr> = <const>`, `None` otherwise (left for the caller to recurse
+    /// into as a regular expression instead).
+    fn try_merge_or_chain(expr: &ExprImpl) -> Option<ExprImpl> {
+        let disjuncts = to_disjunctions(expr.clone());
+        if disjuncts.len() <= IN_LIST_REWRITE_THRESHOLD {
+            return None;
+        }
+
+        let mut left: Option<&ExprImpl> = None;
+        let mut values = Vec::with_capacity(disjuncts.len());
+        for disjunct in &disjuncts {
+            let ExprImpl::FunctionCall(func_call) = disjunct else {
+                return None;
+            };
+            if func_call.func_type() != ExprType::Equal {
+                return None;
+            }
+            let inputs = func_call.inputs();
+            if inputs.len() != 2 {
+                return None;
+            }
+            let (col, val) = match (inputs[0].is_const(), inputs[1].is_const()) {
+                (false, true) => (&inputs[0], &inputs[1]),
+                (true, false) => (&inputs[1], &inputs[0]),
+                // both sides constant, or neither is: not a `col = const` comparison we can fold
+                _ => return None,
+            };
+            match left {
+                None => left = Some(col),
+                Some(existing) if existing != col => return None,
+                _ => {}
+            }
+            values.push(val.clone());
+        }
+        let left = left?.clone();
+        if is_impure(&left) {
+            // `left` is evaluated once per disjunct in the `OR`-chain (short-circuiting on the
+            // first match) but exactly once in the merged `IN`; folding it would change how many
+            // times a volatile expression (e.g. `random()`) is evaluated.
+            return None;
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        let mut args = Vec::with_capacity(values.len() + 1);
+        args.push(left);
+        for value in values {
+            if seen.insert(value.clone()) {
+                args.push(value);
+            }
+        }
+
+        FunctionCall::new(ExprType::In, args).ok().map(Into::into)
+    }
+}
+
+impl ExprRewriter for InListMerger {
+    fn rewrite_function_call(&mut self, func_call: FunctionCall) -> ExprImpl {
+        if func_call.func_type() != ExprType::Or {
+            let (func_type, inputs, ret) = func_call.decompose();
+            let inputs = inputs.into_iter().map(|e| self.rewrite_expr(e)).collect();
+            return FunctionCall::new_unchecked(func_type, inputs, ret).into();
+        }
+
+        // Try to merge the whole chain top-down, before any nested `OR` inside it gets rewritten
+        // (and potentially fragmented into several smaller chains) by the recursive case below.
+        let expr: ExprImpl = func_call.into();
+        if let Some(merged) = Self::try_merge_or_chain(&expr) {
+            return merged;
+        }
+        let ExprImpl::FunctionCall(func_call) = expr else {
+            unreachable!("`expr` was just constructed from a `FunctionCall`")
+        };
+        let (func_type, inputs, ret) = func_call.decompose();
+        let inputs = inputs.into_iter().map(|e| self.rewrite_expr(e)).collect();
+        FunctionCall::new_unchecked(func_type, inputs, ret).into()
+    }
+}
+
 /// Fold boolean constants in a expr
 struct BooleanConstantFolding {}
 
@@ -248,6 +358,20 @@ impl ExprRewriter for BooleanConstantFolding {
                     return ExprImpl::literal_bool(lit.get_data().is_some());
                 }
             }
+            // `now()` is evaluated once per statement and is never null, so comparing it against
+            // itself is a tautology regardless of when the statement runs. This only matches when
+            // both sides are syntactically `now()`; a predicate like `ts > now() - interval` still
+            // depends on a column and is left untouched.
+            Type::Equal | Type::GreaterThanOrEqual | Type::LessThanOrEqual
+                if matches!(inputs.as_slice(), [ExprImpl::Now(_), ExprImpl::Now(_)]) =>
+            {
+                return ExprImpl::literal_bool(true);
+            }
+            Type::NotEqual | Type::GreaterThan | Type::LessThan
+                if matches!(inputs.as_slice(), [ExprImpl::Now(_), ExprImpl::Now(_)]) =>
+            {
+                return ExprImpl::literal_bool(false);
+            }
             // binary functions
             Type::And if contains_bool_constant => {
                 let (constant_lhs, rhs) = prepare_binary_function_inputs(inputs);
@@ -512,11 +636,11 @@ pub fn rewrite_now_to_proctime(expr: ExprImpl) -> ExprImpl {
 
 #[cfg(test)]
 mod tests {
-    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_common::types::{DataType, Interval, ScalarImpl};
     use risingwave_pb::expr::expr_node::Type;
 
     use super::{fold_boolean_constant, push_down_not};
-    use crate::expr::{ExprImpl, FunctionCall, InputRef};
+    use crate::expr::{ExprImpl, FunctionCall, InputRef, Literal, Now};
 
     #[test]
     fn constant_boolean_folding_basic_and() {
@@ -628,6 +752,55 @@ mod tests {
         assert_eq!(*res.get_data(), Some(ScalarImpl::Bool(false)));
     }
 
+    #[test]
+    fn constant_boolean_folding_now_tautology() {
+        // expr := now() >= now(), a tautology since `now()` is evaluated once per statement and
+        // is never null.
+        let expr: ExprImpl =
+            FunctionCall::new(Type::GreaterThanOrEqual, vec![Now.into(), Now.into()])
+                .unwrap()
+                .into();
+
+        let res = fold_boolean_constant(expr);
+
+        assert!(res.as_literal().is_some());
+        let res = res.as_literal().unwrap();
+        assert_eq!(*res.get_data(), Some(ScalarImpl::Bool(true)));
+    }
+
+    #[test]
+    fn constant_boolean_folding_preserves_temporal_predicate() {
+        // expr := ts > now() - interval '1' hour, a genuinely time-dependent predicate that must
+        // not be folded away.
+        let now_minus_interval: ExprImpl = FunctionCall::new(
+            Type::Subtract,
+            vec![
+                Now.into(),
+                Literal::new(
+                    Some(Interval::from_month_day_usec(0, 0, 60 * 60 * 1_000_000).into()),
+                    DataType::Interval,
+                )
+                .into(),
+            ],
+        )
+        .unwrap()
+        .into();
+        let expr: ExprImpl = FunctionCall::new(
+            Type::GreaterThan,
+            vec![
+                InputRef::new(0, DataType::Timestamptz).into(),
+                now_minus_interval,
+            ],
+        )
+        .unwrap()
+        .into();
+
+        let res = fold_boolean_constant(expr.clone());
+
+        // Neither side is a bare `now()`, so the comparison must be left untouched.
+        assert_eq!(res, expr);
+    }
+
     #[test]
     fn not_push_down_test() {
         // Not(Not(A))
@@ -708,4 +881,162 @@ mod tests {
         assert_eq!(rhs_type, Type::Not);
         assert!(rhs_input.as_input_ref().is_some());
     }
+
+    #[test]
+    fn to_conjunctions_handles_deeply_nested_and_without_overflow() {
+        use super::to_conjunctions;
+
+        // Build a left-deep chain `((((c0 AND c1) AND c2) AND c3) ...) AND c49999`, which is the
+        // shape some ORMs generate for a long list of ANDed predicates.
+        const N: usize = 50_000;
+        let mut expr: ExprImpl = InputRef::new(0, DataType::Boolean).into();
+        for i in 1..N {
+            expr = FunctionCall::new(Type::And, vec![expr, InputRef::new(i, DataType::Boolean).into()])
+                .unwrap()
+                .into();
+        }
+
+        let leaves = to_conjunctions(expr);
+
+        assert_eq!(leaves.len(), N);
+        for (i, leaf) in leaves.into_iter().enumerate() {
+            assert_eq!(leaf.as_input_ref().unwrap().index(), i);
+        }
+    }
+
+    /// Builds `col = values[0] OR col = values[1] OR ...` as a left-deep chain, the shape
+    /// `to_disjunctions`/binders typically see.
+    fn or_of_equalities(col: ExprImpl, values: impl IntoIterator<Item = ExprImpl>) -> ExprImpl {
+        let mut values = values.into_iter();
+        let mut expr: ExprImpl =
+            FunctionCall::new(Type::Equal, vec![col.clone(), values.next().unwrap()])
+                .unwrap()
+                .into();
+        for value in values {
+            let eq: ExprImpl = FunctionCall::new(Type::Equal, vec![col.clone(), value])
+                .unwrap()
+                .into();
+            expr = FunctionCall::new(Type::Or, vec![expr, eq]).unwrap().into();
+        }
+        expr
+    }
+
+    #[test]
+    fn merge_eq_to_in_rewrites_large_or_chain() {
+        use super::merge_eq_to_in;
+
+        const N: usize = 2000;
+        let col: ExprImpl = InputRef::new(0, DataType::Int32).into();
+        let expr = or_of_equalities(
+            col,
+            (0..N as i32).map(ExprImpl::literal_int),
+        );
+
+        let res = merge_eq_to_in(expr);
+
+        let func_call = res.as_function_call().expect("should stay a function call");
+        assert_eq!(func_call.func_type(), Type::In);
+        // one slot for the column plus one for each distinct literal
+        assert_eq!(func_call.inputs().len(), N + 1);
+        assert_eq!(func_call.inputs()[0].as_input_ref().unwrap().index(), 0);
+    }
+
+    #[test]
+    fn merge_eq_to_in_dedups_repeated_values() {
+        use super::merge_eq_to_in;
+
+        const N: usize = 30;
+        let col: ExprImpl = InputRef::new(0, DataType::Int32).into();
+        // every value is `0`: the whole chain collapses to a single-element `IN`.
+        let expr = or_of_equalities(col, std::iter::repeat_n(ExprImpl::literal_int(0), N));
+
+        let res = merge_eq_to_in(expr);
+
+        let func_call = res.as_function_call().expect("should stay a function call");
+        assert_eq!(func_call.func_type(), Type::In);
+        assert_eq!(func_call.inputs().len(), 2);
+    }
+
+    #[test]
+    fn merge_eq_to_in_leaves_short_chains_alone() {
+        use super::merge_eq_to_in;
+
+        // Below the rewrite threshold: cheap enough as-is, and rewriting it would just add
+        // overhead for no benefit.
+        const N: usize = 5;
+        let col: ExprImpl = InputRef::new(0, DataType::Int32).into();
+        let expr = or_of_equalities(col, (0..N as i32).map(ExprImpl::literal_int));
+
+        let res = merge_eq_to_in(expr.clone());
+
+        assert_eq!(res, expr);
+    }
+
+    #[test]
+    fn merge_eq_to_in_preserves_null_in_list() {
+        use super::merge_eq_to_in;
+
+        // `col = 0 OR .. OR col = N-1 OR col = NULL`, the shape `col IN (0, .., N-1, NULL)`
+        // desugars to. The `NULL` disjunct must survive the rewrite as a `NULL` entry in the
+        // hashed set, not be dropped or block the merge.
+        const N: usize = 25;
+        let col: ExprImpl = InputRef::new(0, DataType::Int32).into();
+        let null_literal = Literal::new(None, DataType::Int32).into();
+        let expr = or_of_equalities(
+            col,
+            (0..N as i32)
+                .map(ExprImpl::literal_int)
+                .chain(std::iter::once(null_literal)),
+        );
+
+        let res = merge_eq_to_in(expr);
+
+        let func_call = res.as_function_call().expect("should stay a function call");
+        assert_eq!(func_call.func_type(), Type::In);
+        assert_eq!(func_call.inputs().len(), N + 2);
+        assert!(
+            func_call.inputs()[1..]
+                .iter()
+                .any(|e| e.as_literal().unwrap().get_data().is_none())
+        );
+    }
+
+    #[test]
+    fn merge_eq_to_in_does_not_merge_is_null_into_equalities() {
+        use super::{IN_LIST_REWRITE_THRESHOLD, merge_eq_to_in};
+
+        // `col = 0 OR .. OR col = N-1 OR col IS NULL` is *not* the same as `col IN (0, .., N-1)`:
+        // `IS NULL` isn't an equality comparison, so the rewrite must decline rather than
+        // silently drop or misinterpret it. `N` is chosen so the `col = ..` sub-chain alone sits
+        // right at the rewrite threshold and can't independently merge before the `IS NULL`
+        // disjunct is joined in above it.
+        const N: usize = IN_LIST_REWRITE_THRESHOLD;
+        let col: ExprImpl = InputRef::new(0, DataType::Int32).into();
+        let mut expr = or_of_equalities(col.clone(), (0..N as i32).map(ExprImpl::literal_int));
+        let is_null: ExprImpl = FunctionCall::new(Type::IsNull, vec![col]).unwrap().into();
+        expr = FunctionCall::new(Type::Or, vec![expr, is_null])
+            .unwrap()
+            .into();
+
+        let res = merge_eq_to_in(expr.clone());
+
+        assert_eq!(res, expr);
+    }
+
+    #[test]
+    fn merge_eq_to_in_does_not_merge_impure_left_operand() {
+        use super::{IN_LIST_REWRITE_THRESHOLD, merge_eq_to_in};
+
+        // `random() = 0 OR .. OR random() = N` must not fold into `random() IN (0, .., N)`:
+        // the `OR`-chain evaluates `random()` once per disjunct (short-circuiting on the first
+        // match), while the merged `IN` would evaluate it exactly once, changing how often a
+        // volatile expression is called.
+        const N: usize = IN_LIST_REWRITE_THRESHOLD + 1;
+        let random_call: ExprImpl = FunctionCall::new(Type::Random, vec![]).unwrap().into();
+        let expr = or_of_equalities(random_call, (0..N as i32).map(ExprImpl::literal_int));
+
+        let res = merge_eq_to_in(expr.clone());
+
+        assert_eq!(res, expr);
+    }
 }
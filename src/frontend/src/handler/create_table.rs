@@ -2576,4 +2576,50 @@ mod tests {
         // Options are not merged into props.
         assert!(!source.with_properties.contains_key("schema.location"));
     }
+
+    #[test]
+    fn test_on_conflict_to_behavior() {
+        // Non-append-only table: each `ON CONFLICT` clause maps to its `ConflictBehavior`, and
+        // the default (no clause) is `UPDATE FULL`.
+        for (on_conflict, expected) in [
+            (None, ConflictBehavior::Overwrite),
+            (Some(OnConflict::UpdateFull), ConflictBehavior::Overwrite),
+            (Some(OnConflict::Nothing), ConflictBehavior::IgnoreConflict),
+            (
+                Some(OnConflict::UpdateIfNotNull),
+                ConflictBehavior::DoUpdateIfNotNull,
+            ),
+        ] {
+            let behavior = EitherOnConflict::from(on_conflict)
+                .to_behavior(false, false)
+                .unwrap();
+            assert_eq!(behavior, expected, "on_conflict: {on_conflict:?}");
+        }
+
+        // Append-only table without a user-defined PK: conflicts can't happen, no check needed.
+        assert_eq!(
+            EitherOnConflict::from(None).to_behavior(true, true).unwrap(),
+            ConflictBehavior::NoCheck
+        );
+
+        // Append-only table with a user-defined PK: `DO NOTHING` is the only allowed behavior.
+        assert_eq!(
+            EitherOnConflict::from(Some(OnConflict::Nothing))
+                .to_behavior(true, false)
+                .unwrap(),
+            ConflictBehavior::IgnoreConflict
+        );
+
+        // Edge case: any other conflict behavior on an append-only table with a user-defined PK
+        // must be rejected at plan time.
+        for on_conflict in [OnConflict::UpdateFull, OnConflict::UpdateIfNotNull] {
+            let err = EitherOnConflict::from(Some(on_conflict))
+                .to_behavior(true, false)
+                .unwrap_err();
+            assert!(
+                err.to_string().contains("DO NOTHING"),
+                "on_conflict: {on_conflict:?}, err: {err:?}"
+            );
+        }
+    }
 }
@@ -206,11 +206,29 @@ pub async fn handle_execute(session: Arc<SessionImpl>, portal: Portal) -> Result
             let _guard = session.txn_begin_implicit(); // TODO(bugen): is this behavior correct?
             let sql: Arc<str> = Arc::from(portal.statement.to_string());
             let handler_args = HandlerArgs::new(session, &portal.statement, sql)?;
-            if let Statement::FetchCursor { .. } = &portal.statement {
+            let txn_session = handler_args.session.clone();
+
+            // Once an explicit transaction has failed, Postgres clients expect every
+            // subsequent statement to be rejected until the transaction ends with
+            // `ROLLBACK`/`ABORT`. Bound statements executed through the extended query
+            // protocol must honor this the same way `handle()` does for the simple protocol.
+            let is_txn_end_stmt =
+                matches!(&portal.statement, Statement::Rollback { .. } | Statement::Abort);
+            if !is_txn_end_stmt {
+                txn_session.txn_check_not_failed()?;
+            }
+
+            let result = if let Statement::FetchCursor { .. } = &portal.statement {
                 fetch_cursor::handle_fetch_cursor_execute(handler_args, portal).await
             } else {
                 query::handle_execute(handler_args, portal).await
+            };
+
+            if result.is_err() && !is_txn_end_stmt {
+                txn_session.txn_mark_failed();
             }
+
+            result
         }
         Portal::PureStatement(stmt) => {
             let sql: Arc<str> = Arc::from(stmt.to_string());
@@ -269,10 +269,18 @@ pub async fn handle(
     session.clear_cancel_query_flag();
     let _guard = session.txn_begin_implicit();
     let handler_args = HandlerArgs::new(session, &stmt, sql)?;
+    let txn_session = handler_args.session.clone();
 
     check_ban_ddl_for_iceberg_engine_table(handler_args.session.clone(), &stmt)?;
 
-    match stmt {
+    // Once an explicit transaction has failed, Postgres clients expect every subsequent
+    // statement to be rejected until the transaction ends with `ROLLBACK`/`ABORT`.
+    let is_txn_end_stmt = matches!(&stmt, Statement::Rollback { .. } | Statement::Abort);
+    if !is_txn_end_stmt {
+        txn_session.txn_check_not_failed()?;
+    }
+
+    let result = match stmt {
         Statement::Explain {
             statement,
             analyze,
@@ -1308,7 +1316,13 @@ pub async fn handle(
             refresh::handle_refresh(handler_args, table_name).await
         }
         _ => bail_not_implemented!("Unhandled statement: {}", stmt),
+    };
+
+    if result.is_err() && !is_txn_end_stmt {
+        txn_session.txn_mark_failed();
     }
+
+    result
 }
 
 fn check_ban_ddl_for_iceberg_engine_table(
@@ -277,8 +277,26 @@ fn gen_batch_query_plan(
             )
             .into());
         }
-        (true, false) => QueryMode::Distributed,
-        (false, true) => QueryMode::Local,
+        (true, false) => {
+            if session.config().query_mode() == QueryMode::Local {
+                return Err(ErrorCode::NotSupported(
+                    "the query is forced to local mode by the `query_mode` session variable, but it can only run in distributed mode".to_owned(),
+                    "run the query with `SET query_mode TO distributed` or `SET query_mode TO auto`".to_owned(),
+                )
+                .into());
+            }
+            QueryMode::Distributed
+        }
+        (false, true) => {
+            if session.config().query_mode() == QueryMode::Distributed {
+                return Err(ErrorCode::NotSupported(
+                    "the query is forced to distributed mode by the `query_mode` session variable, but it can only run in local mode".to_owned(),
+                    "run the query with `SET query_mode TO local` or `SET query_mode TO auto`".to_owned(),
+                )
+                .into());
+            }
+            QueryMode::Local
+        }
         (false, false) => match session.config().query_mode() {
             QueryMode::Auto => determine_query_mode(&batch_plan),
             QueryMode::Local => QueryMode::Local,
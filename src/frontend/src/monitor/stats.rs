@@ -35,6 +35,8 @@ pub struct FrontendMetrics {
     pub latency_local_execution: Histogram,
     pub active_sessions: IntGauge,
     pub batch_total_mem: TrAdderGauge,
+    pub compute_client_pool_size: IntGauge,
+    pub compute_client_pool_eviction_count: GenericCounter<AtomicU64>,
 }
 
 pub static GLOBAL_FRONTEND_METRICS: LazyLock<FrontendMetrics> =
@@ -73,11 +75,27 @@ impl FrontendMetrics {
             .register(Box::new(batch_total_mem.clone()))
             .unwrap();
 
+        let compute_client_pool_size = register_int_gauge_with_registry!(
+            "frontend_compute_client_pool_size",
+            "Number of compute node endpoints with a cached connection in the compute client pool",
+            registry
+        )
+        .unwrap();
+
+        let compute_client_pool_eviction_count = register_int_counter_with_registry!(
+            "frontend_compute_client_pool_eviction_count",
+            "Total number of compute client pool connections evicted after repeated RPC failures",
+            registry
+        )
+        .unwrap();
+
         Self {
             query_counter_local_execution,
             latency_local_execution,
             active_sessions,
             batch_total_mem,
+            compute_client_pool_size,
+            compute_client_pool_eviction_count,
         }
     }
 
@@ -473,6 +473,8 @@ static SET_OPERATION_TO_JOIN: LazyLock<OptimizationStage> = LazyLock::new(|| {
         vec![
             IntersectToSemiJoinRule::create(),
             ExceptToAntiJoinRule::create(),
+            IntersectAllToAggJoinRule::create(),
+            ExceptAllToAggJoinRule::create(),
         ],
         ApplyOrder::BottomUp,
     )
@@ -54,6 +54,12 @@ impl BatchFilter {
         core.predicate = predicate;
         Self::new(core)
     }
+
+    /// Estimate the fraction of input rows this filter lets through, absent column statistics.
+    /// See [`Condition::estimated_selectivity`].
+    pub fn estimated_selectivity(&self) -> f64 {
+        self.predicate().estimated_selectivity()
+    }
 }
 impl_distill_by_unit!(BatchFilter, core, "BatchFilter");
 
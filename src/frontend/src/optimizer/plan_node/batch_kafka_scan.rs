@@ -134,6 +134,8 @@ impl ToBatchPb for BatchKafkaScan {
             with_properties,
             split: vec![],
             secret_refs,
+            // Kafka splits are only known once the scheduler queries the broker for partitions.
+            split_count: 1,
         })
     }
 }
@@ -45,6 +45,12 @@ impl BatchLimit {
         BatchLimit { base, core }
     }
 
+    /// Creates a [`BatchLimit`] with an explicit `offset`, for keyset/OFFSET pagination
+    /// pushdown where the executor should skip `offset` rows before yielding `limit` rows.
+    pub fn with_offset(input: PlanRef, limit: u64, offset: u64) -> Self {
+        Self::new(generic::Limit::new(input, limit, offset))
+    }
+
     fn two_phase_limit(&self, new_input: PlanRef) -> Result<PlanRef> {
         let new_limit = self.core.limit + self.core.offset;
         let new_offset = 0;
@@ -121,3 +127,37 @@ impl ToLocalBatch for BatchLimit {
 impl ExprRewritable<Batch> for BatchLimit {}
 
 impl ExprVisitable for BatchLimit {}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+    use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+    use super::BatchLimit;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::{LogicalValues, ToBatchPb};
+
+    #[tokio::test]
+    async fn test_with_offset_prost_round_trip() {
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![Field::with_name(ty.clone(), "v1")];
+        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let input = crate::optimizer::plan_node::BatchPlanRef::from(
+            crate::optimizer::plan_node::BatchValues::new(values),
+        );
+
+        let batch_limit = BatchLimit::with_offset(input, 10, 5);
+        assert_eq!(batch_limit.limit(), 10);
+        assert_eq!(batch_limit.offset(), 5);
+
+        match batch_limit.to_batch_prost_body() {
+            NodeBody::Limit(node) => {
+                assert_eq!(node.limit, 10);
+                assert_eq!(node.offset, 5);
+            }
+            other => panic!("expected NodeBody::Limit, got {other:?}"),
+        }
+    }
+}
@@ -215,6 +215,12 @@ impl ToDistributedBatch for BatchLookupJoin {
 
 impl TryToBatchPb for BatchLookupJoin {
     fn try_to_batch_prost_body(&self) -> SchedulerResult<NodeBody> {
+        let lookup_batch_size = self
+            .base
+            .ctx()
+            .session_ctx()
+            .batch_config()
+            .lookup_join_batch_size;
         Ok(if self.distributed_lookup {
             NodeBody::DistributedLookupJoin(DistributedLookupJoinNode {
                 join_type: self.core.join_type as i32,
@@ -246,6 +252,7 @@ impl TryToBatchPb for BatchLookupJoin {
                 lookup_prefix_len: self.lookup_prefix_len as u32,
                 as_of: to_pb_time_travel_as_of(&self.as_of)?,
                 asof_desc: self.asof_desc,
+                lookup_batch_size,
             })
         } else {
             NodeBody::LocalLookupJoin(LocalLookupJoinNode {
@@ -280,6 +287,7 @@ impl TryToBatchPb for BatchLookupJoin {
                 lookup_prefix_len: self.lookup_prefix_len as u32,
                 as_of: to_pb_time_travel_as_of(&self.as_of)?,
                 asof_desc: self.asof_desc,
+                lookup_batch_size,
             })
         })
     }
@@ -318,3 +326,130 @@ impl ExprVisitable for BatchLookupJoin {
         self.core.visit_exprs(v);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{ColumnDesc, Field, Schema};
+    use risingwave_common::hash::VnodeCount;
+    use risingwave_common::types::DataType;
+    use risingwave_common::util::sort_util::{ColumnOrder, OrderType};
+    use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, PbEngine, PbTableVersion};
+    use risingwave_pb::catalog::{PbCreateType, PbStreamJobStatus, PbTable, PbTableType};
+    use risingwave_pb::plan_common::{JoinType, PbColumnCatalog};
+
+    use super::*;
+    use crate::expr::{ExprType, FunctionCall, InputRef};
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::{LogicalJoin, LogicalPlanRef, LogicalScan, LogicalValues};
+    use crate::utils::Condition;
+
+    /// A single-column table `t(id int)` whose pk and distribution key are both `id`, so that an
+    /// equi-join on `id` is eligible for a lookup join.
+    fn test_table_catalog() -> TableCatalog {
+        let columns = [ColumnDesc::named("id", ColumnId::new(0), DataType::Int32)];
+        PbTable {
+            id: 0,
+            schema_id: 0,
+            database_id: 0,
+            name: "t".to_owned(),
+            table_type: PbTableType::Table as i32,
+            columns: columns
+                .into_iter()
+                .map(|c| PbColumnCatalog {
+                    column_desc: Some(c.to_protobuf()),
+                    is_hidden: false,
+                })
+                .collect(),
+            pk: vec![ColumnOrder::new(0, OrderType::ascending()).to_protobuf()],
+            stream_key: vec![0],
+            distribution_key: vec![0],
+            optional_associated_source_id: OptionalAssociatedSourceId::AssociatedSourceId(0)
+                .into(),
+            append_only: false,
+            owner: risingwave_common::catalog::DEFAULT_SUPER_USER_ID,
+            retention_seconds: None,
+            fragment_id: 0,
+            dml_fragment_id: None,
+            initialized_at_epoch: None,
+            value_indices: vec![0],
+            definition: "".into(),
+            read_prefix_len_hint: 0,
+            vnode_col_index: None,
+            row_id_index: None,
+            version: Some(PbTableVersion {
+                version: 0,
+                next_column_id: 1,
+            }),
+            watermark_indices: vec![],
+            handle_pk_conflict_behavior: 3,
+            dist_key_in_pk: vec![0],
+            cardinality: None,
+            created_at_epoch: None,
+            cleaned_by_watermark: false,
+            stream_job_status: PbStreamJobStatus::Created.into(),
+            create_type: PbCreateType::Foreground.into(),
+            description: None,
+            #[expect(deprecated)]
+            incoming_sinks: vec![],
+            created_at_cluster_version: None,
+            initialized_at_cluster_version: None,
+            version_column_indices: Vec::new(),
+            cdc_table_id: None,
+            maybe_vnode_count: VnodeCount::set(1).to_protobuf(),
+            webhook_info: None,
+            job_id: None,
+            engine: Some(PbEngine::Hummock as i32),
+            clean_watermark_index_in_pk: None,
+            refreshable: false,
+            vector_index_info: None,
+            cdc_table_type: None,
+            refresh_state: Some(risingwave_pb::catalog::RefreshState::Idle as i32),
+        }
+        .into()
+    }
+
+    async fn build_lookup_join() -> BatchLookupJoin {
+        let ctx = OptimizerContext::mock().await;
+        let left: LogicalPlanRef = LogicalValues::new(
+            vec![],
+            Schema::new(vec![Field::with_name(DataType::Int32, "k")]),
+            ctx.clone(),
+        )
+        .into();
+        let right: LogicalPlanRef =
+            LogicalScan::create(Arc::new(test_table_catalog()), ctx, None).into();
+
+        let on = Condition::with_expr(
+            FunctionCall::new(
+                ExprType::Equal,
+                vec![
+                    InputRef::new(0, DataType::Int32).into(),
+                    InputRef::new(1, DataType::Int32).into(),
+                ],
+            )
+            .unwrap()
+            .into(),
+        );
+        let join = LogicalJoin::new(left, right, JoinType::Inner, on);
+
+        join.index_lookup_join_to_batch_lookup_join()
+            .unwrap()
+            .as_batch_lookup_join()
+            .unwrap()
+            .clone()
+    }
+
+    #[tokio::test]
+    async fn test_lookup_batch_size_defaults_and_flows_into_prost() {
+        let join = build_lookup_join().await;
+        let batch_config = join.base.ctx().session_ctx().batch_config().clone();
+
+        let NodeBody::LocalLookupJoin(pb) = join.try_to_batch_prost_body().unwrap() else {
+            panic!("expected NodeBody::LocalLookupJoin");
+        };
+        assert_eq!(pb.lookup_batch_size, batch_config.lookup_join_batch_size);
+        // Sensible default: batching is on, not accidentally left at 0 (which the executor
+        // would otherwise interpret as "unset").
+        assert!(pb.lookup_batch_size > 0);
+    }
+}
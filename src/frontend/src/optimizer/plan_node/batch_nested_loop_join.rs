@@ -111,10 +111,13 @@ impl ToDistributedBatch for BatchNestedLoopJoin {
 
 impl ToBatchPb for BatchNestedLoopJoin {
     fn to_batch_prost_body(&self) -> NodeBody {
+        let batch_config = self.base.ctx().session_ctx().batch_config();
         NodeBody::NestedLoopJoin(NestedLoopJoinNode {
             join_type: self.core.join_type as i32,
             join_cond: Some(ExprImpl::from(self.core.on.clone()).to_expr_proto()),
             output_indices: self.core.output_indices.iter().map(|&x| x as u32).collect(),
+            allow_spill: batch_config.enable_spill,
+            memory_limit_bytes: batch_config.nested_loop_join_memory_limit_bytes,
         })
     }
 }
@@ -148,3 +151,42 @@ impl ExprVisitable for BatchNestedLoopJoin {
         self.core.visit_exprs(v);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+    use risingwave_pb::plan_common::JoinType;
+
+    use super::*;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::{BatchValues, LogicalValues};
+    use crate::utils::Condition;
+
+    #[tokio::test]
+    async fn test_to_batch_prost_body_surfaces_spill_config() {
+        let ctx = OptimizerContext::mock().await;
+        let batch_config = ctx.session_ctx().batch_config().clone();
+        let schema = Schema::new(vec![Field::with_name(DataType::Int32, "v1")]);
+
+        let left: PlanRef =
+            BatchValues::new(LogicalValues::new(vec![], schema.clone(), ctx.clone())).into();
+        let right: PlanRef = BatchValues::new(LogicalValues::new(vec![], schema, ctx)).into();
+
+        let core =
+            generic::Join::with_full_output(left, right, JoinType::Inner, Condition::true_cond());
+        let join = BatchNestedLoopJoin::new(core);
+
+        let NodeBody::NestedLoopJoin(pb) = join.to_batch_prost_body() else {
+            panic!("expected NodeBody::NestedLoopJoin");
+        };
+        assert_eq!(pb.allow_spill, batch_config.enable_spill);
+        assert_eq!(
+            pb.memory_limit_bytes,
+            batch_config.nested_loop_join_memory_limit_bytes
+        );
+        // With the default config, spilling is enabled and the configured limit is non-zero.
+        assert!(pb.allow_spill);
+        assert!(pb.memory_limit_bytes > 0);
+    }
+}
@@ -162,6 +162,12 @@ impl Distill for BatchSeqScan {
         }
 
         if verbose {
+            if let Some(as_of) = &self.as_of {
+                // `AsOf`'s `Display` impl is meant for splicing into a `FROM` clause and comes
+                // with a leading space; trim it for use as a standalone field value here.
+                vec.push(("as_of", Pretty::from(as_of.to_string().trim().to_owned())));
+            }
+
             let dist = Pretty::display(&DistributionDisplay {
                 distribution: self.distribution(),
                 input_schema: self.base.schema(),
@@ -30,6 +30,11 @@ use crate::error::Result;
 use crate::optimizer::plan_node::expr_visitable::ExprVisitable;
 use crate::optimizer::property::{Distribution, Order};
 
+/// WITH-properties that statically declare how many splits a source will read, for connectors
+/// whose split count is fixed at creation time rather than discovered at runtime (e.g. Kafka
+/// partitions).
+const SPLIT_COUNT_PROPERTIES: [&str; 2] = ["nexmark.split.num", "datagen.split.num"];
+
 /// [`BatchSource`] represents a table/connector source at the very beginning of the graph.
 ///
 /// For supported batch connectors, see [`crate::scheduler::plan_fragmenter::SourceScanInfo`].
@@ -37,6 +42,10 @@ use crate::optimizer::property::{Distribution, Order};
 pub struct BatchSource {
     pub base: PlanBase<Batch>,
     pub core: generic::Source,
+    /// Hint for how many splits (e.g. Kafka partitions) this source will read, so the scheduler
+    /// can cap the number of parallel scan tasks. Only a handful of connectors expose their
+    /// split count statically via [`SPLIT_COUNT_PROPERTIES`]; everything else defaults to `1`.
+    pub split_count: usize,
 }
 
 impl BatchSource {
@@ -47,8 +56,39 @@ impl BatchSource {
             Distribution::Single,
             Order::any(),
         );
+        let split_count = Self::split_count_hint(&core);
+
+        Self {
+            base,
+            core,
+            split_count,
+        }
+    }
+
+    /// Best-effort static split count for `core`, falling back to `1` with a warning when the
+    /// connector doesn't declare one of [`SPLIT_COUNT_PROPERTIES`] (e.g. because its splits are
+    /// only known once the scheduler queries the external system, as with Kafka).
+    fn split_count_hint(core: &generic::Source) -> usize {
+        let Some(catalog) = &core.catalog else {
+            return 1;
+        };
+
+        for key in SPLIT_COUNT_PROPERTIES {
+            if let Some(split_count) = catalog
+                .with_properties
+                .get(key)
+                .and_then(|value| value.parse::<usize>().ok())
+                && split_count > 0
+            {
+                return split_count;
+            }
+        }
 
-        Self { base, core }
+        tracing::warn!(
+            source = catalog.name,
+            "unable to statically determine split count for source, defaulting to 1"
+        );
+        1
     }
 
     pub fn column_names(&self) -> Vec<&str> {
@@ -63,6 +103,11 @@ impl BatchSource {
         self.core.as_of.clone()
     }
 
+    /// Hint for how many splits this source will read. See [`Self::split_count`] field docs.
+    pub fn split_count(&self) -> usize {
+        self.split_count
+    }
+
     pub fn clone_with_dist(&self) -> Self {
         let base = self
             .base
@@ -70,6 +115,7 @@ impl BatchSource {
         Self {
             base,
             core: self.core.clone(),
+            split_count: self.split_count,
         }
     }
 }
@@ -118,6 +164,7 @@ impl ToBatchPb for BatchSource {
             with_properties,
             split: vec![],
             secret_refs,
+            split_count: self.split_count as u32,
         })
     }
 }
@@ -125,3 +172,83 @@ impl ToBatchPb for BatchSource {
 impl ExprRewritable<Batch> for BatchSource {}
 
 impl ExprVisitable for BatchSource {}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_connector::WithOptionsSecResolved;
+    use risingwave_pb::catalog::StreamSourceInfo;
+
+    use super::*;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::generic::SourceNodeKind;
+
+    fn mock_source_catalog(with_properties: Vec<(&str, &str)>) -> SourceCatalog {
+        SourceCatalog {
+            id: 0,
+            name: "s".to_owned(),
+            schema_id: 0,
+            database_id: 0,
+            columns: vec![],
+            pk_col_ids: vec![],
+            append_only: false,
+            owner: 0,
+            info: StreamSourceInfo::default(),
+            row_id_index: None,
+            with_properties: WithOptionsSecResolved::new(
+                with_properties
+                    .into_iter()
+                    .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                    .collect(),
+                Default::default(),
+            ),
+            watermark_descs: vec![],
+            associated_table_id: None,
+            definition: "".to_owned(),
+            connection_id: None,
+            created_at_epoch: None,
+            initialized_at_epoch: None,
+            version: 0,
+            created_at_cluster_version: None,
+            initialized_at_cluster_version: None,
+            rate_limit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_split_count_flows_into_batch_prost() {
+        let ctx = OptimizerContext::mock().await;
+        let catalog = mock_source_catalog(vec![("nexmark.split.num", "4")]);
+        let core = generic::Source {
+            catalog: Some(Rc::new(catalog)),
+            column_catalog: vec![],
+            row_id_index: None,
+            kind: SourceNodeKind::CreateMViewOrBatch,
+            ctx,
+            as_of: None,
+        };
+
+        let batch_source = BatchSource::new(core);
+        assert_eq!(batch_source.split_count(), 4);
+
+        match batch_source.to_batch_prost_body() {
+            NodeBody::Source(source_node) => assert_eq!(source_node.split_count, 4),
+            other => panic!("expected Source node body, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_split_count_defaults_to_one_for_unknown_connector() {
+        let ctx = OptimizerContext::mock().await;
+        let catalog = mock_source_catalog(vec![]);
+        let core = generic::Source {
+            catalog: Some(Rc::new(catalog)),
+            column_catalog: vec![],
+            row_id_index: None,
+            kind: SourceNodeKind::CreateMViewOrBatch,
+            ctx,
+            as_of: None,
+        };
+
+        assert_eq!(BatchSource::new(core).split_count(), 1);
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use pretty_xmlish::XmlNode;
+use risingwave_common::catalog::Schema;
+use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_pb::batch_plan::ValuesNode;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::values_node::ExprTuple;
@@ -23,8 +25,9 @@ use super::{
     BatchPlanRef as PlanRef, ExprRewritable, LogicalValues, PlanBase, PlanTreeNodeLeaf, ToBatchPb,
     ToDistributedBatch,
 };
-use crate::error::Result;
+use crate::error::{ErrorCode, Result, RwError};
 use crate::expr::{Expr, ExprImpl, ExprRewriter, ExprVisitor};
+use crate::optimizer::optimizer_context::OptimizerContextRef;
 use crate::optimizer::plan_node::ToLocalBatch;
 use crate::optimizer::plan_node::expr_visitable::ExprVisitable;
 use crate::optimizer::property::{Distribution, Order};
@@ -49,6 +52,36 @@ impl BatchValues {
         BatchValues { base, logical }
     }
 
+    /// Create a `BatchValues` whose schema is `schema` instead of the one inferred from `rows`.
+    /// Each literal is wrapped in an assignment cast to its corresponding target type, so the
+    /// resulting rows genuinely produce `schema`'s types at runtime.
+    ///
+    /// # Errors
+    /// Returns `ErrorCode::BindError` if a literal cannot be assignment-cast to its target type.
+    pub fn with_schema_override(
+        rows: Vec<Vec<ExprImpl>>,
+        schema: Schema,
+        ctx: OptimizerContextRef,
+    ) -> Result<Self> {
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .zip_eq_fast(schema.fields())
+                    .map(|(expr, field)| {
+                        expr.cast_assign(&field.data_type()).map_err(|e| {
+                            RwError::from(ErrorCode::BindError(format!(
+                                "failed to cast VALUES literal to column \"{}\": {}",
+                                field.name, e
+                            )))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(LogicalValues::new(rows, schema, ctx)))
+    }
+
     /// Get a reference to the batch values's logical.
     #[must_use]
     pub fn logical(&self) -> &LogicalValues {
@@ -126,3 +159,42 @@ impl ExprVisitable for BatchValues {
             .for_each(|e| v.visit_expr(e));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::Field;
+    use risingwave_common::types::{DataType, ScalarImpl};
+
+    use super::*;
+    use crate::expr::Literal;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+
+    #[tokio::test]
+    async fn test_with_schema_override_coerces_int_to_decimal() {
+        let ctx = OptimizerContext::mock().await;
+        let rows = vec![vec![
+            Literal::new(Some(ScalarImpl::Int32(1)), DataType::Int32).into(),
+        ]];
+        let schema = Schema::new(vec![Field::with_name(DataType::Decimal, "v1")]);
+
+        let batch_values = BatchValues::with_schema_override(rows, schema, ctx).unwrap();
+
+        assert_eq!(batch_values.schema().fields()[0].data_type(), DataType::Decimal);
+        assert_eq!(
+            batch_values.logical().rows()[0][0].return_type(),
+            DataType::Decimal
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_schema_override_rejects_non_numeric_string() {
+        let ctx = OptimizerContext::mock().await;
+        let rows = vec![vec![
+            Literal::new(Some(ScalarImpl::Utf8("abc".into())), DataType::Varchar).into(),
+        ]];
+        let schema = Schema::new(vec![Field::with_name(DataType::Int32, "v1")]);
+
+        let err = BatchValues::with_schema_override(rows, schema, ctx).unwrap_err();
+        assert!(err.to_string().contains("failed to cast VALUES literal"));
+    }
+}
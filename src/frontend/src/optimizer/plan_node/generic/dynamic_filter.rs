@@ -29,18 +29,28 @@ use crate::{OptimizerContextRef, TableCatalog};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DynamicFilter<PlanRef> {
-    /// The predicate (formed with exactly one of < , <=, >, >=)
+    /// The predicate comparing `left[left_index]` against `right[0]` (formed with exactly one
+    /// of <, <=, >, >=). When `upper_comparator` is set, this must be `>` or `>=` and acts as
+    /// the lower bound of a BETWEEN-style predicate.
     comparator: ExprType,
     left_index: usize,
     left: PlanRef,
-    /// The right input can only have one column.
+    /// The right input has one column, unless `upper_comparator` is set, in which case it has
+    /// two: the lower bound (compared via `comparator`) at index 0 and the upper bound
+    /// (compared via `upper_comparator`) at index 1.
     right: PlanRef,
+    /// The upper bound of a BETWEEN-style predicate (`<` or `<=`), if any. See `right`.
+    upper_comparator: Option<ExprType>,
 }
 impl<PlanRef> DynamicFilter<PlanRef> {
     pub fn comparator(&self) -> ExprType {
         self.comparator
     }
 
+    pub fn upper_comparator(&self) -> Option<ExprType> {
+        self.upper_comparator
+    }
+
     pub fn left_index(&self) -> usize {
         self.left_index
     }
@@ -80,33 +90,83 @@ impl<PlanRef: GenericPlanRef> DynamicFilter<PlanRef> {
             left_index,
             left,
             right,
+            upper_comparator: None,
+        }
+    }
+
+    /// Builds a `DynamicFilter` for a BETWEEN-style predicate: `left[left_index]` is bounded
+    /// below by `right[0]` via `lower_comparator` (`>` or `>=`) and above by `right[1]` via
+    /// `upper_comparator` (`<` or `<=`).
+    pub fn new_between(
+        lower_comparator: ExprType,
+        upper_comparator: ExprType,
+        left_index: usize,
+        left: PlanRef,
+        right: PlanRef,
+    ) -> Self {
+        assert_eq!(right.schema().len(), 2);
+        assert_matches!(
+            lower_comparator,
+            ExprType::GreaterThan | ExprType::GreaterThanOrEqual
+        );
+        assert_matches!(
+            upper_comparator,
+            ExprType::LessThan | ExprType::LessThanOrEqual
+        );
+        Self {
+            comparator: lower_comparator,
+            left_index,
+            left,
+            right,
+            upper_comparator: Some(upper_comparator),
         }
     }
 
     pub fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
-        Self::new(self.comparator, self.left_index, left, right)
+        Self {
+            comparator: self.comparator,
+            left_index: self.left_index,
+            left,
+            right,
+            upper_comparator: self.upper_comparator,
+        }
+    }
+
+    fn comparison(&self, comparator: ExprType, right_index: usize) -> ExprImpl {
+        ExprImpl::from(
+            FunctionCall::new(
+                comparator,
+                vec![
+                    ExprImpl::from(InputRef::new(
+                        self.left_index,
+                        self.left.schema().fields()[self.left_index].data_type(),
+                    )),
+                    ExprImpl::from(InputRef::new(
+                        self.left.schema().len() + right_index,
+                        self.right.schema().fields()[right_index].data_type(),
+                    )),
+                ],
+            )
+            .unwrap(),
+        )
+    }
+
+    /// The expression comparing `left[left_index]` against `right[0]` via `comparator`.
+    pub fn condition(&self) -> ExprImpl {
+        self.comparison(self.comparator, 0)
+    }
+
+    /// The expression comparing `left[left_index]` against `right[1]` via `upper_comparator`,
+    /// if this is a BETWEEN-style predicate with two dynamic bounds.
+    pub fn upper_condition(&self) -> Option<ExprImpl> {
+        self.upper_comparator.map(|c| self.comparison(c, 1))
     }
 
     /// normalize to the join predicate
     pub fn predicate(&self) -> Condition {
-        Condition {
-            conjunctions: vec![ExprImpl::from(
-                FunctionCall::new(
-                    self.comparator,
-                    vec![
-                        ExprImpl::from(InputRef::new(
-                            self.left_index,
-                            self.left.schema().fields()[self.left_index].data_type(),
-                        )),
-                        ExprImpl::from(InputRef::new(
-                            self.left.schema().len(),
-                            self.right.schema().fields()[0].data_type(),
-                        )),
-                    ],
-                )
-                .unwrap(),
-            )],
-        }
+        let mut conjunctions = vec![self.condition()];
+        conjunctions.extend(self.upper_condition());
+        Condition { conjunctions }
     }
 
     fn condition_display(&self) -> (Condition, Schema) {
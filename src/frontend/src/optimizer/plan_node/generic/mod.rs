@@ -77,6 +77,8 @@ mod limit;
 pub use limit::*;
 mod max_one_row;
 pub use max_one_row::*;
+mod sample;
+pub use sample::*;
 mod cte_ref;
 pub use cte_ref::*;
 mod recursive_union;
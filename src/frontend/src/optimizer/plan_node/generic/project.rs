@@ -289,7 +289,10 @@ impl<PlanRef: GenericPlanRef> Project<PlanRef> {
     }
 
     pub fn is_identity(&self) -> bool {
-        self.exprs.len() == self.input.schema().len()
+        // A `field_names` override means some column was renamed (e.g. `SELECT a AS x`), which is
+        // not an identity even if the underlying expressions are still plain `InputRef`s.
+        self.field_names.is_empty()
+        && self.exprs.len() == self.input.schema().len()
         && self
             .exprs
             .iter()
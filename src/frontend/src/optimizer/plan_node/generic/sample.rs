@@ -0,0 +1,82 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hash;
+
+use pretty_xmlish::{Pretty, Str, XmlNode};
+use risingwave_common::catalog::Schema;
+
+use super::{DistillUnit, GenericPlanNode, GenericPlanRef};
+use crate::OptimizerContextRef;
+use crate::optimizer::plan_node::utils::childless_record;
+use crate::optimizer::property::FunctionalDependencySet;
+
+/// The sampling method used by a `TABLESAMPLE` clause.
+///
+/// Note: `System` sampling is not yet distinguished from `Bernoulli` at execution time; both
+/// apply a per-row random gate. Block-level sampling for `System` is left as a follow-up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleMethod {
+    Bernoulli,
+    System,
+}
+
+/// `Sample` implements `TABLESAMPLE`, keeping roughly `fraction` percent of the input rows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sample<PlanRef> {
+    pub input: PlanRef,
+    pub method: SampleMethod,
+    /// Percentage of rows to keep, in `[0, 100]`.
+    pub fraction: f64,
+}
+
+impl<PlanRef: GenericPlanRef> GenericPlanNode for Sample<PlanRef> {
+    fn ctx(&self) -> OptimizerContextRef {
+        self.input.ctx()
+    }
+
+    fn schema(&self) -> Schema {
+        self.input.schema().clone()
+    }
+
+    fn functional_dependency(&self) -> FunctionalDependencySet {
+        self.input.functional_dependency().clone()
+    }
+
+    fn stream_key(&self) -> Option<Vec<usize>> {
+        self.input.stream_key().map(|s| s.to_vec())
+    }
+}
+
+impl<PlanRef> Sample<PlanRef> {
+    pub fn new(input: PlanRef, method: SampleMethod, fraction: f64) -> Self {
+        Sample {
+            input,
+            method,
+            fraction,
+        }
+    }
+}
+
+impl<PlanRef> DistillUnit for Sample<PlanRef> {
+    fn distill_with_name<'a>(&self, name: impl Into<Str<'a>>) -> XmlNode<'a> {
+        childless_record(
+            name,
+            vec![
+                ("method", Pretty::debug(&self.method)),
+                ("fraction", Pretty::debug(&self.fraction)),
+            ],
+        )
+    }
+}
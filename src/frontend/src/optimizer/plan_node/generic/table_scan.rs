@@ -375,6 +375,11 @@ impl GenericPlanNode for TableScan {
     }
 
     fn functional_dependency(&self) -> FunctionalDependencySet {
+        // Note: we can only seed the FD set from the table's primary key here, not from
+        // `self.table_indexes`. Unlike Postgres, RisingWave doesn't support unique indexes
+        // (`CREATE UNIQUE INDEX` is rejected in `handler::handle`) -- every index is
+        // a secondary, non-unique lookup structure, so its columns never determine the rest of
+        // the row on their own.
         let pk_indices = self.stream_key();
         let col_num = self.output_col_idx.len();
         match &pk_indices {
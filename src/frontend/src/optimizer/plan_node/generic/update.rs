@@ -26,6 +26,12 @@ use crate::expr::{Expr, ExprImpl, ExprRewriter, ExprVisitor};
 use crate::optimizer::plan_node::utils::childless_record;
 use crate::optimizer::property::FunctionalDependencySet;
 
+/// `Update` always emits `new_exprs` (or, when `returning` is set, whatever the caller placed
+/// there — see below) as its output; selecting a subset of columns for `RETURNING id, x` is not
+/// this node's job. The planner (`Planner::plan_update`) wraps a `returning: true` `Update` in a
+/// `LogicalProject` built from `BoundUpdate::returning_list` to pick out and rename exactly the
+/// requested columns, the same way other DML nodes keep column selection in a separate `Project`
+/// rather than embedding it here.
 #[derive(Debug, Clone, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct Update<PlanRef: Eq + Hash> {
@@ -37,6 +43,9 @@ pub struct Update<PlanRef: Eq + Hash> {
     pub input: PlanRef,
     pub old_exprs: Vec<ExprImpl>,
     pub new_exprs: Vec<ExprImpl>,
+    /// Whether this update returns `new_exprs` as rows (`RETURNING`) instead of a single
+    /// affected-row count. Threaded through `LogicalUpdate`/`BatchUpdate` into `UpdateNode::returning`,
+    /// and also used by `BatchUpdate::to_distributed` to skip the affected-row-count aggregation.
     pub returning: bool,
 }
 
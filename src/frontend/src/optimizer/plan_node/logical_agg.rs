@@ -1407,7 +1407,24 @@ fn new_stream_hash_agg(
     core: Agg<StreamPlanRef>,
     vnode_col_idx: Option<usize>,
 ) -> Result<StreamHashAgg> {
+    use super::stream::prelude::*;
+
     let (logical, row_count_idx) = find_or_append_row_count(core);
+    // Propagate Emit-On-Window-Close from the input: if the input already emits only on window
+    // close and the group key still contains the watermark column it's based on, this agg can
+    // keep the property. If the group key only sometimes carries a watermark column (i.e. no
+    // window column can be determined), `eowc_window_column` fails and eowc is correctly left
+    // off. Only applies to the single-phase/global-phase construction (`vnode_col_idx` is `None`
+    // here); 2-phase EOWC aggregation isn't supported yet, so the local phase of a two-phase agg
+    // is left non-EOWC.
+    if vnode_col_idx.is_none()
+        && logical.input.emit_on_window_close()
+        && logical
+            .eowc_window_column(logical.input.watermark_columns())
+            .is_ok()
+    {
+        return StreamHashAgg::new_with_eowc(logical, vnode_col_idx, row_count_idx, true);
+    }
     StreamHashAgg::new(logical, vnode_col_idx, row_count_idx)
 }
 
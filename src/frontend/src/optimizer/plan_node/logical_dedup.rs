@@ -14,10 +14,12 @@
 
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
+use pretty_xmlish::{Pretty, XmlNode};
+use risingwave_common::catalog::FieldDisplay;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 
 use super::generic::{GenericPlanRef, TopNLimit};
-use super::utils::impl_distill_by_unit;
+use super::utils::{Distill, childless_record};
 use super::{
     BatchGroupTopN, BatchPlanRef, ColPrunable, ColumnPruningContext, ExprRewritable, Logical,
     LogicalPlanRef as PlanRef, LogicalProject, PlanBase, PlanTreeNodeUnary, PredicatePushdown,
@@ -31,22 +33,64 @@ use crate::utils::Condition;
 
 /// [`LogicalDedup`] deduplicates data on specific columns. It is now used in `DISTINCT ON` without
 /// an `ORDER BY`.
+///
+/// `LogicalDedup` itself never projects: its schema is always the (possibly pruned) input schema.
+/// Deduplicating on a subset of columns while projecting a different output set, e.g. for
+/// `SELECT DISTINCT ON (a) a, b`, is handled by `ColPrunable::prune_col`, which keeps `dedup_cols`
+/// as part of the required input columns and only wraps the result in a `LogicalProject` when the
+/// caller's required columns differ from what dedup needs; the projection is elided when they
+/// coincide. The pk needed for `StreamDedup`'s state table is likewise preserved automatically,
+/// since `dedup_cols` are always part of `LogicalDedup`'s own schema and `generic::Dedup::stream_key`
+/// reports them as the stream key.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LogicalDedup {
     pub base: PlanBase<Logical>,
     core: generic::Dedup<PlanRef>,
+    /// Whether `dedup_cols` is exactly the window columns (`window_start`/`window_end`, or both)
+    /// of a `LogicalHopWindow` directly below this dedup, e.g. `DISTINCT ON (window_start)` right
+    /// on top of a `HOP`/`TUMBLE` window. It is purely a display hint recomputed whenever the
+    /// input changes: it does not change `to_batch`/`to_stream`, which keep using the same
+    /// generic `Dedup` lowering regardless of what produces the input.
+    fused_hop_window: bool,
 }
 
 impl LogicalDedup {
     pub fn new(input: PlanRef, dedup_cols: Vec<usize>) -> Self {
+        let fused_hop_window = Self::is_hop_window_fusion(&input, &dedup_cols);
         let core = generic::Dedup::new(input, dedup_cols);
         let base = PlanBase::new_logical_with_core(&core);
-        LogicalDedup { base, core }
+        LogicalDedup {
+            base,
+            core,
+            fused_hop_window,
+        }
+    }
+
+    /// Whether `dedup_cols` consists exclusively of the window columns that `input` (if it is a
+    /// `LogicalHopWindow`) exposes in its output, e.g. deduping a hop window's output on
+    /// `window_start` to get one arbitrary row per gap-filled window. A dedup that also keys on
+    /// any non-window column does not match.
+    fn is_hop_window_fusion(input: &PlanRef, dedup_cols: &[usize]) -> bool {
+        let Some(hop_window) = input.as_logical_hop_window() else {
+            return false;
+        };
+        let window_cols = [
+            hop_window.output_window_start_col_idx(),
+            hop_window.output_window_end_col_idx(),
+        ];
+        !dedup_cols.is_empty() && dedup_cols.iter().all(|c| window_cols.contains(&Some(*c)))
     }
 
     pub fn dedup_cols(&self) -> &[usize] {
         &self.core.dedup_cols
     }
+
+    /// Whether this dedup is keyed on exactly the window columns of a directly underlying
+    /// `LogicalHopWindow`, and could be fused into a single windowed-dedup stream node instead of
+    /// materializing both. See [`LogicalDedup::is_hop_window_fusion`].
+    pub fn is_fused_hop_window(&self) -> bool {
+        self.fused_hop_window
+    }
 }
 
 impl PlanTreeNodeUnary<Logical> for LogicalDedup {
@@ -63,16 +107,14 @@ impl PlanTreeNodeUnary<Logical> for LogicalDedup {
         input: PlanRef,
         input_col_change: ColIndexMapping,
     ) -> (Self, ColIndexMapping) {
-        (
-            Self::new(
-                input,
-                self.dedup_cols()
-                    .iter()
-                    .map(|idx| input_col_change.map(*idx))
-                    .collect_vec(),
-            ),
-            input_col_change,
-        )
+        let new_dedup = Self::new(
+            input,
+            self.dedup_cols()
+                .iter()
+                .map(|idx| input_col_change.map(*idx))
+                .collect_vec(),
+        );
+        (new_dedup, input_col_change)
     }
 }
 
@@ -192,4 +234,19 @@ impl ColPrunable for LogicalDedup {
     }
 }
 
-impl_distill_by_unit!(LogicalDedup, core, "LogicalDedup");
+impl Distill for LogicalDedup {
+    fn distill<'a>(&self) -> XmlNode<'a> {
+        let dedup_cols = Pretty::Array(
+            self.dedup_cols()
+                .iter()
+                .map(|i| FieldDisplay(self.input().schema().fields.get(*i).unwrap()))
+                .map(|fd| Pretty::display(&fd))
+                .collect(),
+        );
+        let mut vec = vec![("dedup_cols", dedup_cols)];
+        if self.fused_hop_window {
+            vec.push(("fused", Pretty::from("hop_window")));
+        }
+        childless_record("LogicalDedup", vec)
+    }
+}
@@ -20,8 +20,8 @@ use risingwave_common::types::DataType;
 use super::generic::GenericPlanRef;
 use super::utils::impl_distill_by_unit;
 use super::{
-    ColPrunable, ExprRewritable, Logical, LogicalPlanRef as PlanRef, LogicalProject, PlanBase,
-    PlanTreeNodeUnary, PredicatePushdown, ToBatch, ToStream, generic,
+    ColPrunable, ExprRewritable, Logical, LogicalPlanRef as PlanRef, LogicalProject, LogicalValues,
+    PlanBase, PlanTreeNodeUnary, PredicatePushdown, ToBatch, ToStream, generic,
 };
 use crate::error::Result;
 use crate::expr::{
@@ -55,10 +55,19 @@ impl LogicalFilter {
         LogicalFilter { base, core }
     }
 
-    /// Create a `LogicalFilter` unless the predicate is always true
+    /// Create a `LogicalFilter` unless the predicate is a constant.
+    ///
+    /// - `true` drops the filter entirely, returning `input` unchanged.
+    /// - `false` prunes the whole input subtree, returning an empty [`LogicalValues`] of the
+    ///   same schema instead. Note this only applies to a predicate that is the *boolean*
+    ///   `false`, not a `NULL` one: a `NULL` predicate also filters out every row, but it isn't
+    ///   provably constant the way `false` is (e.g. streaming retract semantics still need to
+    ///   evaluate it as a real filter), so [`Condition::always_false`] deliberately excludes it.
     pub fn create(input: PlanRef, predicate: Condition) -> PlanRef {
         if predicate.always_true() {
             input
+        } else if predicate.always_false() {
+            LogicalValues::new(vec![], input.schema().clone(), input.ctx()).into()
         } else {
             LogicalFilter::new(input, predicate).into()
         }
@@ -87,6 +96,12 @@ impl LogicalFilter {
     pub fn predicate(&self) -> &Condition {
         &self.core.predicate
     }
+
+    /// Estimate the fraction of input rows this filter lets through, absent column statistics.
+    /// See [`Condition::estimated_selectivity`].
+    pub fn estimated_selectivity(&self) -> f64 {
+        self.predicate().estimated_selectivity()
+    }
 }
 
 impl PlanTreeNodeUnary<Logical> for LogicalFilter {
@@ -506,4 +521,50 @@ mod tests {
         .collect();
         assert_eq!(fd_set, expected_fd_set);
     }
+
+    #[tokio::test]
+    async fn test_create_with_constant_false_predicate() {
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![Field::with_name(DataType::Int32, "v1")];
+        let values: PlanRef =
+            LogicalValues::new(vec![], Schema { fields: fields.clone() }, ctx).into();
+
+        let filter =
+            LogicalFilter::create(values, Condition::with_expr(ExprImpl::literal_bool(false)));
+
+        let values = filter
+            .as_logical_values()
+            .expect("a constant-false predicate should prune the input to an empty LogicalValues");
+        assert!(values.rows().is_empty());
+        assert_eq!(values.schema().fields(), &fields[..]);
+    }
+
+    #[tokio::test]
+    async fn test_create_with_constant_true_predicate() {
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![Field::with_name(DataType::Int32, "v1")];
+        let values: PlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+
+        let filter = LogicalFilter::create(
+            values.clone(),
+            Condition::with_expr(ExprImpl::literal_bool(true)),
+        );
+
+        // a constant-true predicate drops the filter entirely
+        assert_eq!(filter, values);
+    }
+
+    #[tokio::test]
+    async fn test_create_with_null_predicate_is_not_folded_away() {
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![Field::with_name(DataType::Int32, "v1")];
+        let values: PlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+
+        let null_predicate = ExprImpl::Literal(Box::new(Literal::new(None, DataType::Boolean)));
+        let filter = LogicalFilter::create(values, Condition::with_expr(null_predicate));
+
+        // a NULL predicate also filters out every row, but it's not the constant `false`, so it
+        // must still be a real `LogicalFilter` rather than a statically empty `LogicalValues`.
+        assert!(filter.as_logical_filter().is_some());
+    }
 }
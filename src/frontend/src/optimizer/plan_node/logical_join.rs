@@ -1355,7 +1355,16 @@ impl LogicalJoin {
 
         // If there is exactly one predicate, it is a comparison (<, <=, >, >=), and the
         // join is a `Inner` or `LeftSemi` join, we can convert the scalar subquery into a
-        // `StreamDynamicFilter`
+        // `StreamDynamicFilter`. If the right side has two columns instead and there are
+        // exactly two predicates forming a lower (>, >=) and upper (<, <=) bound pair against
+        // the same left column, e.g. `col BETWEEN lo AND hi` where `lo`/`hi` are each a
+        // correlated scalar subquery, we can convert into a `StreamDynamicFilter` with two
+        // dynamic bounds.
+        //
+        // This is also the path that temporal filters (e.g. `WHERE ts > now() - interval
+        // '1 hour'`) end up going through: `FilterWithNowToJoinRule` rewrites them into a
+        // `LeftSemi` join against a `LogicalNow`, whose right side always has exactly one row,
+        // so it is recognized here just like any other correlated scalar subquery.
 
         // Check if `Inner`/`LeftSemi`
         if !matches!(self.join_type(), JoinType::Inner | JoinType::LeftSemi) {
@@ -1366,33 +1375,83 @@ impl LogicalJoin {
         if !self.right().max_one_row() {
             return Ok(None);
         }
-        if self.right().schema().len() != 1 {
+        let right_len = self.right().schema().len();
+        if right_len != 1 && right_len != 2 {
             return Ok(None);
         }
 
-        // Check if the join condition is a correlated comparison
-        if predicate.conjunctions.len() > 1 {
+        // Check if the join condition is a correlated comparison (or, for a two-column right
+        // side, a lower/upper bound pair of correlated comparisons)
+        if predicate.conjunctions.len() != right_len {
             return Ok(None);
         }
-        let expr: ExprImpl = predicate.into();
-        let (left_ref, comparator, right_ref) = match expr.as_comparison_cond() {
-            Some(v) => v,
-            None => return Ok(None),
-        };
 
-        let condition_cross_inputs = left_ref.index < self.left().schema().len()
-            && right_ref.index == self.left().schema().len() /* right side has only one column */;
-        if !condition_cross_inputs {
-            // Maybe we should panic here because it means some predicates are not pushed down.
-            return Ok(None);
-        }
+        let (left_index, comparator, upper_comparator) = if right_len == 1 {
+            let expr: ExprImpl = predicate.into();
+            let (left_ref, comparator, right_ref) = match expr.as_comparison_cond() {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+
+            let condition_cross_inputs = left_ref.index < self.left().schema().len()
+                && right_ref.index == self.left().schema().len() /* right side has only one column */;
+            if !condition_cross_inputs {
+                // Maybe we should panic here because it means some predicates are not pushed down.
+                return Ok(None);
+            }
+            (left_ref.index, comparator, None)
+        } else {
+            let mut lower = None;
+            let mut upper = None;
+            for conjunction in &predicate.conjunctions {
+                let (left_ref, comparator, right_ref) = match conjunction.as_comparison_cond() {
+                    Some(v) => v,
+                    None => return Ok(None),
+                };
+                if left_ref.index >= self.left().schema().len() {
+                    return Ok(None);
+                }
+                let Some(right_index) = right_ref.index.checked_sub(self.left().schema().len())
+                else {
+                    return Ok(None);
+                };
+                match (comparator, right_index) {
+                    (ExprType::GreaterThan | ExprType::GreaterThanOrEqual, 0) => {
+                        if lower.replace((left_ref.index, comparator)).is_some() {
+                            return Ok(None);
+                        }
+                    }
+                    (ExprType::LessThan | ExprType::LessThanOrEqual, 1) => {
+                        if upper.replace((left_ref.index, comparator)).is_some() {
+                            return Ok(None);
+                        }
+                    }
+                    _ => return Ok(None),
+                }
+            }
+            let (Some((lower_index, lower_comparator)), Some((upper_index, upper_comparator))) =
+                (lower, upper)
+            else {
+                return Ok(None);
+            };
+            if lower_index != upper_index {
+                return Ok(None);
+            }
+            (lower_index, lower_comparator, Some(upper_comparator))
+        };
 
         // We align input types on all join predicates with cmp operator
-        if self.left().schema().fields()[left_ref.index].data_type
+        if self.left().schema().fields()[left_index].data_type
             != self.right().schema().fields()[0].data_type
         {
             return Ok(None);
         }
+        if upper_comparator.is_some()
+            && self.left().schema().fields()[left_index].data_type
+                != self.right().schema().fields()[1].data_type
+        {
+            return Ok(None);
+        }
 
         // Check if non of the columns from the inner side is required to output
         let all_output_from_left = self
@@ -1415,7 +1474,12 @@ impl LogicalJoin {
             Distribution::Single
         );
 
-        let core = DynamicFilter::new(comparator, left_ref.index, left, right);
+        let core = match upper_comparator {
+            Some(upper_comparator) => {
+                DynamicFilter::new_between(comparator, upper_comparator, left_index, left, right)
+            }
+            None => DynamicFilter::new(comparator, left_index, left, right),
+        };
         let plan = StreamDynamicFilter::new(core)?.into();
         // TODO: `DynamicFilterExecutor` should support `output_indices` in `ChunkBuilder`
         if self
@@ -15,6 +15,7 @@
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use pretty_xmlish::{Pretty, XmlNode};
 use risingwave_common::catalog::Schema;
@@ -439,13 +440,27 @@ impl LogicalMultiJoin {
         // Sort in decreasing order of len
         edge_sets.sort_by_key(|a| std::cmp::Reverse(a.len()));
 
+        // Absent column statistics, use each input's estimated filter selectivity (from the
+        // shape of any single-relation predicates in `self.on`) as a heuristic: relations that
+        // are filtered more aggressively are joined first, pushing them towards the build side
+        // of the resulting left-deep join.
+        let input_selectivity = self.input_selectivity();
+
         let mut join_ordering = vec![];
 
         for component in edge_sets {
             let mut eq_cond_edges: Vec<(usize, usize)> = component.into_iter().collect();
 
-            // TODO(jon-chuang): add sorting of eq_cond_edges based on selectivity here
-            eq_cond_edges.sort();
+            // Prefer edges whose endpoints are more selectively filtered; break ties
+            // deterministically by the original lexicographic ordering.
+            eq_cond_edges.sort_by(|a, b| {
+                let selectivity = |edge: &(usize, usize)| {
+                    input_selectivity[edge.0].min(input_selectivity[edge.1])
+                };
+                selectivity(a)
+                    .total_cmp(&selectivity(b))
+                    .then_with(|| a.cmp(b))
+            });
 
             if eq_cond_edges.is_empty() {
                 // There is nothing to join in this connected component
@@ -453,7 +468,14 @@ impl LogicalMultiJoin {
             };
 
             let edge = eq_cond_edges.remove(0);
-            join_ordering.extend(&vec![edge.0, edge.1]);
+            // Seed the chain with the more selective endpoint first, so a highly-filtered
+            // relation lands at the base of the left-deep join (its build side).
+            let seed = if input_selectivity[edge.0] <= input_selectivity[edge.1] {
+                [edge.0, edge.1]
+            } else {
+                [edge.1, edge.0]
+            };
+            join_ordering.extend(&seed);
 
             while !eq_cond_edges.is_empty() {
                 let mut found = vec![];
@@ -670,6 +692,40 @@ impl LogicalMultiJoin {
         self.inputs.iter().map(|i| i.schema().len()).collect()
     }
 
+    /// Estimate, for each input, the fraction of its rows that survive the single-relation
+    /// filter predicates in `self.on` (predicates referencing only that input's columns).
+    /// Inputs with no such predicate default to `1.0` (no filtering).
+    ///
+    /// Used by [`Self::heuristic_ordering`] to prefer joining highly-selective relations first,
+    /// pushing them towards the build side of the resulting left-deep join.
+    fn input_selectivity(&self) -> Vec<f64> {
+        let input_col_nums = self.input_col_nums();
+        let mut bitmaps = Vec::with_capacity(input_col_nums.len());
+        let mut cols_seen = 0;
+        for cols in &input_col_nums {
+            bitmaps.push(FixedBitSet::from_iter(cols_seen..cols_seen + cols));
+            cols_seen += cols;
+        }
+
+        let mut selectivity = vec![1.0; input_col_nums.len()];
+        for expr in &self.on.conjunctions {
+            let input_bits = expr.collect_input_refs(cols_seen);
+            let touched: Vec<usize> = bitmaps
+                .iter()
+                .enumerate()
+                .filter(|(_, bitmap)| !input_bits.is_disjoint(bitmap))
+                .map(|(idx, _)| idx)
+                .collect();
+            if let [idx] = touched[..] {
+                selectivity[idx] *= Condition {
+                    conjunctions: vec![expr.clone()],
+                }
+                .estimated_selectivity();
+            }
+        }
+        selectivity
+    }
+
     /// get join graph from `self.on`, return the join graph and the new join condition.
     fn get_join_graph(&self) -> Result<(BTreeMap<usize, GraphNode>, Condition)> {
         let mut nodes: BTreeMap<_, _> = (0..self.inputs.len())
@@ -1004,4 +1060,53 @@ mod test {
             .collect();
         assert_eq!(expected_fd_set, fd_set);
     }
+
+    #[tokio::test]
+    async fn heuristic_ordering_prefers_selective_relation_as_build_side() {
+        // t1: [v0], t2: [v1], t3: [v2]
+        // Join graph (a chain): v0 = v1, v1 = v2
+        // A highly selective equality filter on t3 (v2 = 5) should cause the reorderer to seed
+        // the left-deep chain with t3, rather than following the plain lexicographic order that
+        // would otherwise start with t1 and t2.
+        let ctx = OptimizerContext::mock().await;
+        let mk_table = |name: &str| {
+            let fields: Vec<Field> = vec![Field::with_name(DataType::Int32, name)];
+            LogicalValues::new(vec![], Schema { fields }, ctx.clone())
+        };
+        let t1 = mk_table("v0");
+        let t2 = mk_table("v1");
+        let t3 = mk_table("v2");
+
+        let eq_join = |l: usize, r: usize| {
+            FunctionCall::new(
+                Type::Equal,
+                vec![
+                    InputRef::new(l, DataType::Int32).into(),
+                    InputRef::new(r, DataType::Int32).into(),
+                ],
+            )
+            .unwrap()
+            .into()
+        };
+        let selective_filter: ExprImpl = FunctionCall::new(
+            Type::Equal,
+            vec![InputRef::new(2, DataType::Int32).into(), ExprImpl::literal_int(5)],
+        )
+        .unwrap()
+        .into();
+
+        let multi_join = LogicalMultiJoin::new(
+            vec![t1.into(), t2.into(), t3.into()],
+            Condition {
+                conjunctions: vec![eq_join(0, 1), eq_join(1, 2), selective_filter],
+            },
+            vec![0, 1, 2],
+        );
+
+        let ordering = multi_join.heuristic_ordering().unwrap();
+        assert_eq!(
+            ordering[0], 2,
+            "the selectively-filtered relation should be seeded first (build side), got {ordering:?}"
+        );
+    }
 }
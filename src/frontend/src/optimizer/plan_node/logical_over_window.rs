@@ -603,6 +603,9 @@ impl PredicatePushdown for LogicalOverWindow {
             return LogicalFilter::create(self.clone().into(), predicate);
         }
 
+        // A conjunct is safe to push below the window only if it depends solely on the
+        // partition-key input columns; anything touching a window output column (or any other
+        // input column) must be retained above as a `LogicalFilter`.
         let all_out_cols: FixedBitSet = (0..self.schema().len()).collect();
         let mut remain_cols: FixedBitSet = all_out_cols
             .difference(&self.partition_key_indices().into_iter().collect())
@@ -502,4 +502,53 @@ mod tests {
         assert_eq!(values.schema().fields()[0], fields[0]);
         assert_eq!(values.schema().fields()[1], fields[2]);
     }
+
+    #[tokio::test]
+    async fn test_is_identity() {
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![
+            Field::with_name(ty.clone(), "v1"),
+            Field::with_name(ty.clone(), "v2"),
+        ];
+        let values: PlanRef = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields.clone(),
+            },
+            ctx,
+        )
+        .into();
+
+        let identity = LogicalProject::new(
+            values.clone(),
+            vec![
+                InputRef::new(0, ty.clone()).into(),
+                InputRef::new(1, ty.clone()).into(),
+            ],
+        );
+        assert!(identity.is_identity());
+
+        let reordered = LogicalProject::new(
+            values.clone(),
+            vec![
+                InputRef::new(1, ty.clone()).into(),
+                InputRef::new(0, ty.clone()).into(),
+            ],
+        );
+        assert!(!reordered.is_identity());
+
+        // Same expressions as `identity`, but with an output column renamed: not an identity,
+        // since collapsing it into its input would lose the rename.
+        let mut renamed_core = generic::Project::new(
+            vec![
+                InputRef::new(0, ty.clone()).into(),
+                InputRef::new(1, ty).into(),
+            ],
+            values,
+        );
+        renamed_core.field_names.insert(0, "renamed".to_owned());
+        let renamed = LogicalProject::with_core(renamed_core);
+        assert!(!renamed.is_identity());
+    }
 }
@@ -0,0 +1,191 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pretty_xmlish::XmlNode;
+use risingwave_pb::expr::expr_node::Type as ExprType;
+
+use super::generic::{DistillUnit, SampleMethod};
+use super::utils::Distill;
+use super::{
+    ColPrunable, ExprRewritable, Logical, LogicalFilter, LogicalPlanRef as PlanRef, LogicalValues,
+    PlanBase, PlanTreeNodeUnary, PredicatePushdown, ToBatch, ToStream, generic,
+};
+use crate::error::Result;
+use crate::expr::{ExprImpl, FunctionCall};
+use crate::optimizer::plan_node::expr_visitable::ExprVisitable;
+use crate::optimizer::plan_node::{
+    ColumnPruningContext, PredicatePushdownContext, RewriteStreamContext, ToStreamContext,
+};
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalSample` implements `TABLESAMPLE`, keeping roughly `fraction` percent of the rows
+/// produced by its input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LogicalSample {
+    pub base: PlanBase<Logical>,
+    core: generic::Sample<PlanRef>,
+}
+
+impl LogicalSample {
+    pub fn new(input: PlanRef, method: SampleMethod, fraction: f64) -> Self {
+        let core = generic::Sample::new(input, method, fraction);
+        let base = PlanBase::new_logical_with_core(&core);
+        LogicalSample { base, core }
+    }
+
+    /// Creates a [`LogicalSample`], but immediately optimizes away trivial fractions:
+    /// `fraction <= 0` becomes an empty [`LogicalValues`] without touching the input, and
+    /// `fraction >= 100` is a no-op that returns the input untouched.
+    pub fn create(input: PlanRef, method: SampleMethod, fraction: f64) -> PlanRef {
+        if fraction <= 0.0 {
+            LogicalValues::new(vec![], input.schema().clone(), input.ctx()).into()
+        } else if fraction >= 100.0 {
+            input
+        } else {
+            Self::new(input, method, fraction).into()
+        }
+    }
+
+    pub fn method(&self) -> SampleMethod {
+        self.core.method
+    }
+
+    pub fn fraction(&self) -> f64 {
+        self.core.fraction
+    }
+
+    /// Builds the `random() * 100 < fraction` gate used to sample rows at execution time.
+    fn sample_condition(&self) -> Result<Condition> {
+        let random_pct = FunctionCall::new(
+            ExprType::Multiply,
+            vec![
+                FunctionCall::new(ExprType::Random, vec![])?.into(),
+                ExprImpl::literal_f64(100.0),
+            ],
+        )?;
+        let lt_fraction = FunctionCall::new(
+            ExprType::LessThan,
+            vec![random_pct.into(), ExprImpl::literal_f64(self.core.fraction)],
+        )?;
+        Ok(Condition::with_expr(ExprImpl::from(lt_fraction)))
+    }
+}
+
+impl PlanTreeNodeUnary<Logical> for LogicalSample {
+    fn input(&self) -> PlanRef {
+        self.core.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.core.method, self.core.fraction)
+    }
+
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        (self.clone_with_input(input), input_col_change)
+    }
+}
+impl_plan_tree_node_for_unary! { Logical, LogicalSample }
+
+impl Distill for LogicalSample {
+    fn distill<'a>(&self) -> XmlNode<'a> {
+        self.core.distill_with_name("LogicalSample")
+    }
+}
+
+impl ColPrunable for LogicalSample {
+    fn prune_col(&self, required_cols: &[usize], ctx: &mut ColumnPruningContext) -> PlanRef {
+        // The sampling gate is row-wise and does not reference any column, so required columns
+        // pass straight through to the input.
+        let new_input = self.input().prune_col(required_cols, ctx);
+        self.clone_with_input(new_input).into()
+    }
+}
+
+impl ExprRewritable<Logical> for LogicalSample {}
+
+impl ExprVisitable for LogicalSample {}
+
+impl PredicatePushdown for LogicalSample {
+    fn predicate_pushdown(
+        &self,
+        predicate: Condition,
+        ctx: &mut PredicatePushdownContext,
+    ) -> PlanRef {
+        // The predicate is independent of sampling, so it can be pushed below unconditionally.
+        let new_input = self.input().predicate_pushdown(predicate, ctx);
+        self.clone_with_input(new_input).into()
+    }
+}
+
+impl ToBatch for LogicalSample {
+    fn to_batch(&self) -> Result<crate::optimizer::plan_node::BatchPlanRef> {
+        // No dedicated batch executor exists for sampling yet, so we lower to a `BatchFilter`
+        // with a synthesized `random() * 100 < fraction` gate, applied per row.
+        let logical_filter = LogicalFilter::create(self.input(), self.sample_condition()?);
+        logical_filter.to_batch()
+    }
+}
+
+impl ToStream for LogicalSample {
+    fn to_stream(
+        &self,
+        _ctx: &mut ToStreamContext,
+    ) -> Result<crate::optimizer::plan_node::StreamPlanRef> {
+        risingwave_common::bail!("TABLESAMPLE is not supported in streaming mode")
+    }
+
+    fn logical_rewrite_for_stream(
+        &self,
+        _ctx: &mut RewriteStreamContext,
+    ) -> Result<(PlanRef, ColIndexMapping)> {
+        risingwave_common::bail!("TABLESAMPLE is not supported in streaming mode")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+
+    use super::LogicalSample;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::generic::SampleMethod;
+    use crate::optimizer::plan_node::{LogicalPlanRef, LogicalValues};
+
+    #[tokio::test]
+    async fn test_create_trivial_fraction() {
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![Field::with_name(ty.clone(), "v1")];
+        let values: LogicalPlanRef =
+            LogicalValues::new(vec![], Schema { fields }, ctx).into();
+
+        // fraction <= 0 must not touch the input: it becomes an empty `LogicalValues`.
+        let empty = LogicalSample::create(values.clone(), SampleMethod::Bernoulli, 0.0);
+        assert!(empty.as_logical_values().is_some());
+
+        // fraction >= 100 is a no-op.
+        let unchanged = LogicalSample::create(values.clone(), SampleMethod::Bernoulli, 100.0);
+        assert_eq!(unchanged, values);
+
+        // Anything in between actually creates a `LogicalSample`.
+        let sampled = LogicalSample::create(values, SampleMethod::Bernoulli, 10.0);
+        assert!(sampled.as_logical_sample().is_some());
+    }
+}
+
@@ -15,6 +15,7 @@
 use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use pretty_xmlish::{Pretty, XmlNode};
 use risingwave_common::catalog::{ColumnDesc, Schema};
@@ -373,6 +374,31 @@ impl LogicalScan {
     pub fn required_col_idx(&self) -> &Vec<usize> {
         &self.core.required_col_idx
     }
+
+    /// Unions the table's primary key columns into this scan's output columns, in case a
+    /// downstream operator (e.g. `LogicalDedup`, `StreamMaterialize`) needs them present even
+    /// though the user didn't select them.
+    ///
+    /// Returns the augmented scan together with a bitset over the *new* output schema, with a
+    /// bit set for every column that was already visible in the original output (i.e. the
+    /// complement of the pk columns just added). Pass it directly to
+    /// [`LogicalProject::with_out_fields`] to strip the newly-added pk columns back out once the
+    /// downstream operator no longer needs them.
+    pub fn with_required_pk(&self) -> (Self, FixedBitSet) {
+        let mut output_col_idx = self.output_col_idx().clone();
+        let mut out_fields = FixedBitSet::with_capacity(output_col_idx.len());
+        out_fields.insert_range(..);
+
+        for pk_column_order in self.primary_key() {
+            let table_col_idx = pk_column_order.column_index;
+            if !output_col_idx.contains(&table_col_idx) {
+                output_col_idx.push(table_col_idx);
+                out_fields.grow(out_fields.len() + 1);
+            }
+        }
+
+        (self.clone_with_output_indices(output_col_idx), out_fields)
+    }
 }
 
 impl_plan_tree_node_for_leaf! { Logical, LogicalScan}
@@ -725,3 +751,108 @@ impl ToStream for LogicalScan {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::ColumnId;
+    use risingwave_common::hash::VnodeCount;
+    use risingwave_common::types::DataType;
+    use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, PbEngine, PbTableVersion};
+    use risingwave_pb::catalog::{PbCreateType, PbStreamJobStatus, PbTable, PbTableType};
+    use risingwave_pb::plan_common::PbColumnCatalog;
+
+    use super::*;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+
+    fn test_table_catalog() -> TableCatalog {
+        let columns = [
+            ColumnDesc::named("id", ColumnId::new(0), DataType::Int32),
+            ColumnDesc::named("v1", ColumnId::new(1), DataType::Int32),
+            ColumnDesc::named("v2", ColumnId::new(2), DataType::Int32),
+        ];
+        PbTable {
+            id: 0,
+            schema_id: 0,
+            database_id: 0,
+            name: "t".to_owned(),
+            table_type: PbTableType::Table as i32,
+            columns: columns
+                .into_iter()
+                .map(|c| PbColumnCatalog {
+                    column_desc: Some(c.to_protobuf()),
+                    is_hidden: false,
+                })
+                .collect(),
+            pk: vec![ColumnOrder::new(0, OrderType::ascending()).to_protobuf()],
+            stream_key: vec![0],
+            distribution_key: vec![0],
+            optional_associated_source_id: OptionalAssociatedSourceId::AssociatedSourceId(0)
+                .into(),
+            append_only: false,
+            owner: risingwave_common::catalog::DEFAULT_SUPER_USER_ID,
+            retention_seconds: None,
+            fragment_id: 0,
+            dml_fragment_id: None,
+            initialized_at_epoch: None,
+            value_indices: vec![0, 1, 2],
+            definition: "".into(),
+            read_prefix_len_hint: 0,
+            vnode_col_index: None,
+            row_id_index: None,
+            version: Some(PbTableVersion {
+                version: 0,
+                next_column_id: 3,
+            }),
+            watermark_indices: vec![],
+            handle_pk_conflict_behavior: 3,
+            dist_key_in_pk: vec![0],
+            cardinality: None,
+            created_at_epoch: None,
+            cleaned_by_watermark: false,
+            stream_job_status: PbStreamJobStatus::Created.into(),
+            create_type: PbCreateType::Foreground.into(),
+            description: None,
+            #[expect(deprecated)]
+            incoming_sinks: vec![],
+            created_at_cluster_version: None,
+            initialized_at_cluster_version: None,
+            version_column_indices: Vec::new(),
+            cdc_table_id: None,
+            maybe_vnode_count: VnodeCount::set(1).to_protobuf(),
+            webhook_info: None,
+            job_id: None,
+            engine: Some(PbEngine::Hummock as i32),
+            clean_watermark_index_in_pk: None,
+            refreshable: false,
+            vector_index_info: None,
+            cdc_table_type: None,
+            refresh_state: Some(risingwave_pb::catalog::RefreshState::Idle as i32),
+        }
+        .into()
+    }
+
+    #[tokio::test]
+    async fn test_with_required_pk() {
+        let ctx = OptimizerContext::mock().await;
+        let table_catalog = test_table_catalog();
+
+        let scan = LogicalScan::create(Arc::new(table_catalog), ctx, None);
+        // Simulate the user only selecting the non-pk column `v1` (table column index 1).
+        let scan = scan.clone_with_output_indices(vec![1]);
+
+        let (scan_with_pk, out_fields) = scan.with_required_pk();
+
+        // The pk column (table index 0) is appended after the originally selected column.
+        assert_eq!(scan_with_pk.output_col_idx(), &vec![1, 0]);
+        // The originally selected column stays visible, the newly added pk column is hidden.
+        assert!(out_fields[0]);
+        assert!(!out_fields[1]);
+
+        // Selecting a column that already covers the pk is a no-op.
+        let scan_with_pk_already_selected = scan_with_pk.with_required_pk();
+        assert_eq!(
+            scan_with_pk_already_selected.0.output_col_idx(),
+            scan_with_pk.output_col_idx()
+        );
+    }
+}
@@ -16,6 +16,7 @@ use pretty_xmlish::{Pretty, XmlNode};
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::types::DataType;
 
+use super::generic::GenericPlanRef;
 use super::utils::{Distill, childless_record};
 use super::{
     ColPrunable, ExprRewritable, Logical, LogicalFilter, LogicalPlanRef as PlanRef, LogicalProject,
@@ -89,8 +90,20 @@ impl Distill for LogicalTableFunction {
 
 impl ColPrunable for LogicalTableFunction {
     fn prune_col(&self, required_cols: &[usize], _ctx: &mut ColumnPruningContext) -> PlanRef {
-        // No pruning.
-        LogicalProject::with_out_col_idx(self.clone().into(), required_cols.iter().copied()).into()
+        // The function call itself always produces its full row (we can't ask e.g.
+        // `jsonb_each` to compute only some of the fields it returns), so in general pruning
+        // just means wrapping in a project that picks out `required_cols`.
+        //
+        // The `ordinality` column is the one exception: it's appended by this node itself
+        // rather than being part of the function's output, so when it's not required we can
+        // avoid generating it at all instead of merely hiding it behind the outer project.
+        let ordinality_idx = self.base.schema().len() - 1;
+        let node = if self.with_ordinality && !required_cols.contains(&ordinality_idx) {
+            LogicalTableFunction::new(self.table_function.clone(), false, self.base.ctx()).into()
+        } else {
+            self.clone().into()
+        };
+        LogicalProject::with_out_col_idx(node, required_cols.iter().copied()).into()
     }
 }
 
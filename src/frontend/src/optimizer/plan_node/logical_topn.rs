@@ -299,6 +299,18 @@ impl ToBatch for LogicalTopN {
     fn to_batch(&self) -> Result<crate::optimizer::plan_node::BatchPlanRef> {
         let new_input = self.input().to_batch()?;
         let core = self.core.clone_with_input(new_input);
+        // The choice between `BatchTopN` and `BatchGroupTopN` is made once here, at the logical
+        // level, based on whether the query is a genuine per-group top-N (e.g. lowered from
+        // `DISTINCT ON` or a `row_number() OVER (PARTITION BY ...)` filter by
+        // `OverWindowToTopNRule`) or a plain global `LIMIT`. There's no sound way to recover this
+        // distinction later by pattern-matching the batch plan shape: an ungrouped `BatchTopN`
+        // sitting above a hash exchange whose key happens to be a prefix of the order is not
+        // necessarily scoped to that key's groups, e.g. it may be the local phase of a two-phase
+        // `ORDER BY ... LIMIT n` (see `BatchTopN::two_phase_topn`), where every row on the shard,
+        // regardless of its value for that column, competes for the same limit. Rewriting such a
+        // `BatchTopN` into a `BatchGroupTopN` after the fact would silently change `LIMIT`
+        // semantics, so the two plan nodes are never unified once the group/no-group choice has
+        // been made here.
         if self.group_key().is_empty() {
             Ok(BatchTopN::new(core).into())
         } else {
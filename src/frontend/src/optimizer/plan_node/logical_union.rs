@@ -45,6 +45,16 @@ pub struct LogicalUnion {
 }
 
 impl LogicalUnion {
+    /// Note: it's tempting to downgrade a distinct union (`all == false`) to `UNION ALL` whenever
+    /// every input's [`FunctionalDependencySet`] already shows it's a key over its own output
+    /// columns (i.e. each input is individually distinct). That's unsound: per-input distinctness
+    /// says nothing about whether the *same* row can appear in two different inputs, e.g.
+    /// `SELECT DISTINCT a FROM t1 UNION SELECT DISTINCT a FROM t2` still needs the cross-input
+    /// dedup whenever `t1` and `t2` can share a value of `a`. Proving the inputs are also disjoint
+    /// would need more than functional dependencies (e.g. disjoint value ranges), so the dedup for
+    /// `all == false` is always kept here regardless of each input's own distinctness.
+    ///
+    /// [`FunctionalDependencySet`]: crate::optimizer::property::FunctionalDependencySet
     pub fn new(all: bool, inputs: Vec<PlanRef>) -> Self {
         assert!(Schema::all_type_eq(inputs.iter().map(|x| x.schema())));
         Self::new_with_source_col(all, inputs, None)
@@ -393,4 +403,34 @@ mod tests {
 
         assert_eq!(union.inputs().len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_union_keeps_dedup_when_each_input_already_distinct() {
+        use crate::optimizer::plan_node::generic;
+        use crate::utils::IndexSet;
+
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![Field::with_name(ty.clone(), "v1")];
+        let values1 = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let values2 = values1.clone();
+
+        // Each side is `SELECT DISTINCT v1 FROM ...`, i.e. individually distinct on its (only)
+        // output column, via a group-by with no aggregate calls.
+        let distinct1: PlanRef =
+            generic::Agg::new(vec![], IndexSet::from(vec![0]), values1.into()).into();
+        let distinct2: PlanRef =
+            generic::Agg::new(vec![], IndexSet::from(vec![0]), values2.into()).into();
+        assert!(
+            distinct1
+                .functional_dependency()
+                .is_key(&(0..distinct1.schema().len()).collect_vec())
+        );
+
+        // The union must still dedup: per-input distinctness doesn't rule out the same value
+        // showing up on both sides.
+        let union = LogicalUnion::new(false, vec![distinct1, distinct2]);
+        let plan = union.to_batch().unwrap();
+        assert!(plan.as_batch_hash_agg().is_some());
+    }
 }
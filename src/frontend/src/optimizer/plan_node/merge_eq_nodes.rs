@@ -135,6 +135,17 @@ impl Endo<PlanRef> for Pruner<'_> {
             // Prune if share node has only one parent
             // or it just shares a scan
             // or it doesn't share any scan or source.
+            //
+            // A bare `LogicalScan` (e.g. two identical scans of the same table produced by a
+            // self-join) is deliberately pruned back into duplicate scans rather than kept
+            // shared: unlike a computed subquery, re-scanning a table costs nothing extra (both
+            // scans read the same persisted, storage-backed data independently), while keeping
+            // it shared would force a `StreamShare`/exchange boundary between the join's two
+            // sides for no benefit. `LogicalSource`s are not pruned this way because re-reading a
+            // source (e.g. Kafka) a second time would duplicate or lose events, so sharing there
+            // is required for correctness, not just an optimization; see `ShareSourceRewriter`
+            // for the `enable_share_plan = false` path, and the `self_join`/
+            // `force_share_source_for_self_join` cases in `share.yaml` for both behaviors.
             *self.counts.get(&s.id()).expect("Unprocessed shared node.") == 1
                 || s.input().as_logical_scan().is_some()
                 || !(plan_visitor::has_logical_scan(s.input())
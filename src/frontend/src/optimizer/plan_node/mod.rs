@@ -27,7 +27,7 @@
 //! - all field should be valued in construction, so the properties' derivation should be finished
 //!   in the `new()` function.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
@@ -63,6 +63,15 @@ use crate::utils::{PrettySerde, build_graph_from_pretty};
 /// A marker trait for different conventions, used for enforcing type safety.
 ///
 /// Implementors are [`Logical`], [`Batch`], and [`Stream`].
+///
+/// Note that the convention of a [`PlanRef<C>`] is already encoded in its type parameter `C` and
+/// checked at compile time, so passes that only operate on e.g. [`StreamPlanRef`] simply take that
+/// type in their signature: there is no way for a [`LogicalPlanRef`] to be passed where a
+/// [`StreamPlanRef`] is expected, and thus no `expect_*`-style runtime assertion is needed (or
+/// possible, since the wrong convention is a type error rather than a value that exists at
+/// runtime). What *does* still need runtime downcasting, and can still panic on a programmer
+/// error, is going from a convention-level [`PlanRef<C>`] to a concrete node type within that
+/// convention, e.g. `plan.as_stream_filter()`.
 pub trait ConventionMarker: 'static + Sized + Clone + Debug + Eq + PartialEq + Hash {
     /// The extra fields in the [`PlanBase`] of this convention.
     type Extra: 'static + Eq + Hash + Clone + Debug;
@@ -361,6 +370,114 @@ pub trait VisitPlan: Visit<LogicalPlanRef> {
     }
 }
 
+impl LogicalPlanRef {
+    /// Whether this subtree contains a node for which `predicate` returns `true`, short-circuiting
+    /// as soon as a match is found. Built on [`VisitPlan`]/`dag_visit`, so a shared subplan (behind
+    /// a `LogicalShare`) is only visited once no matter how many parents reference it.
+    pub fn subtree_contains(&self, predicate: impl Fn(&dyn LogicalPlanNode) -> bool) -> bool {
+        struct Finder<P> {
+            predicate: P,
+            visited_shares: HashSet<PlanNodeId>,
+            found: bool,
+        }
+
+        impl<P: Fn(&dyn LogicalPlanNode) -> bool> Visit<LogicalPlanRef> for Finder<P> {
+            fn visit(&mut self, t: &LogicalPlanRef) {
+                if self.found {
+                    return;
+                }
+                if (self.predicate)(t.deref()) {
+                    self.found = true;
+                    return;
+                }
+                self.dag_visit(t);
+            }
+        }
+
+        impl<P: Fn(&dyn LogicalPlanNode) -> bool> VisitPlan for Finder<P> {
+            fn visited<F>(&mut self, plan: &LogicalPlanRef, mut f: F)
+            where
+                F: FnMut(&mut Self),
+            {
+                if let Some(share) = plan.as_logical_share()
+                    && !self.visited_shares.insert(share.id())
+                {
+                    return;
+                }
+                f(self);
+            }
+        }
+
+        let mut finder = Finder {
+            predicate,
+            visited_shares: HashSet::new(),
+            found: false,
+        };
+        finder.visit(self);
+        finder.found
+    }
+
+    /// Renders this plan as a GraphViz DOT digraph: one node per distinct [`PlanNodeId`], labeled
+    /// with [`Distill::distill_to_string`], and one edge per parent-to-input pointer. Built on
+    /// [`VisitPlan`]/`dag_visit`, so a subplan shared behind a `LogicalShare` renders as a single
+    /// vertex with one incoming edge per parent, instead of being duplicated the way the plain
+    /// tree print does.
+    pub fn to_dot(&self) -> String {
+        struct DotBuilder {
+            visited_shares: HashSet<PlanNodeId>,
+            emitted: HashSet<PlanNodeId>,
+            node_lines: Vec<String>,
+            edges: Vec<(PlanNodeId, PlanNodeId)>,
+        }
+
+        impl Visit<LogicalPlanRef> for DotBuilder {
+            fn visit(&mut self, t: &LogicalPlanRef) {
+                if self.emitted.insert(t.id()) {
+                    self.node_lines
+                        .push(format!("  {} [label={:?}];", t.id().0, t.distill_to_string()));
+                    for input in t.inputs() {
+                        self.edges.push((t.id(), input.id()));
+                    }
+                }
+                self.dag_visit(t);
+            }
+        }
+
+        impl VisitPlan for DotBuilder {
+            fn visited<F>(&mut self, plan: &LogicalPlanRef, mut f: F)
+            where
+                F: FnMut(&mut Self),
+            {
+                if let Some(share) = plan.as_logical_share()
+                    && !self.visited_shares.insert(share.id())
+                {
+                    return;
+                }
+                f(self);
+            }
+        }
+
+        let mut builder = DotBuilder {
+            visited_shares: HashSet::new(),
+            emitted: HashSet::new(),
+            node_lines: vec![],
+            edges: vec![],
+        };
+        builder.visit(self);
+
+        let mut out = String::from("digraph plan {\n");
+        for line in &builder.node_lines {
+            out.push_str(line);
+            out.push('\n');
+        }
+        for (from, to) in &builder.edges {
+            out.push_str(&format!("  {} -> {};\n", from.0, to.0));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
 impl<C: ConventionMarker> PlanRef<C> {
     pub fn rewrite_exprs_recursive(&self, r: &mut impl ExprRewriter) -> PlanRef<C> {
         let new = self.rewrite_exprs(r);
@@ -709,6 +826,16 @@ pub trait Explain {
     /// Explain the plan node and return a string.
     fn explain_to_string(&self) -> String;
 
+    /// Explain the plan node and return a string, keeping each node's original [`PlanNodeId`]
+    /// instead of resetting ids for stable output.
+    ///
+    /// Unlike [`Self::explain_to_string`], this skips [`reorganize_elements_id`], so the ids
+    /// shown match the ones surfaced in runtime metrics/traces for the same plan. Because of
+    /// that, two calls to this method on plans that only differ by id-reorganization (e.g. one
+    /// built directly, one round-tripped through [`reorganize_elements_id`]) can print
+    /// different ids for otherwise-identical output.
+    fn explain_with_original_ids(&self) -> String;
+
     /// Explain the plan node and return a json string.
     fn explain_to_json(&self) -> String;
 
@@ -758,10 +885,26 @@ impl<C: ConventionMarker> Explain for PlanRef<C> {
         output
     }
 
+    /// Explain the plan node and return a string, keeping each node's original [`PlanNodeId`].
+    fn explain_with_original_ids(&self) -> String {
+        let mut visited_share_ids = HashSet::new();
+        let explain_ir = self.explain_with_id_dedup_share(&mut visited_share_ids);
+
+        let mut output = String::with_capacity(2048);
+        let mut config = pretty_config();
+        config.unicode(&mut output, &explain_ir);
+        output
+    }
+
     /// Explain the plan node and return a json string.
+    ///
+    /// Unlike [`Self::explain`], shared subplans (`LogicalShare`/`StreamShare`) are only expanded
+    /// the first time they are encountered; later occurrences emit a `share_id` field referring
+    /// back to it instead of duplicating the subtree.
     fn explain_to_json(&self) -> String {
         let plan = reorganize_elements_id(self.clone());
-        let explain_ir = plan.explain();
+        let mut visited_share_ids = HashSet::new();
+        let explain_ir = plan.explain_dedup_share(&mut visited_share_ids);
         serde_json::to_string_pretty(&PrettySerde(explain_ir, true))
             .expect("failed to serialize plan to json")
     }
@@ -798,6 +941,230 @@ impl<C: ConventionMarker> PlanRef<C> {
     pub fn as_share_node(&self) -> Option<&C::ShareNode> {
         C::as_share(self)
     }
+
+    /// Like [`Explain::explain`], but deduplicates shared subplans: the first time a share node's
+    /// id is seen, its subtree is expanded as usual; later occurrences emit a childless record
+    /// with just a `share_id` field instead of duplicating the subtree. Used by
+    /// [`Explain::explain_to_json`].
+    fn explain_dedup_share<'a>(&self, visited_share_ids: &mut HashSet<i32>) -> Pretty<'a> {
+        if self.as_share_node().is_some() && !visited_share_ids.insert(self.id().0) {
+            return Pretty::childless_record(
+                self.distill().name,
+                vec![("share_id", Pretty::display(&self.id().0))],
+            );
+        }
+        let mut node = self.distill();
+        let inputs = self.inputs();
+        for input in inputs.iter() {
+            node.children.push(input.explain_dedup_share(visited_share_ids));
+        }
+        Pretty::Record(node)
+    }
+
+    /// Like [`Self::explain_dedup_share`], but also prepends each node's original
+    /// [`PlanNodeId`] as an `id` field, without resetting ids first. Used by
+    /// [`Explain::explain_with_original_ids`].
+    fn explain_with_id_dedup_share<'a>(&self, visited_share_ids: &mut HashSet<i32>) -> Pretty<'a> {
+        let node_id = self.id();
+        if self.as_share_node().is_some() && !visited_share_ids.insert(node_id.0) {
+            return Pretty::childless_record(
+                self.distill().name,
+                vec![
+                    ("id".into(), Pretty::display(&node_id.0)),
+                    ("share_id".into(), Pretty::display(&node_id.0)),
+                ],
+            );
+        }
+        let mut node = self.distill();
+        node.fields.insert(0, ("id".into(), Pretty::display(&node_id.0)));
+        let inputs = self.inputs();
+        for input in inputs.iter() {
+            node.children
+                .push(input.explain_with_id_dedup_share(visited_share_ids));
+        }
+        Pretty::Record(node)
+    }
+}
+
+#[cfg(test)]
+mod explain_tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_pb::expr::expr_node::Type;
+
+    use super::*;
+    use crate::expr::{ExprImpl, FunctionCall, InputRef, Literal};
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::{LogicalFilter, LogicalJoin, LogicalShare, LogicalValues};
+
+    fn int32_eq_literal(input_ref: usize, literal: i32) -> ExprImpl {
+        ExprImpl::FunctionCall(Box::new(
+            FunctionCall::new(
+                Type::Equal,
+                vec![
+                    ExprImpl::InputRef(Box::new(InputRef::new(input_ref, DataType::Int32))),
+                    ExprImpl::Literal(Box::new(Literal::new(
+                        Some(ScalarImpl::from(literal)),
+                        DataType::Int32,
+                    ))),
+                ],
+            )
+            .unwrap(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_explain_to_json_filter_over_scan() {
+        let ctx = OptimizerContext::mock().await;
+        let fields = vec![Field::with_name(DataType::Int32, "v1")];
+        let scan: LogicalPlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+        let filter = LogicalFilter::create_with_expr(scan, int32_eq_literal(0, 1));
+
+        let json = filter.explain_to_json();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["name"], "LogicalFilter");
+        assert_eq!(parsed["children"][0]["name"], "LogicalValues");
+    }
+
+    #[tokio::test]
+    async fn test_explain_to_json_dedups_shared_subplan() {
+        let ctx = OptimizerContext::mock().await;
+        let fields = vec![Field::with_name(DataType::Int32, "v1")];
+        let values: LogicalPlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+        let share: LogicalPlanRef = LogicalShare::create(values);
+
+        let join: LogicalPlanRef = LogicalJoin::create(
+            LogicalFilter::create_with_expr(share.clone(), int32_eq_literal(0, 1)),
+            LogicalFilter::create_with_expr(share, int32_eq_literal(0, 2)),
+            risingwave_pb::plan_common::JoinType::Inner,
+            int32_eq_literal(0, 0),
+        );
+
+        let json = join.explain_to_json();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        // Both branches reference the same `LogicalShare`, but only the first occurrence should
+        // expand its subtree; the second should just carry a `share_id` reference.
+        let left_share = &parsed["children"][0]["children"][0];
+        let right_share = &parsed["children"][1]["children"][0];
+        assert_eq!(left_share["name"], "LogicalShare");
+        assert!(left_share["children"][0]["name"] == "LogicalValues");
+        assert_eq!(right_share["fields"]["share_id"], left_share["fields"]["id"]);
+        assert!(right_share["children"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_explain_with_original_ids_differs_across_reorganize() {
+        let ctx = OptimizerContext::mock().await;
+        // Skip one id so the plan's ids don't already start at 1, which is where
+        // `reorganize_elements_id` restarts numbering.
+        ctx.next_plan_node_id();
+        let fields = vec![Field::with_name(DataType::Int32, "v1")];
+        let scan: LogicalPlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+        let filter = LogicalFilter::create_with_expr(scan, int32_eq_literal(0, 1));
+
+        // Calling it twice on the same plan is stable: unlike `explain_to_string`, it doesn't
+        // clone/reorganize ids on every call.
+        assert_eq!(
+            filter.explain_with_original_ids(),
+            filter.explain_with_original_ids()
+        );
+
+        // `reorganize_elements_id` builds a fresh clone with newly assigned node ids, so the
+        // original-id explain of the reorganized plan differs from the original plan's, even
+        // though the id-agnostic explain output is identical.
+        let reorganized = reorganize_elements_id(filter.clone());
+        assert_ne!(
+            filter.explain_with_original_ids(),
+            reorganized.explain_with_original_ids()
+        );
+        assert_eq!(filter.explain_to_string(), reorganized.explain_to_string());
+    }
+}
+
+#[cfg(test)]
+mod subtree_contains_tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::{LogicalFilter, LogicalJoin, LogicalValues};
+
+    #[tokio::test]
+    async fn test_subtree_contains_finds_nested_join() {
+        let ctx = OptimizerContext::mock().await;
+        let fields = vec![Field::with_name(DataType::Int32, "v1")];
+        let left: LogicalPlanRef =
+            LogicalValues::new(vec![], Schema { fields: fields.clone() }, ctx.clone()).into();
+        let right: LogicalPlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+        let join: LogicalPlanRef = LogicalJoin::create(
+            left,
+            right,
+            risingwave_pb::plan_common::JoinType::Inner,
+            crate::expr::ExprImpl::literal_bool(true),
+        );
+        let filter =
+            LogicalFilter::create_with_expr(join, crate::expr::ExprImpl::literal_bool(true));
+
+        assert!(
+            filter.subtree_contains(|node| node.node_type() == LogicalPlanNodeType::LogicalJoin)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subtree_contains_returns_false_without_match() {
+        let ctx = OptimizerContext::mock().await;
+        let fields = vec![Field::with_name(DataType::Int32, "v1")];
+        let values: LogicalPlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+        let filter =
+            LogicalFilter::create_with_expr(values, crate::expr::ExprImpl::literal_bool(true));
+
+        assert!(
+            !filter.subtree_contains(|node| node.node_type() == LogicalPlanNodeType::LogicalJoin)
+        );
+    }
+}
+
+#[cfg(test)]
+mod to_dot_tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::{LogicalFilter, LogicalJoin, LogicalShare, LogicalValues};
+
+    #[tokio::test]
+    async fn test_to_dot_collapses_shared_subplan() {
+        let ctx = OptimizerContext::mock().await;
+        let fields = vec![Field::with_name(DataType::Int32, "v1")];
+        let values: LogicalPlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+        let share: LogicalPlanRef = LogicalShare::create(values);
+
+        let join: LogicalPlanRef = LogicalJoin::create(
+            LogicalFilter::create_with_expr(
+                share.clone(),
+                crate::expr::ExprImpl::literal_bool(true),
+            ),
+            LogicalFilter::create_with_expr(
+                share.clone(),
+                crate::expr::ExprImpl::literal_bool(true),
+            ),
+            risingwave_pb::plan_common::JoinType::Inner,
+            crate::expr::ExprImpl::literal_bool(true),
+        );
+
+        let dot = join.to_dot();
+
+        // Exactly one node line for the shared subplan...
+        let share_node_line = format!("\n  {} [label=", share.id().0);
+        assert_eq!(dot.matches(share_node_line.as_str()).count(), 1);
+
+        // ...but two edges into it, one per referencing `LogicalFilter`.
+        let share_edge = format!("-> {};", share.id().0);
+        assert_eq!(dot.matches(share_edge.as_str()).count(), 2);
+    }
 }
 
 pub(crate) fn pretty_config() -> PrettyConfig {
@@ -960,6 +1327,8 @@ mod predicate_pushdown;
 pub use predicate_pushdown::*;
 mod merge_eq_nodes;
 pub use merge_eq_nodes::*;
+mod replace_subtree;
+pub use replace_subtree::*;
 
 pub mod batch;
 pub mod generic;
@@ -1017,6 +1386,7 @@ mod logical_max_one_row;
 mod logical_multi_join;
 mod logical_now;
 mod logical_over_window;
+mod logical_sample;
 mod logical_project;
 mod logical_project_set;
 mod logical_recursive_union;
@@ -1148,6 +1518,7 @@ pub use logical_multi_join::{LogicalMultiJoin, LogicalMultiJoinBuilder};
 pub use logical_mysql_query::LogicalMySqlQuery;
 pub use logical_now::LogicalNow;
 pub use logical_over_window::LogicalOverWindow;
+pub use logical_sample::LogicalSample;
 pub use logical_postgres_query::LogicalPostgresQuery;
 pub use logical_project::LogicalProject;
 pub use logical_project_set::LogicalProjectSet;
@@ -1255,6 +1626,7 @@ macro_rules! for_all_plan_nodes {
             , { Logical, OverWindow }
             , { Logical, Share }
             , { Logical, Now }
+            , { Logical, Sample }
             , { Logical, Dedup }
             , { Logical, Intersect }
             , { Logical, Except }
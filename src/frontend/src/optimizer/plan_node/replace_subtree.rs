@@ -0,0 +1,120 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use super::generic::GenericPlanRef;
+use super::{EndoPlan, LogicalPlanRef as PlanRef, PlanNodeId};
+use crate::utils::Endo;
+
+impl PlanRef {
+    /// Replaces the subtree rooted at the node with id `target` with `replacement`, rebuilding
+    /// only the path from the root down to `target` and leaving everything else, including shared
+    /// subplans reachable through other paths, untouched. If `target` is not found in the plan,
+    /// the original plan is returned unchanged.
+    pub fn replace_subtree(&self, target: PlanNodeId, replacement: PlanRef) -> PlanRef {
+        Replacer {
+            target,
+            replacement,
+            cache: HashMap::new(),
+        }
+        .apply(self.clone())
+    }
+}
+
+struct Replacer {
+    target: PlanNodeId,
+    replacement: PlanRef,
+    cache: HashMap<PlanNodeId, PlanRef>,
+}
+
+impl EndoPlan for Replacer {
+    fn cached<F>(&mut self, plan: PlanRef, mut f: F) -> PlanRef
+    where
+        F: FnMut(&mut Self) -> PlanRef,
+    {
+        self.cache.get(&plan.id()).cloned().unwrap_or_else(|| {
+            let res = f(self);
+            self.cache.entry(plan.id()).or_insert(res).clone()
+        })
+    }
+}
+
+impl Endo<PlanRef> for Replacer {
+    fn apply(&mut self, t: PlanRef) -> PlanRef {
+        let target = self.target;
+        if t.id() == target {
+            self.replacement.clone()
+        } else if !t.subtree_contains(|node| node.id() == target) {
+            t
+        } else {
+            self.dag_apply(t)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::expr::{ExprImpl, FunctionCall, Literal};
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::{LogicalFilter, LogicalValues, PlanTreeNode};
+    use crate::utils::Condition;
+
+    #[tokio::test]
+    async fn test_replace_subtree() {
+        let ctx = OptimizerContext::mock().await;
+        let fields = vec![Field::with_name(DataType::Int32, "v1")];
+
+        // A three-level plan: root -> middle (filter) -> leaf (values).
+        let leaf: PlanRef = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields.clone(),
+            },
+            ctx.clone(),
+        )
+        .into();
+        let predicate = ExprImpl::FunctionCall(Box::new(
+            FunctionCall::new_unchecked(
+                risingwave_pb::expr::expr_node::Type::IsNotNull,
+                vec![ExprImpl::Literal(Box::new(Literal::new(
+                    None,
+                    DataType::Int32,
+                )))],
+                DataType::Boolean,
+            ),
+        ));
+        let middle: PlanRef =
+            LogicalFilter::new(leaf.clone(), Condition::with_expr(predicate)).into();
+        let root = middle.clone_root_with_inputs(&[middle.clone()]);
+
+        let replacement: PlanRef = LogicalValues::new(vec![], Schema { fields }, ctx).into();
+        let new_root = root.replace_subtree(middle.id(), replacement.clone());
+
+        // The middle node was swapped out for the replacement...
+        assert_eq!(new_root.inputs()[0], replacement);
+        // ...while the leaf and the original root/middle plan are untouched.
+        assert_eq!(root.inputs()[0].id(), middle.id());
+        assert_eq!(middle.inputs()[0].id(), leaf.id());
+
+        // Replacing an id that doesn't exist in the plan leaves it unchanged.
+        let missing = PlanNodeId(i32::MAX);
+        let unchanged = root.replace_subtree(missing, replacement);
+        assert_eq!(unchanged, root);
+    }
+}
@@ -40,7 +40,12 @@ pub struct StreamDynamicFilter {
 
 impl StreamDynamicFilter {
     pub fn new(core: DynamicFilter<PlanRef>) -> Result<Self> {
-        let right_non_decreasing = core.right().columns_monotonicity()[0].is_non_decreasing();
+        // With two dynamic bounds (BETWEEN), the append-only-preserving and
+        // watermark-propagation optimizations below would need to reason about both bounds
+        // moving together, so conservatively skip them (see `derive_watermark_columns` and
+        // `cleaned_by_watermark`) and always require a retracting state table.
+        let right_non_decreasing = core.upper_comparator().is_none()
+            && core.right().columns_monotonicity()[0].is_non_decreasing();
         let condition_always_relax = right_non_decreasing
             && matches!(
                 core.comparator(),
@@ -73,6 +78,11 @@ impl StreamDynamicFilter {
     }
 
     fn derive_watermark_columns(core: &DynamicFilter<PlanRef>) -> WatermarkColumns {
+        if core.upper_comparator().is_some() {
+            // See the note in `new`.
+            return WatermarkColumns::new();
+        }
+
         let mut res = WatermarkColumns::new();
         let lhs_watermark_columns = core.left().watermark_columns();
         let rhs_watermark_columns = core.right().watermark_columns();
@@ -106,6 +116,11 @@ impl StreamDynamicFilter {
     }
 
     fn cleaned_by_watermark(core: &DynamicFilter<PlanRef>) -> bool {
+        if core.upper_comparator().is_some() {
+            // See the note in `new`.
+            return false;
+        }
+
         let rhs_watermark_columns = core.right().watermark_columns();
         if rhs_watermark_columns.contains(0) {
             match core.comparator() {
@@ -176,10 +191,10 @@ impl StreamNode for StreamDynamicFilter {
     fn to_stream_prost_body(&self, state: &mut BuildFragmentGraphState) -> NodeBody {
         use generic::dynamic_filter::*;
         let cleaned_by_watermark = self.cleaned_by_watermark;
-        let condition = self
+        let condition = Some(self.core.condition().to_expr_proto());
+        let upper_condition = self
             .core
-            .predicate()
-            .as_expr_unless_true()
+            .upper_condition()
             .map(|x| x.to_expr_proto());
         let left_index = self.core.left_index();
         let left_table = infer_left_internal_table_catalog(&self.base, left_index)
@@ -192,6 +207,7 @@ impl StreamNode for StreamDynamicFilter {
         NodeBody::DynamicFilter(Box::new(DynamicFilterNode {
             left_key: left_index as u32,
             condition,
+            upper_condition,
             left_table: Some(left_table.to_internal_table_prost()),
             right_table: Some(right_table.to_internal_table_prost()),
             condition_always_relax: false, // deprecated
@@ -70,6 +70,12 @@ pub struct StreamHashJoin {
 
 impl StreamHashJoin {
     pub fn new(core: generic::Join<PlanRef>, eq_join_predicate: EqJoinPredicate) -> Result<Self> {
+        assert!(
+            eq_join_predicate.has_eq(),
+            "a stream hash join must have at least one equi-condition, otherwise it should have \
+             been routed to a nested-loop or dynamic-filter join instead"
+        );
+
         let ctx = core.ctx();
 
         let stream_kind = core.stream_kind()?;
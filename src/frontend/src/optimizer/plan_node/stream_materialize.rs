@@ -20,7 +20,7 @@ use itertools::Itertools;
 use pretty_xmlish::{Pretty, XmlNode};
 use risingwave_common::catalog::{
     ColumnCatalog, ConflictBehavior, CreateType, Engine, OBJECT_ID_PLACEHOLDER, StreamJobStatus,
-    TableId,
+    TableId, checked_conflict_behaviors,
 };
 use risingwave_common::hash::VnodeCount;
 use risingwave_common::types::DataType;
@@ -77,9 +77,7 @@ impl StreamMaterialize {
             }
 
             // When conflict handling is enabled, upsert stream can be converted to retract stream.
-            ConflictBehavior::Overwrite
-            | ConflictBehavior::IgnoreConflict
-            | ConflictBehavior::DoUpdateIfNotNull => match input.stream_kind() {
+            checked_conflict_behaviors!() => match input.stream_kind() {
                 StreamKind::AppendOnly => StreamKind::AppendOnly,
                 StreamKind::Retract | StreamKind::Upsert => StreamKind::Retract,
             },
@@ -0,0 +1,156 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{DefaultBehavior, DefaultValue, LogicalPlanVisitor};
+use crate::optimizer::plan_node::{
+    self, LogicalPlanRef as PlanRef, PlanTreeNodeBinary, PlanTreeNodeUnary,
+};
+
+/// Absent per-column statistics, assume a predicate lets this fraction of its input through.
+/// This mirrors the fixed default selectivity constants classic cost-based optimizers (e.g.
+/// PostgreSQL) fall back on when there are no column statistics to consult.
+const DEFAULT_FILTER_SELECTIVITY: f64 = 1.0 / 3.0;
+
+/// A visitor that produces a rough point estimate of the number of rows a plan node will yield,
+/// for use in diagnostics such as `EXPLAIN`. Unlike [`super::CardinalityVisitor`], which computes
+/// a sound `lo..=hi` bound, this is a best-effort guess and is not guaranteed to bound the actual
+/// row count in either direction.
+pub struct EstimatedCardinalityVisitor;
+
+impl LogicalPlanVisitor for EstimatedCardinalityVisitor {
+    type Result = Option<u64>;
+
+    type DefaultBehavior = impl DefaultBehavior<Self::Result>;
+
+    fn default_behavior() -> Self::DefaultBehavior {
+        // Nodes we don't have a specific rule for return `None`, i.e. "no estimate available",
+        // rather than guessing from their inputs.
+        DefaultValue
+    }
+
+    fn visit_logical_values(&mut self, plan: &plan_node::LogicalValues) -> Self::Result {
+        Some(plan.rows().len() as u64)
+    }
+
+    fn visit_logical_scan(&mut self, plan: &plan_node::LogicalScan) -> Self::Result {
+        // Only exact table cardinality (e.g. a materialized view known to emit at most one row)
+        // counts as a usable statistic; an ordinary table has no row-count statistics at all.
+        plan.table_cardinality().get_exact().map(|c| c as u64)
+    }
+
+    fn visit_logical_project(&mut self, plan: &plan_node::LogicalProject) -> Self::Result {
+        self.visit(plan.input())
+    }
+
+    fn visit_logical_filter(&mut self, plan: &plan_node::LogicalFilter) -> Self::Result {
+        let input = self.visit(plan.input())?;
+        if plan.predicate().always_true() {
+            Some(input)
+        } else {
+            Some(((input as f64) * DEFAULT_FILTER_SELECTIVITY).round() as u64)
+        }
+    }
+
+    fn visit_logical_join(&mut self, plan: &plan_node::LogicalJoin) -> Self::Result {
+        let left = self.visit(plan.left())?;
+        let right = self.visit(plan.right())?;
+        let product = left.saturating_mul(right);
+        if plan.on().always_true() {
+            Some(product)
+        } else {
+            Some(((product as f64) * DEFAULT_FILTER_SELECTIVITY).round() as u64)
+        }
+    }
+
+    fn visit_logical_agg(&mut self, plan: &plan_node::LogicalAgg) -> Self::Result {
+        let input = self.visit(plan.input())?;
+        if plan.group_key().is_empty() {
+            // A group-less aggregate always emits exactly one row.
+            Some(1)
+        } else {
+            // Absent per-column NDV statistics, assume the number of distinct groups grows
+            // roughly with the square root of the input size.
+            Some((input as f64).sqrt().ceil() as u64)
+        }
+    }
+}
+
+#[easy_ext::ext(EstimatedCardinalityExt)]
+pub impl PlanRef {
+    /// Returns a rough point estimate of the number of rows this plan node will yield, or `None`
+    /// if there isn't enough information (e.g. no table statistics) to even guess. This is meant
+    /// for diagnostics; use [`super::LogicalCardinalityExt`] when a sound guarantee is required.
+    fn estimated_cardinality(&self) -> Option<u64> {
+        EstimatedCardinalityVisitor.visit(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+    use risingwave_pb::plan_common::JoinType;
+
+    use super::*;
+    use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef};
+    use crate::optimizer::optimizer_context::OptimizerContext;
+    use crate::optimizer::plan_node::{LogicalFilter, LogicalJoin, LogicalValues};
+    use crate::utils::Condition;
+
+    fn values(ctx: OptimizerContext, num_rows: usize) -> PlanRef {
+        let fields = vec![Field::with_name(DataType::Int32, "v1")];
+        let rows = (0..num_rows)
+            .map(|i| vec![ExprImpl::literal_int(i as i32)])
+            .collect();
+        LogicalValues::new(rows, Schema { fields }, ctx).into()
+    }
+
+    #[tokio::test]
+    async fn test_filtered_scan_estimate_lower_than_base() {
+        let ctx = OptimizerContext::mock().await;
+        let base = values(ctx.clone(), 9);
+        assert_eq!(base.estimated_cardinality(), Some(9));
+
+        let predicate: ExprImpl = FunctionCall::new(
+            ExprType::Equal,
+            vec![
+                ExprImpl::InputRef(Box::new(InputRef::new(0, DataType::Int32))),
+                ExprImpl::literal_int(1),
+            ],
+        )
+        .unwrap()
+        .into();
+        let filtered: PlanRef =
+            LogicalFilter::new(base.clone(), Condition::with_expr(predicate)).into();
+
+        let base_estimate = base.estimated_cardinality().unwrap();
+        let filtered_estimate = filtered.estimated_cardinality().unwrap();
+        assert!(
+            filtered_estimate < base_estimate,
+            "filtered estimate {filtered_estimate} should be lower than base estimate {base_estimate}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cross_join_multiplies_estimates() {
+        let ctx = OptimizerContext::mock().await;
+        let left = values(ctx.clone(), 3);
+        let right = values(ctx, 4);
+
+        let join: PlanRef =
+            LogicalJoin::new(left, right, JoinType::Inner, Condition::true_cond()).into();
+
+        assert_eq!(join.estimated_cardinality(), Some(3 * 4));
+    }
+}
@@ -37,6 +37,8 @@ mod side_effect_visitor;
 pub use side_effect_visitor::*;
 mod cardinality_visitor;
 pub use cardinality_visitor::*;
+mod estimated_cardinality;
+pub use estimated_cardinality::*;
 mod jsonb_stream_key_checker;
 pub use jsonb_stream_key_checker::*;
 mod distributed_dml_visitor;
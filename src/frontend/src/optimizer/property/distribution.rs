@@ -306,6 +306,11 @@ impl RequiredDist {
         Self::PhysicalDist(Distribution::HashShard(key.to_vec()))
     }
 
+    /// Enforces `self` on `plan`, inserting a [`BatchExchange`] only if `plan`'s current
+    /// distribution doesn't already satisfy `self` (e.g. a plan that's already singleton needs no
+    /// exchange to become `Single`). This also naturally rejects a wrongly-elided shuffle: a
+    /// `SomeShard` input never satisfies a `Single` requirement, since [`Distribution::satisfies`]
+    /// falls back to plain equality for a required [`Distribution`].
     pub fn batch_enforce_if_not_satisfies(
         &self,
         mut plan: BatchPlanRef,
@@ -436,4 +441,14 @@ mod tests {
         assert!(!r3.satisfies(&r4));
         assert!(!r4.satisfies(&r3));
     }
+
+    #[test]
+    fn single_satisfy() {
+        // A plan that's already `Single` satisfies a `Single` requirement, so
+        // `batch_enforce_if_not_satisfies` can elide the exchange entirely.
+        assert!(Distribution::Single.satisfies(&RequiredDist::single()));
+
+        // A `SomeShard` plan does *not* satisfy `Single`: a gather exchange is still required.
+        assert!(!Distribution::SomeShard.satisfies(&RequiredDist::single()));
+    }
 }
@@ -355,3 +355,40 @@ impl FromIterator<(usize, Monotonicity)> for MonotonicityMap {
         MonotonicityMap(iter.into_iter().collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use monotonicity_variants::*;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::expr::{ExprImpl, FunctionCall, InputRef, Literal};
+
+    fn input_ref(idx: usize) -> ExprImpl {
+        InputRef::new(idx, DataType::Int32).into()
+    }
+
+    fn int_literal(v: i32) -> ExprImpl {
+        Literal::new(Some(v.into()), DataType::Int32).into()
+    }
+
+    #[test]
+    fn following_input_for_add_with_a_constant_operand() {
+        // `col + 10`, e.g. `StreamProject`'s way of checking whether `ts + interval '1h'`
+        // preserves the watermark on `ts`.
+        let expr = FunctionCall::new(ExprType::Add, vec![input_ref(0), int_literal(10)])
+            .unwrap()
+            .into();
+        assert_eq!(analyze_monotonicity(&expr), FollowingInput(0));
+    }
+
+    #[test]
+    fn unknown_for_modulus_of_a_column_by_a_constant() {
+        // `col % 10` is not monotonic in `col`, e.g. `ts % 10`, so it must not be classified as
+        // `FollowingInput` and therefore must not carry a watermark through in `StreamProject`.
+        let expr = FunctionCall::new(ExprType::Modulus, vec![input_ref(0), int_literal(10)])
+            .unwrap()
+            .into();
+        assert_eq!(analyze_monotonicity(&expr), Inherent(Unknown));
+    }
+}
@@ -120,6 +120,24 @@ impl Order {
         true
     }
 
+    /// Returns how many leading [`ColumnOrder`]s of `required` are already established by
+    /// `self`, or `None` if `self` doesn't even provide the first one.
+    ///
+    /// This is a relaxation of [`Self::satisfies`]: instead of an all-or-nothing check, it
+    /// reports how long of a common prefix the two orders share, which is exactly the portion of
+    /// `required` that is already established and does not need to be (re-)enforced. A return
+    /// value of `Some(n)` with `n == required.len()` means `self.satisfies(required)`.
+    pub fn prefix_satisfies(&self, required: &Order) -> Option<usize> {
+        #[expect(clippy::disallowed_methods)]
+        let n = self
+            .column_orders
+            .iter()
+            .zip(required.column_orders.iter())
+            .take_while(|(order, required_order)| order == required_order)
+            .count();
+        (n > 0).then_some(n)
+    }
+
     #[inline(always)]
     pub const fn any() -> Self {
         ANY_ORDER
@@ -187,4 +205,69 @@ mod tests {
         assert!(!o2.satisfies(&o3));
         assert!(!o3.satisfies(&o2));
     }
+
+    #[test]
+    fn test_order_prefix_satisfies() {
+        let o1 = Order {
+            column_orders: vec![
+                ColumnOrder {
+                    column_index: 0,
+                    order_type: OrderType::ascending(),
+                },
+                ColumnOrder {
+                    column_index: 1,
+                    order_type: OrderType::descending(),
+                },
+                ColumnOrder {
+                    column_index: 2,
+                    order_type: OrderType::ascending(),
+                },
+            ],
+        };
+        let o2 = Order {
+            column_orders: vec![
+                ColumnOrder {
+                    column_index: 0,
+                    order_type: OrderType::ascending(),
+                },
+                ColumnOrder {
+                    column_index: 1,
+                    order_type: OrderType::descending(),
+                },
+            ],
+        };
+        let o3 = Order {
+            column_orders: vec![
+                ColumnOrder {
+                    column_index: 0,
+                    order_type: OrderType::ascending(),
+                },
+                ColumnOrder {
+                    column_index: 1,
+                    order_type: OrderType::ascending(),
+                },
+            ],
+        };
+
+        // A full match reports the same length as `satisfies` would accept.
+        assert_eq!(o1.prefix_satisfies(&o2), Some(2));
+        assert!(o1.satisfies(&o2));
+
+        // Only the leading column agrees, so the prefix stops there.
+        assert_eq!(o1.prefix_satisfies(&o3), Some(1));
+        assert!(!o1.satisfies(&o3));
+
+        // `self` shorter than `required` still reports however much of a prefix matches.
+        assert_eq!(o2.prefix_satisfies(&o1), Some(2));
+        assert!(!o2.satisfies(&o1));
+
+        // No agreement at all.
+        let unrelated = Order {
+            column_orders: vec![ColumnOrder {
+                column_index: 5,
+                order_type: OrderType::ascending(),
+            }],
+        };
+        assert_eq!(unrelated.prefix_satisfies(&o1), None);
+    }
 }
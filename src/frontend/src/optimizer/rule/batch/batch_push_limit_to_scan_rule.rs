@@ -23,6 +23,10 @@ use super::prelude::*;
 use crate::optimizer::plan_node::generic::PhysicalPlanRef;
 use crate::optimizer::plan_node::{BatchLimit, BatchSeqScan, PlanTreeNodeUnary};
 
+/// Pushes a `BatchLimit`'s row-count hint down into a `BatchSeqScan` it directly covers, so the
+/// scan executor can bound its prefetching instead of exhaustively iterating the table. This only
+/// fires when the limit's input is *literally* a scan, so any intervening node (`BatchExchange`,
+/// an aggregate, a filter of unknown selectivity, etc.) naturally blocks the pushdown.
 pub struct BatchPushLimitToScanRule {}
 
 impl Rule<Batch> for BatchPushLimitToScanRule {
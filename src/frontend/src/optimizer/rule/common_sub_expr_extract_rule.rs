@@ -21,6 +21,11 @@ use crate::optimizer::plan_expr_visitor::CseExprCounter;
 use crate::optimizer::plan_node::generic::GenericPlanRef;
 use crate::optimizer::plan_node::*;
 
+/// Detects non-trivial subexpressions (impure-free, non-const function calls) that occur more
+/// than once in a `LogicalProject`'s output list, and factors each of them into a preceding
+/// project that computes it once, referenced downstream via `InputRef`. Since this runs on the
+/// logical plan before `to_batch`/`to_stream`, the resulting `BatchProject`/`StreamProject`
+/// nodes never recompute the shared subexpression per occurrence.
 pub struct CommonSubExprExtractRule {}
 impl Rule<Logical> for CommonSubExprExtractRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
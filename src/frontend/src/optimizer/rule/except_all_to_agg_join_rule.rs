@@ -0,0 +1,48 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::prelude::{PlanRef, *};
+use crate::optimizer::plan_node::{LogicalExcept, PlanTreeNode};
+use crate::optimizer::rule::set_operation_all_common::count_based_set_op;
+
+/// Lowers `EXCEPT ALL` to a counting-based plan: count occurrences of each distinct row on both
+/// sides, join the counts, and emit each row `greatest(left_count - right_count, 0)` times.
+///
+/// `EXCEPT` (the distinct variant) is instead handled by [`ExceptToAntiJoinRule`], which doesn't
+/// apply here.
+pub struct ExceptAllToAggJoinRule {}
+impl Rule<Logical> for ExceptAllToAggJoinRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let logical_except: &LogicalExcept = plan.as_logical_except()?;
+        if !logical_except.all() {
+            return None;
+        }
+
+        let plan = logical_except
+            .inputs()
+            .into_iter()
+            .fold(None, |left, right| match left {
+                None => Some(right),
+                Some(left) => Some(count_based_set_op(false, left, right)),
+            })
+            .unwrap();
+        Some(plan)
+    }
+}
+
+impl ExceptAllToAggJoinRule {
+    pub fn create() -> BoxedRule {
+        Box::new(ExceptAllToAggJoinRule {})
+    }
+}
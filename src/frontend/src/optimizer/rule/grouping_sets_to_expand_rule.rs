@@ -69,6 +69,10 @@ impl GroupingSetsToExpandRule {
 }
 
 impl Rule<Logical> for GroupingSetsToExpandRule {
+    // Note: because the lowered `Agg` always groups by the flag column added by `Expand`, it goes
+    // through `HashAgg` even for the grand-total (empty) grouping set. On a fully empty input,
+    // `Expand` therefore produces no rows for any subset, including the grand total, so unlike a
+    // plain `GROUP BY ()` the grand-total row is not guaranteed to appear.
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let agg: &LogicalAgg = plan.as_logical_agg()?;
         if agg.grouping_sets().is_empty() {
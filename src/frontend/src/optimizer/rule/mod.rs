@@ -206,6 +206,11 @@ mod intersect_to_semi_join_rule;
 pub use intersect_to_semi_join_rule::*;
 mod except_to_anti_join_rule;
 pub use except_to_anti_join_rule::*;
+mod set_operation_all_common;
+mod intersect_all_to_agg_join_rule;
+pub use intersect_all_to_agg_join_rule::*;
+mod except_all_to_agg_join_rule;
+pub use except_all_to_agg_join_rule::*;
 mod intersect_merge_rule;
 pub use intersect_merge_rule::*;
 mod except_merge_rule;
@@ -333,6 +338,8 @@ macro_rules! for_all_rules {
             , { PullUpHopRule }
             , { IntersectToSemiJoinRule }
             , { ExceptToAntiJoinRule }
+            , { IntersectAllToAggJoinRule }
+            , { ExceptAllToAggJoinRule }
             , { IntersectMergeRule }
             , { ExceptMergeRule }
             , { ApplyUnionTransposeRule }
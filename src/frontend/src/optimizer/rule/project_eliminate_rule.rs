@@ -33,3 +33,103 @@ impl ProjectEliminateRule {
         Box::new(ProjectEliminateRule {})
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::expr::InputRef;
+    use crate::optimizer::optimizer_context::OptimizerContext;
+
+    #[tokio::test]
+    async fn test_removes_identity_project() {
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let values: PlanRef = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: vec![
+                    Field::with_name(ty.clone(), "v1"),
+                    Field::with_name(ty.clone(), "v2"),
+                ],
+            },
+            ctx,
+        )
+        .into();
+
+        let identity: PlanRef = LogicalProject::new(
+            values,
+            vec![
+                InputRef::new(0, ty.clone()).into(),
+                InputRef::new(1, ty).into(),
+            ],
+        )
+        .into();
+
+        let rewritten = ProjectEliminateRule {}.apply(identity).unwrap();
+        assert!(rewritten.as_logical_values().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_keeps_reordering_project() {
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let values: PlanRef = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: vec![
+                    Field::with_name(ty.clone(), "v1"),
+                    Field::with_name(ty.clone(), "v2"),
+                ],
+            },
+            ctx,
+        )
+        .into();
+
+        let reordered: PlanRef = LogicalProject::new(
+            values,
+            vec![
+                InputRef::new(1, ty.clone()).into(),
+                InputRef::new(0, ty).into(),
+            ],
+        )
+        .into();
+
+        assert!(ProjectEliminateRule {}.apply(reordered).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_keeps_renaming_project() {
+        use crate::optimizer::plan_node::generic;
+
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let values: PlanRef = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: vec![
+                    Field::with_name(ty.clone(), "v1"),
+                    Field::with_name(ty.clone(), "v2"),
+                ],
+            },
+            ctx,
+        )
+        .into();
+
+        // Every expression is a plain, in-order `InputRef`, but the first column is renamed, so
+        // collapsing this project into its input would silently drop the rename.
+        let mut renamed_core = generic::Project::new(
+            vec![
+                InputRef::new(0, ty.clone()).into(),
+                InputRef::new(1, ty).into(),
+            ],
+            values,
+        );
+        renamed_core.field_names.insert(0, "renamed".to_owned());
+        let renamed: PlanRef = LogicalProject::with_core(renamed_core).into();
+
+        assert!(ProjectEliminateRule {}.apply(renamed).is_none());
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+use risingwave_pb::plan_common::JoinType;
+
+use super::prelude::PlanRef;
+use crate::expr::{
+    Expr, ExprImpl, ExprType, FunctionCall, InputRef, TableFunction, TableFunctionType,
+};
+use crate::optimizer::plan_node::generic::{Agg, PlanAggCall};
+use crate::optimizer::plan_node::{LogicalJoin, LogicalProject, LogicalProjectSet};
+use crate::utils::{Condition, IndexSet};
+
+/// Shared by [`super::IntersectAllToAggJoinRule`] and [`super::ExceptAllToAggJoinRule`]: lowers a
+/// binary `INTERSECT ALL`/`EXCEPT ALL` between `left` and `right` into a counting-based plan.
+///
+/// Both sides are aggregated into `(row columns..., count(*))`, the two counts are joined on the
+/// row columns, and the output multiplicity is derived from the two counts: `least(left, right)`
+/// for `INTERSECT ALL`, `greatest(left - right, 0)` for `EXCEPT ALL`. Each row is then exploded
+/// into that many copies with `generate_series` inside a `ProjectSet`, so a row with multiplicity
+/// `0` is correctly dropped rather than emitted with an empty payload.
+///
+/// Since this only rewrites into existing agg/join/project-set plan nodes, insertions and
+/// retractions on either side propagate through it exactly as they would through any other
+/// agg-then-join plan; there's no bespoke state to keep consistent.
+pub(super) fn count_based_set_op(is_intersect: bool, left: PlanRef, right: PlanRef) -> PlanRef {
+    let width = left.schema().len();
+    let group_key = IndexSet::from_iter(0..width);
+
+    let left_agg: PlanRef =
+        Agg::new(vec![PlanAggCall::count_star()], group_key.clone(), left).into();
+    let right_agg: PlanRef = Agg::new(vec![PlanAggCall::count_star()], group_key, right).into();
+
+    let on = Condition::with_expr(ExprImpl::and((0..width).map(|i| {
+        FunctionCall::new_unchecked(
+            ExprType::IsNotDistinctFrom,
+            vec![
+                InputRef::new(i, left_agg.schema().fields()[i].data_type()).into(),
+                InputRef::new(
+                    width + 1 + i,
+                    right_agg.schema().fields()[i].data_type(),
+                )
+                .into(),
+            ],
+            DataType::Boolean,
+        )
+        .into()
+    })));
+    let join_type = if is_intersect {
+        JoinType::Inner
+    } else {
+        JoinType::LeftOuter
+    };
+    let join = LogicalJoin::new(left_agg, right_agg, join_type, on).into();
+
+    let left_count = InputRef::new(width, DataType::Int64).into();
+    let right_count = InputRef::new(2 * width + 1, DataType::Int64).into();
+    let multiplicity: ExprImpl = if is_intersect {
+        FunctionCall::new(ExprType::Least, vec![left_count, right_count])
+            .unwrap()
+            .into()
+    } else {
+        let right_count_or_zero = FunctionCall::new(
+            ExprType::Coalesce,
+            vec![right_count, ExprImpl::literal_bigint(0)],
+        )
+        .unwrap();
+        let diff = FunctionCall::new(
+            ExprType::Subtract,
+            vec![left_count, right_count_or_zero.into()],
+        )
+        .unwrap();
+        FunctionCall::new(ExprType::Greatest, vec![diff.into(), ExprImpl::literal_bigint(0)])
+            .unwrap()
+            .into()
+    };
+
+    let row_columns = |input: &PlanRef| -> Vec<ExprImpl> {
+        (0..width)
+            .map(|i| InputRef::new(i, input.schema().fields()[i].data_type()).into())
+            .collect()
+    };
+    let mut counted_list = row_columns(&join);
+    counted_list.push(multiplicity);
+    let counted: PlanRef = LogicalProject::create(join, counted_list);
+
+    let mut select_list = row_columns(&counted);
+    let series = TableFunction::new(
+        TableFunctionType::GenerateSeries,
+        vec![ExprImpl::literal_bigint(1), InputRef::new(width, DataType::Int64).into()],
+    )
+    .unwrap();
+    select_list.push(ExprImpl::TableFunction(Box::new(series)));
+    let exploded: PlanRef = LogicalProjectSet::new(counted, select_list).into();
+
+    // Drop the hidden `projected_row_id` (index 0) and the `generate_series` value (last column),
+    // keeping only the original row columns.
+    let final_list = (0..width)
+        .map(|i| InputRef::new(i + 1, exploded.schema().fields()[i + 1].data_type()).into())
+        .collect();
+    LogicalProject::create(exploded, final_list)
+}
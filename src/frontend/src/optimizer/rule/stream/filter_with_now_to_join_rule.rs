@@ -24,6 +24,14 @@ use crate::utils::Condition;
 
 /// Convert `LogicalFilter` with now in predicate to left-semi `LogicalJoin`
 /// Only applies to stream.
+///
+/// This is what makes temporal filters like `WHERE ts > now() - interval '1 hour'` incremental:
+/// the resulting join has a `LogicalNow` right side with at most one row, so
+/// `LogicalJoin::to_stream_dynamic_filter` recognizes it as a correlated comparison and lowers it
+/// to a `StreamDynamicFilter` with a monotonically increasing bound, rather than a plan that
+/// re-evaluates the whole predicate on every barrier. A predicate with two now-anchored bounds
+/// (e.g. `ts > now() - '1h' AND ts < now()`) produces two now-filters here, each becoming its own
+/// join and, in turn, its own chained `StreamDynamicFilter`.
 pub struct FilterWithNowToJoinRule {}
 impl Rule<Logical> for FilterWithNowToJoinRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
@@ -58,6 +58,7 @@ use tracing::{Instrument, debug, error, warn};
 
 use crate::catalog::catalog_service::CatalogReader;
 use crate::catalog::{FragmentId, TableId};
+use crate::monitor::GLOBAL_FRONTEND_METRICS;
 use crate::optimizer::plan_node::BatchPlanNodeType;
 use crate::scheduler::SchedulerError::{TaskExecutionError, TaskRunningOutOfMemory};
 use crate::scheduler::distributed::QueryMessage;
@@ -947,21 +948,29 @@ impl StageRunner {
     ) -> SchedulerResult<Fuse<Streaming<TaskInfoResponse>>> {
         let mut worker = worker.unwrap_or(self.worker_node_manager.next_random_worker()?);
         let worker_node_addr = worker.host.take().unwrap();
-        let compute_client = self
-            .compute_client_pool
-            .get_by_addr((&worker_node_addr).into())
-            .await
-            .inspect_err(|_| self.mask_failed_serving_worker(&worker))
-            .map_err(|e| anyhow!(e))?;
+        let addr: HostAddr = (&worker_node_addr).into();
+        let compute_client = match self.compute_client_pool.get_by_addr(addr.clone()).await {
+            Ok(client) => client,
+            Err(e) => {
+                self.mask_failed_serving_worker(&worker);
+                self.report_compute_client_failure(addr).await;
+                return Err(anyhow!(e).into());
+            }
+        };
 
         let t_id = task_id.task_id;
 
-        let stream_status: Fuse<Streaming<TaskInfoResponse>> = compute_client
+        let stream_status: Fuse<Streaming<TaskInfoResponse>> = match compute_client
             .create_task(task_id, plan_fragment, self.epoch, expr_context)
             .await
-            .inspect_err(|_| self.mask_failed_serving_worker(&worker))
-            .map_err(|e| anyhow!(e))?
-            .fuse();
+        {
+            Ok(stream) => stream.fuse(),
+            Err(e) => {
+                self.mask_failed_serving_worker(&worker);
+                self.report_compute_client_failure(addr).await;
+                return Err(anyhow!(e).into());
+            }
+        };
 
         self.tasks[&t_id].inner.store(Arc::new(TaskStatus {
             _task_id: t_id,
@@ -971,6 +980,20 @@ impl StageRunner {
         Ok(stream_status)
     }
 
+    /// Reports a transport-level RPC failure against `addr` to the compute client pool, evicting
+    /// its cached connection once consecutive failures cross the pool's threshold, and updates
+    /// the corresponding frontend metrics.
+    async fn report_compute_client_failure(&self, addr: HostAddr) {
+        if self.compute_client_pool.report_failure(addr).await {
+            GLOBAL_FRONTEND_METRICS
+                .compute_client_pool_eviction_count
+                .inc();
+        }
+        GLOBAL_FRONTEND_METRICS
+            .compute_client_pool_size
+            .set(self.compute_client_pool.len() as i64);
+    }
+
     pub fn create_plan_fragment(
         &self,
         task_id: TaskId,
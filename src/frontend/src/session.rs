@@ -14,9 +14,10 @@
 
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::future::Future;
 use std::io::{Error, ErrorKind};
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
-use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
 use std::time::{Duration, Instant};
 
@@ -53,7 +54,9 @@ use risingwave_common::config::{
 };
 use risingwave_common::memory::MemoryContext;
 use risingwave_common::secret::LocalSecretManager;
-use risingwave_common::session_config::{ConfigReporter, SessionConfig, VisibilityMode};
+use risingwave_common::session_config::{
+    ConfigReporter, SESSION_CONFIG_LIST_SEP, SessionConfig, VisibilityMode,
+};
 use risingwave_common::system_param::local_manager::{
     LocalSystemParamsManager, LocalSystemParamsManagerRef,
 };
@@ -119,7 +122,7 @@ use crate::rpc::FrontendServiceImpl;
 use crate::scheduler::streaming_manager::{StreamingJobTracker, StreamingJobTrackerRef};
 use crate::scheduler::{
     DistributedQueryMetrics, GLOBAL_DISTRIBUTED_QUERY_METRICS, HummockSnapshotManager,
-    HummockSnapshotManagerRef, QueryManager,
+    HummockSnapshotManagerRef, QueryManager, SchedulerError,
 };
 use crate::telemetry::FrontendTelemetryCreator;
 use crate::user::UserId;
@@ -721,6 +724,14 @@ pub struct SessionImpl {
     notice_tx: UnboundedSender<String>,
     /// Channel receiver for pgwire to take notices and send to clients.
     notice_rx: Mutex<UnboundedReceiver<String>>,
+    /// Number of notices currently buffered in `notice_tx`/`notice_rx`, i.e. sent but not yet
+    /// consumed. Used by [`Self::notice_to_user`] to cap the buffer at
+    /// [`Self::MAX_BUFFERED_NOTICES`].
+    notice_count: AtomicUsize,
+    /// Number of notices dropped by [`Self::notice_to_user`] because the buffer was already at
+    /// [`Self::MAX_BUFFERED_NOTICES`]. Surfaced as a single summary notice by
+    /// [`Self::take_notices`].
+    suppressed_notice_count: AtomicUsize,
 
     /// Identified by `process_id`, `secret_key`. Corresponds to `SessionManager`.
     id: (i32, i32),
@@ -827,6 +838,8 @@ impl SessionImpl {
             current_query_cancel_flag: Mutex::new(None),
             notice_tx,
             notice_rx: Mutex::new(notice_rx),
+            notice_count: AtomicUsize::new(0),
+            suppressed_notice_count: AtomicUsize::new(0),
             exec_context: Mutex::new(None),
             last_idle_instant: Default::default(),
             cursor_manager: Arc::new(CursorManager::new(cursor_metrics)),
@@ -854,6 +867,8 @@ impl SessionImpl {
             current_query_cancel_flag: Mutex::new(None),
             notice_tx,
             notice_rx: Mutex::new(notice_rx),
+            notice_count: AtomicUsize::new(0),
+            suppressed_notice_count: AtomicUsize::new(0),
             exec_context: Mutex::new(None),
             peer_addr: Address::Tcp(SocketAddr::new(
                 IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
@@ -916,6 +931,23 @@ impl SessionImpl {
             .map_err(Into::into)
     }
 
+    /// Sets a parameter only if it still holds its default value, i.e. it hasn't been set
+    /// earlier in the session. Used by drivers that replay connection-init scripts, so repeated
+    /// `SET`s of the same variable don't emit spurious notices or clobber a value the user set
+    /// intentionally.
+    pub fn set_config_if_unset(&self, key: &str, value: Vec<String>) -> Result<()> {
+        let is_unset = {
+            let config = self.config();
+            let current: String = config.get(key).map_err(Into::into)?;
+            let default: String = SessionConfig::default().get(key).map_err(Into::into)?;
+            current == default
+        };
+        if is_unset {
+            self.set_config(key, value.join(SESSION_CONFIG_LIST_SEP))?;
+        }
+        Ok(())
+    }
+
     pub fn reset_config(&self, key: &str) -> Result<String> {
         self.config_map
             .write()
@@ -1318,16 +1350,91 @@ impl SessionImpl {
             );
         }
         let stmt = stmts.swap_remove(0);
-        let rsp = handle(self, stmt, sql.clone(), formats).await?;
-        Ok(rsp)
+        let session = self.clone();
+        session
+            .run_with_statement_timeout(handle(self, stmt, sql, formats))
+            .await
     }
 
+    /// Races `fut` against `self.statement_timeout()`, cancelling and returning a timeout error
+    /// if it doesn't resolve in time. Factored out of [`Self::run_statement`] so the timeout and
+    /// cancellation behavior can be exercised directly against a deliberately blocking future in
+    /// tests, without going through the SQL parser/handler.
+    ///
+    /// A `statement_timeout` of 0 disables the cap entirely; the statement then only relies on
+    /// whatever timeout the individual handler (e.g. batch query execution) enforces on its own.
+    async fn run_with_statement_timeout(
+        self: Arc<Self>,
+        fut: impl Future<Output = Result<PgResponse<PgResponseStream>>>,
+    ) -> std::result::Result<PgResponse<PgResponseStream>, BoxedError> {
+        // Simulated time under madsim doesn't advance the same way as wall-clock time, so the
+        // timeout race is skipped there, mirroring `distribute_execute`/`local_execute`.
+        if cfg!(madsim) || self.config().statement_timeout() == 0 {
+            return Ok(fut.await?);
+        }
+
+        let timeout = self.statement_timeout();
+        tokio::select! {
+            rsp = fut => Ok(rsp?),
+            _ = tokio::time::sleep(timeout) => {
+                self.cancel_current_query();
+                Err(RwError::from(SchedulerError::QueryCancelled(format!(
+                    "statement timeout after {} seconds",
+                    timeout.as_secs(),
+                )))
+                .into())
+            }
+        }
+    }
+
+    /// Maximum number of notices buffered (sent but not yet consumed) before further ones are
+    /// collapsed into a single "N additional notices suppressed" summary. Guards against
+    /// per-row notices in DDL/validation paths blowing up memory with tens of thousands of
+    /// buffered strings.
+    const MAX_BUFFERED_NOTICES: usize = 1000;
+
     pub fn notice_to_user(&self, str: impl Into<String>) {
         let notice = str.into();
         tracing::trace!(notice, "notice to user");
-        self.notice_tx
-            .send(notice)
-            .expect("notice channel should not be closed");
+
+        if self.notice_count.fetch_add(1, Ordering::Relaxed) < Self::MAX_BUFFERED_NOTICES {
+            self.notice_tx
+                .send(notice)
+                .expect("notice channel should not be closed");
+        } else {
+            self.notice_count.fetch_sub(1, Ordering::Relaxed);
+            self.suppressed_notice_count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Drains all notices currently buffered for this session, appending a summary line if any
+    /// were dropped by [`Self::notice_to_user`] due to the [`Self::MAX_BUFFERED_NOTICES`] cap.
+    pub fn take_notices(&self) -> Vec<String> {
+        let mut notices = Vec::new();
+        {
+            let mut notice_rx = self.notice_rx.lock();
+            while let Ok(notice) = notice_rx.try_recv() {
+                notices.push(notice);
+            }
+        }
+        self.notice_count.store(0, Ordering::Relaxed);
+
+        let suppressed = self.suppressed_notice_count.swap(0, Ordering::Relaxed);
+        if suppressed > 0 {
+            notices.push(format!("{suppressed} additional notices suppressed"));
+        }
+        notices
+    }
+
+    /// Discards all notices currently buffered for this session, resetting the suppression
+    /// count as well.
+    pub fn clear_notices(&self) {
+        let mut notice_rx = self.notice_rx.lock();
+        while notice_rx.try_recv().is_ok() {}
+        drop(notice_rx);
+
+        self.notice_count.store(0, Ordering::Relaxed);
+        self.suppressed_notice_count.store(0, Ordering::Relaxed);
     }
 
     pub fn is_barrier_read(&self) -> bool {
@@ -1646,7 +1753,10 @@ impl Session for SessionImpl {
         let sql: Arc<str> = Arc::from(sql_str);
         // The handle can be slow. Release potential large String early.
         drop(string);
-        let rsp = handle(self, stmt, sql, vec![format]).await?;
+        let session = self.clone();
+        let rsp = session
+            .run_with_statement_timeout(handle(self, stmt, sql, vec![format]))
+            .await?;
         Ok(rsp)
     }
 
@@ -1689,7 +1799,10 @@ impl Session for SessionImpl {
         self: Arc<Self>,
         portal: Portal,
     ) -> std::result::Result<PgResponse<PgResponseStream>, BoxedError> {
-        let rsp = handle_execute(self, portal).await?;
+        let session = self.clone();
+        let rsp = session
+            .run_with_statement_timeout(handle_execute(self, portal))
+            .await?;
         Ok(rsp)
     }
 
@@ -1739,9 +1852,11 @@ impl Session for SessionImpl {
     }
 
     async fn next_notice(self: &Arc<Self>) -> String {
-        std::future::poll_fn(|cx| self.clone().notice_rx.lock().poll_recv(cx))
+        let notice = std::future::poll_fn(|cx| self.clone().notice_rx.lock().poll_recv(cx))
             .await
-            .expect("notice channel should not be closed")
+            .expect("notice channel should not be closed");
+        self.notice_count.fetch_sub(1, Ordering::Relaxed);
+        notice
     }
 
     fn transaction_status(&self) -> TransactionStatus {
@@ -1749,8 +1864,10 @@ impl Session for SessionImpl {
             transaction::State::Initial | transaction::State::Implicit(_) => {
                 TransactionStatus::Idle
             }
+            transaction::State::Explicit(ctx) if ctx.is_failed() => {
+                TransactionStatus::InFailedTransaction
+            }
             transaction::State::Explicit(_) => TransactionStatus::InTransaction,
-            // TODO: failed transaction
         }
     }
 
@@ -1890,3 +2007,103 @@ pub fn cancel_creating_jobs_in_session(session_id: SessionId, sessions_map: Sess
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_config_if_unset_keeps_earlier_value() {
+        let session = SessionImpl::mock();
+
+        session
+            .set_config("application_name", "first".to_owned())
+            .unwrap();
+        session
+            .set_config_if_unset("application_name", vec!["second".to_owned()])
+            .unwrap();
+
+        assert_eq!(session.config().application_name(), "first");
+    }
+
+    #[test]
+    fn test_set_config_if_unset_sets_default_value() {
+        let session = SessionImpl::mock();
+
+        session
+            .set_config_if_unset("application_name", vec!["first".to_owned()])
+            .unwrap();
+
+        assert_eq!(session.config().application_name(), "first");
+    }
+
+    #[tokio::test]
+    async fn test_statement_timeout_cancels_blocking_handler() {
+        let session = Arc::new(SessionImpl::mock());
+        session
+            .set_config("statement_timeout", "1".to_owned())
+            .unwrap();
+
+        // A handler that never resolves on its own, standing in for a stuck statement.
+        let blocking_handler = std::future::pending();
+
+        let err = session
+            .run_with_statement_timeout(blocking_handler)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("statement timeout"));
+    }
+
+    #[tokio::test]
+    async fn test_statement_timeout_disabled_when_zero() {
+        let session = Arc::new(SessionImpl::mock());
+        session
+            .set_config("statement_timeout", "0".to_owned())
+            .unwrap();
+
+        let rsp = session
+            .run_with_statement_timeout(async {
+                Ok(PgResponse::empty_result(StatementType::EMPTY))
+            })
+            .await
+            .unwrap();
+        assert_eq!(rsp.stmt_type(), StatementType::EMPTY);
+    }
+
+    #[test]
+    fn test_notice_to_user_caps_buffer_and_summarizes() {
+        let session = SessionImpl::mock();
+
+        for i in 0..5000 {
+            session.notice_to_user(format!("notice {i}"));
+        }
+
+        let notices = session.take_notices();
+        // The capped notices, plus one summary line for the rest.
+        assert_eq!(notices.len(), SessionImpl::MAX_BUFFERED_NOTICES + 1);
+        assert_eq!(
+            notices.last().unwrap(),
+            &format!(
+                "{} additional notices suppressed",
+                5000 - SessionImpl::MAX_BUFFERED_NOTICES
+            )
+        );
+
+        // A subsequent call sees an empty buffer, since `take_notices` already drained it.
+        assert!(session.take_notices().is_empty());
+    }
+
+    #[test]
+    fn test_clear_notices_resets_suppression_count() {
+        let session = SessionImpl::mock();
+
+        for i in 0..(SessionImpl::MAX_BUFFERED_NOTICES + 5) {
+            session.notice_to_user(format!("notice {i}"));
+        }
+        session.clear_notices();
+
+        session.notice_to_user("fresh notice".to_owned());
+        let notices = session.take_notices();
+        assert_eq!(notices, vec!["fresh notice".to_owned()]);
+    }
+}
@@ -66,10 +66,38 @@ pub struct Context {
     /// The snapshot of the transaction, acquired lazily at the first read operation in the
     /// transaction.
     snapshot: Option<ReadSnapshot>,
+
+    /// Savepoints declared so far in this transaction, in declaration order.
+    ///
+    /// Since the frontend transaction model is currently read-only (see [`AccessMode::ReadWrite`]
+    /// transactions being unimplemented), a savepoint only needs to remember enough to restore
+    /// read consistency: the snapshot that was pinned when it was declared. There is no DML to
+    /// undo yet.
+    savepoints: Vec<Savepoint>,
+
+    /// Set once a statement inside an explicit transaction errors. While set, `ROLLBACK`/`ABORT`
+    /// is the only statement accepted; everything else is rejected, mirroring Postgres'
+    /// `InFailedTransaction` behavior. Meaningless for [`State::Implicit`], which is always
+    /// dropped (successfully or not) at the end of the single statement it wraps.
+    failed: bool,
+}
+
+impl Context {
+    /// Whether a statement inside this (explicit) transaction has previously errored.
+    pub fn is_failed(&self) -> bool {
+        self.failed
+    }
+}
+
+/// A named savepoint within an explicit transaction.
+///
+/// See [`Context::savepoints`] for the scope of what a savepoint currently captures.
+struct Savepoint {
+    name: String,
+    snapshot: Option<ReadSnapshot>,
 }
 
 /// Transaction state.
-// TODO: failed state
 #[derive(Default)]
 pub enum State {
     /// Initial state, used as a placeholder.
@@ -119,6 +147,8 @@ impl SessionImpl {
                     id: Id::new(),
                     access_mode: AccessMode::ReadWrite,
                     snapshot: Default::default(),
+                    savepoints: Default::default(),
+                    failed: false,
                 })
             }
             State::Implicit(_) => unreachable!("implicit transaction is already in progress"),
@@ -147,6 +177,8 @@ impl SessionImpl {
                     id: ctx.id,
                     access_mode,
                     snapshot: ctx.snapshot.clone(),
+                    savepoints: Default::default(),
+                    failed: false,
                 })
             }
             State::Explicit(_) => {
@@ -157,7 +189,9 @@ impl SessionImpl {
     }
 
     /// Commits an explicit transaction.
-    // TODO: handle failed transaction
+    ///
+    /// Only reachable while the transaction hasn't failed, since [`Self::txn_check_not_failed`]
+    /// rejects every statement but `ROLLBACK`/`ABORT` once it has.
     pub fn txn_commit_explicit(&self) {
         let mut txn = self.txn.lock();
 
@@ -175,7 +209,10 @@ impl SessionImpl {
     }
 
     /// Rollbacks an explicit transaction.
-    // TODO: handle failed transaction
+    ///
+    /// Always accepted, whether or not the transaction has failed: this is how a failed
+    /// transaction ends and its failed flag is cleared, since rolling back resets the state
+    /// to [`State::Initial`] entirely.
     pub fn txn_rollback_explicit(&self) {
         let mut txn = self.txn.lock();
 
@@ -192,6 +229,31 @@ impl SessionImpl {
         }
     }
 
+    /// Marks the current explicit transaction as failed, e.g. after a statement inside it
+    /// errors. No-op outside of an explicit transaction, since [`State::Implicit`] and
+    /// [`State::Initial`] don't persist across statements.
+    pub fn txn_mark_failed(&self) {
+        if let State::Explicit(ctx) = &mut *self.txn.lock() {
+            ctx.failed = true;
+        }
+    }
+
+    /// Returns an error if the current explicit transaction has previously failed. Called before
+    /// dispatching any statement other than `ROLLBACK`/`ABORT`, so that once a statement errors
+    /// inside a transaction, every following one is rejected until the transaction ends.
+    pub fn txn_check_not_failed(&self) -> Result<()> {
+        if let State::Explicit(ctx) = &*self.txn.lock()
+            && ctx.failed
+        {
+            return Err(ErrorCode::InternalError(
+                "current transaction is aborted, commands ignored until end of transaction block"
+                    .to_owned(),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
     /// Returns the transaction context.
     fn txn_ctx(&self) -> MappedMutexGuard<'_, Context> {
         MutexGuard::map(self.txn.lock(), |txn| match txn {
@@ -201,6 +263,64 @@ impl SessionImpl {
         })
     }
 
+    /// Returns the names of the savepoints declared so far in the current transaction, in
+    /// declaration order.
+    pub fn current_transaction_savepoints(&self) -> Vec<String> {
+        self.txn_ctx()
+            .savepoints
+            .iter()
+            .map(|savepoint| savepoint.name.clone())
+            .collect()
+    }
+
+    /// Declares a new savepoint with the given name in the current transaction, capturing the
+    /// currently pinned snapshot (if any) so a later `ROLLBACK TO` can restore it.
+    ///
+    /// Per SQL semantics, declaring a savepoint with a name that's already in use destroys the
+    /// old one.
+    pub fn declare_savepoint(&self, name: String) {
+        let mut ctx = self.txn_ctx();
+        ctx.savepoints.retain(|savepoint| savepoint.name != name);
+        let snapshot = ctx.snapshot.clone();
+        ctx.savepoints.push(Savepoint { name, snapshot });
+    }
+
+    /// Rolls back to the named savepoint, restoring the snapshot pinned at the time it was
+    /// declared and forgetting any savepoints declared after it.
+    ///
+    /// Since the frontend transaction model is currently read-only, this is scoped to snapshot
+    /// and catalog-read consistency; rolling back DML is not supported yet.
+    pub fn rollback_to_savepoint(&self, name: &str) -> Result<()> {
+        let mut ctx = self.txn_ctx();
+        let index = ctx
+            .savepoints
+            .iter()
+            .rposition(|savepoint| savepoint.name == name)
+            .ok_or_else(|| {
+                ErrorCode::InternalError(format!("savepoint \"{}\" does not exist", name))
+            })?;
+
+        ctx.snapshot = ctx.savepoints[index].snapshot.clone();
+        ctx.savepoints.truncate(index + 1);
+        Ok(())
+    }
+
+    /// Releases the named savepoint, forgetting it and any savepoints declared after it, without
+    /// otherwise affecting the transaction's state.
+    pub fn release_savepoint(&self, name: &str) -> Result<()> {
+        let mut ctx = self.txn_ctx();
+        let index = ctx
+            .savepoints
+            .iter()
+            .rposition(|savepoint| savepoint.name == name)
+            .ok_or_else(|| {
+                ErrorCode::InternalError(format!("savepoint \"{}\" does not exist", name))
+            })?;
+
+        ctx.savepoints.truncate(index);
+        Ok(())
+    }
+
     pub fn get_pinned_snapshot(&self) -> Option<ReadSnapshot> {
         self.txn_ctx().snapshot.clone()
     }
@@ -273,3 +393,110 @@ impl SessionImpl {
             .map(|guard| self.env().user_info_writer(guard))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_in_explicit_txn() -> SessionImpl {
+        let session = SessionImpl::mock();
+        // Leak the guard so the implicit transaction isn't auto-committed back to `Initial`
+        // before we upgrade it to an explicit one.
+        std::mem::forget(session.txn_begin_implicit());
+        session.txn_begin_explicit(AccessMode::ReadOnly);
+        session
+    }
+
+    #[test]
+    fn test_savepoint_lifecycle() {
+        let session = session_in_explicit_txn();
+
+        assert!(session.current_transaction_savepoints().is_empty());
+
+        session.declare_savepoint("a".to_owned());
+        session.declare_savepoint("b".to_owned());
+        assert_eq!(
+            session.current_transaction_savepoints(),
+            vec!["a".to_owned(), "b".to_owned()]
+        );
+
+        // Rolling back to "a" keeps "a" but forgets "b".
+        session.rollback_to_savepoint("a").unwrap();
+        assert_eq!(
+            session.current_transaction_savepoints(),
+            vec!["a".to_owned()]
+        );
+
+        session.declare_savepoint("c".to_owned());
+        assert_eq!(
+            session.current_transaction_savepoints(),
+            vec!["a".to_owned(), "c".to_owned()]
+        );
+
+        // Releasing "a" forgets it and everything declared after it.
+        session.release_savepoint("a").unwrap();
+        assert!(session.current_transaction_savepoints().is_empty());
+    }
+
+    #[test]
+    fn test_savepoint_redeclare_replaces_existing() {
+        let session = session_in_explicit_txn();
+
+        session.declare_savepoint("a".to_owned());
+        session.declare_savepoint("b".to_owned());
+        session.declare_savepoint("a".to_owned());
+
+        // Re-declaring "a" drops the old "a" and pushes a fresh one at the end, without
+        // disturbing other savepoints declared in between.
+        assert_eq!(
+            session.current_transaction_savepoints(),
+            vec!["b".to_owned(), "a".to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_failed_transaction_status_and_rejection() {
+        use pgwire::pg_message::TransactionStatus;
+        use pgwire::pg_server::Session;
+
+        let session = session_in_explicit_txn();
+        assert!(matches!(
+            session.transaction_status(),
+            TransactionStatus::InTransaction
+        ));
+        session.txn_check_not_failed().unwrap();
+
+        // Simulate a statement erroring inside the transaction.
+        session.txn_mark_failed();
+        assert!(matches!(
+            session.transaction_status(),
+            TransactionStatus::InFailedTransaction
+        ));
+
+        // A subsequent non-rollback statement is rejected...
+        assert!(session.txn_check_not_failed().is_err());
+
+        // ...until the transaction is rolled back, which clears the failed state entirely.
+        session.txn_rollback_explicit();
+        assert!(matches!(session.transaction_status(), TransactionStatus::Idle));
+    }
+
+    #[test]
+    fn test_rollback_to_nonexistent_savepoint_errors() {
+        let session = session_in_explicit_txn();
+
+        session.declare_savepoint("a".to_owned());
+
+        let err = session.rollback_to_savepoint("nonexistent").unwrap_err();
+        assert!(err.to_string().contains("nonexistent"));
+
+        let err = session.release_savepoint("nonexistent").unwrap_err();
+        assert!(err.to_string().contains("nonexistent"));
+
+        // The existing savepoint is untouched by the failed lookups.
+        assert_eq!(
+            session.current_transaction_savepoints(),
+            vec!["a".to_owned()]
+        );
+    }
+}
@@ -31,7 +31,7 @@ use crate::error::Result;
 use crate::expr::{
     ExprDisplay, ExprImpl, ExprMutator, ExprRewriter, ExprType, ExprVisitor, FunctionCall,
     InequalityInputPair, InputRef, collect_input_refs, column_self_eq_eliminate,
-    factorization_expr, fold_boolean_constant, push_down_not, to_conjunctions,
+    factorization_expr, fold_boolean_constant, merge_eq_to_in, push_down_not, to_conjunctions,
     try_get_bool_constant,
 };
 use crate::utils::condition::cast_compare::{ResultForCmp, ResultForEq};
@@ -97,6 +97,43 @@ impl Condition {
         !self.conjunctions.is_empty() && self.conjunctions.contains(&*FALSE)
     }
 
+    /// Estimate the fraction of rows that satisfy this condition, in `[0, 1]`, for use as a
+    /// join-ordering heuristic when no column statistics are available.
+    ///
+    /// Each conjunct is classified by its predicate shape (equality, range comparison, or `IN`
+    /// list) and assigned a fixed selectivity; conjuncts of an unrecognized shape are treated as
+    /// non-filtering (selectivity `1.0`). Conjuncts are assumed independent and combined
+    /// multiplicatively.
+    pub fn estimated_selectivity(&self) -> f64 {
+        self.conjunctions
+            .iter()
+            .map(|expr| Self::estimate_expr_selectivity(expr))
+            .product()
+    }
+
+    fn estimate_expr_selectivity(expr: &ExprImpl) -> f64 {
+        const EQUALITY_SELECTIVITY: f64 = 0.1;
+        const RANGE_SELECTIVITY: f64 = 0.3;
+
+        let ExprImpl::FunctionCall(func_call) = expr else {
+            return 1.0;
+        };
+        match func_call.func_type() {
+            ExprType::Equal | ExprType::IsNotDistinctFrom => EQUALITY_SELECTIVITY,
+            ExprType::LessThan
+            | ExprType::LessThanOrEqual
+            | ExprType::GreaterThan
+            | ExprType::GreaterThanOrEqual => RANGE_SELECTIVITY,
+            ExprType::In => {
+                // Each candidate is roughly as selective as an equality check; more candidates
+                // make the overall predicate less selective.
+                let candidates = func_call.inputs().len().saturating_sub(1).max(1);
+                (EQUALITY_SELECTIVITY * candidates as f64).min(1.0)
+            }
+            _ => 1.0,
+        }
+    }
+
     /// Convert condition to an expression. If always true, return `None`.
     pub fn as_expr_unless_true(&self) -> Option<ExprImpl> {
         if self.always_true() {
@@ -1136,6 +1173,7 @@ impl Condition {
             .map(push_down_not)
             .map(fold_boolean_constant)
             .map(column_self_eq_eliminate)
+            .map(merge_eq_to_in)
             .flat_map(to_conjunctions)
             .collect();
         let mut res: Vec<ExprImpl> = Vec::new();
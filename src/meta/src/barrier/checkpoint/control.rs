@@ -858,6 +858,39 @@ impl DatabaseCheckpointControl {
                     task.refresh_finished_table_ids
                         .extend(refresh_finished_table_ids);
                 }
+                // Report cache staleness events observed by state tables on the stream side.
+                for resp in &node.state.resps {
+                    for (table_id, count) in &resp.table_cache_stale_stats {
+                        GLOBAL_META_METRICS
+                            .state_table_cache_stale_total
+                            .with_label_values(&[&table_id.to_string()])
+                            .inc_by(u64::from(*count));
+                    }
+                }
+                // Break down barrier latency into inflight and sync components, as reported by
+                // each compute node. The barrier is only as fast as the slowest node.
+                if let Some(inflight_latency_secs) = node
+                    .state
+                    .resps
+                    .iter()
+                    .filter_map(|resp| resp.barrier_inflight_latency_secs)
+                    .max_by(|a, b| a.total_cmp(b))
+                {
+                    GLOBAL_META_METRICS
+                        .barrier_inflight_latency
+                        .observe(inflight_latency_secs);
+                }
+                if let Some(sync_latency_secs) = node
+                    .state
+                    .resps
+                    .iter()
+                    .filter_map(|resp| resp.barrier_sync_latency_secs)
+                    .max_by(|a, b| a.total_cmp(b))
+                {
+                    GLOBAL_META_METRICS
+                        .barrier_sync_latency
+                        .observe(sync_latency_secs);
+                }
 
                 let mut finished_jobs = self.create_mview_tracker.apply_collected_command(
                     node.command_ctx.command.as_ref(),
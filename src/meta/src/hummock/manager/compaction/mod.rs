@@ -28,7 +28,7 @@
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::{Arc, LazyLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use anyhow::Context;
 use compaction_event_loop::{
@@ -1289,7 +1289,9 @@ impl HummockManager {
 
 #[derive(Debug, Default)]
 pub struct CompactionState {
-    scheduled: Mutex<HashSet<(CompactionGroupId, compact_task::TaskType)>>,
+    /// Maps a scheduled `(group, task_type)` to the instant it was scheduled at, i.e. when it
+    /// became a pending, un-dispatched compaction request for that group.
+    scheduled: Mutex<HashMap<(CompactionGroupId, compact_task::TaskType), Instant>>,
 }
 
 impl CompactionState {
@@ -1307,10 +1309,10 @@ impl CompactionState {
     ) -> std::result::Result<bool, SendError<CompactionRequestChannelItem>> {
         let mut guard = self.scheduled.lock();
         let key = (compaction_group, task_type);
-        if guard.contains(&key) {
+        if guard.contains_key(&key) {
             return Ok(false);
         }
-        guard.insert(key);
+        guard.insert(key, Instant::now());
         Ok(true)
     }
 
@@ -1324,20 +1326,31 @@ impl CompactionState {
 
     pub fn auto_pick_type(&self, group: CompactionGroupId) -> Option<TaskType> {
         let guard = self.scheduled.lock();
-        if guard.contains(&(group, compact_task::TaskType::Dynamic)) {
+        if guard.contains_key(&(group, compact_task::TaskType::Dynamic)) {
             Some(compact_task::TaskType::Dynamic)
-        } else if guard.contains(&(group, compact_task::TaskType::SpaceReclaim)) {
+        } else if guard.contains_key(&(group, compact_task::TaskType::SpaceReclaim)) {
             Some(compact_task::TaskType::SpaceReclaim)
-        } else if guard.contains(&(group, compact_task::TaskType::Ttl)) {
+        } else if guard.contains_key(&(group, compact_task::TaskType::Ttl)) {
             Some(compact_task::TaskType::Ttl)
-        } else if guard.contains(&(group, compact_task::TaskType::Tombstone)) {
+        } else if guard.contains_key(&(group, compact_task::TaskType::Tombstone)) {
             Some(compact_task::TaskType::Tombstone)
-        } else if guard.contains(&(group, compact_task::TaskType::VnodeWatermark)) {
+        } else if guard.contains_key(&(group, compact_task::TaskType::VnodeWatermark)) {
             Some(compact_task::TaskType::VnodeWatermark)
         } else {
             None
         }
     }
+
+    /// Returns how long the oldest still-pending (scheduled but not yet unscheduled) compaction
+    /// request for `group` has been waiting, or `None` if the group has nothing pending.
+    pub fn oldest_pending_duration(&self, group: CompactionGroupId) -> Option<Duration> {
+        self.scheduled
+            .lock()
+            .iter()
+            .filter(|((cg, _), _)| *cg == group)
+            .map(|(_, scheduled_at)| scheduled_at.elapsed())
+            .max()
+    }
 }
 
 impl Compaction {
@@ -276,7 +276,19 @@ impl HummockManager {
                                             compaction_group_config.compaction_config(),
                                             group_levels,
                                             compaction_group_config.group_id(),
-                                        )
+                                        );
+
+                                        let oldest_pending_task_age_secs = hummock_manager
+                                            .compaction_state
+                                            .oldest_pending_duration(compaction_group_id)
+                                            .map_or(0, |age| age.as_secs());
+                                        hummock_manager
+                                            .metrics
+                                            .compact_oldest_pending_task_age_secs
+                                            .with_label_values(&[
+                                                &compaction_group_id.to_string(),
+                                            ])
+                                            .set(oldest_pending_task_age_secs as _);
                                     }
 
                                     {
@@ -23,6 +23,7 @@ use prometheus::core::{AtomicU64, GenericCounter};
 use risingwave_hummock_sdk::compact_task::CompactTask;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::object_size_map;
 use risingwave_hummock_sdk::level::Levels;
+use risingwave_hummock_sdk::sstable_info::SstableInfo;
 use risingwave_hummock_sdk::table_stats::PbTableStatsMap;
 use risingwave_hummock_sdk::version::HummockVersion;
 use risingwave_hummock_sdk::{CompactionGroupId, HummockContextId, HummockVersionId};
@@ -130,6 +131,37 @@ pub fn trigger_mv_stat(
     }
 }
 
+/// Counts how many of `table_infos` may hold data for `table_id`. An `SstableInfo` doesn't
+/// record per-table key ranges, only the sorted list of table ids it may contain, so this is the
+/// same coarse approximation already used for id-range pruning (see
+/// `ForwardSstableIterator::new`'s table-id-range handling).
+fn count_ssts_for_table(table_infos: &[SstableInfo], table_id: u32) -> u64 {
+    table_infos
+        .iter()
+        .filter(|sst| sst.table_ids.binary_search(&table_id).is_ok())
+        .count() as u64
+}
+
+/// Computes the read amplification of `table_id` in `levels`: the largest number of SSTs, in
+/// any single level, that a point read for one of the table's keys may need to check. `L0`'s
+/// sub-levels can overlap both each other and (for an `Overlapping` sub-level) internally, so
+/// they're summed together into one level for this purpose; `L1..Ln` are each counted on their
+/// own since a level's own SSTs are otherwise non-overlapping.
+pub fn table_read_amplification(levels: &Levels, table_id: u32) -> u64 {
+    let l0_overlap: u64 = levels
+        .l0
+        .sub_levels
+        .iter()
+        .map(|sub_level| count_ssts_for_table(&sub_level.table_infos, table_id))
+        .sum();
+
+    levels
+        .levels
+        .iter()
+        .map(|level| count_ssts_for_table(&level.table_infos, table_id))
+        .fold(l0_overlap, u64::max)
+}
+
 pub fn trigger_sst_stat(
     metrics: &MetaMetrics,
     compact_status: Option<&CompactStatus>,
@@ -279,6 +311,19 @@ pub fn trigger_sst_stat(
             .set(partition_level_num as i64);
     }
 
+    if let Some(levels) = current_version.levels.get(&compaction_group_id) {
+        for table_id in current_version
+            .state_table_info
+            .compaction_group_member_table_ids(compaction_group_id)
+        {
+            let read_amplification = table_read_amplification(levels, table_id.table_id);
+            metrics
+                .table_read_amplification
+                .with_label_values(&[&table_id.table_id.to_string()])
+                .set(read_amplification as i64);
+        }
+    }
+
     let previous_time = metrics.time_after_last_observation.load(Ordering::Relaxed);
     let current_time = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -731,3 +776,71 @@ pub fn trigger_compact_tasks_stat(
         );
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_hummock_sdk::level::{Level, Levels, OverlappingLevel};
+    use risingwave_hummock_sdk::sstable_info::SstableInfoInner;
+    use risingwave_pb::hummock::LevelType;
+
+    use super::*;
+
+    fn sst_for_tables(table_ids: Vec<u32>) -> SstableInfo {
+        SstableInfoInner {
+            table_ids,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_table_read_amplification_over_synthetic_levels() {
+        // L0 has two overlapping sub-levels, both holding table 1.
+        // L1 splits table 1 across two SSTs (over-approximating amplification, since a real
+        // level's SSTs don't actually overlap in key range, but this metric only has table ids
+        // to go on).
+        // L2 doesn't hold table 1 at all.
+        let levels = Levels {
+            l0: OverlappingLevel {
+                sub_levels: vec![
+                    Level {
+                        level_idx: 0,
+                        level_type: LevelType::Overlapping,
+                        table_infos: vec![sst_for_tables(vec![1])],
+                        ..Default::default()
+                    },
+                    Level {
+                        level_idx: 0,
+                        level_type: LevelType::Overlapping,
+                        table_infos: vec![sst_for_tables(vec![1, 2])],
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+            levels: vec![
+                Level {
+                    level_idx: 1,
+                    level_type: LevelType::Nonoverlapping,
+                    table_infos: vec![sst_for_tables(vec![1]), sst_for_tables(vec![1])],
+                    ..Default::default()
+                },
+                Level {
+                    level_idx: 2,
+                    level_type: LevelType::Nonoverlapping,
+                    table_infos: vec![sst_for_tables(vec![2])],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        // L0 contributes 2 (both sub-levels hold table 1), L1 also contributes 2 (two SSTs both
+        // claim table 1), L2 contributes 0: the max across levels is 2.
+        assert_eq!(table_read_amplification(&levels, 1), 2);
+        // Table 2 only ever appears in one SST per level it's in.
+        assert_eq!(table_read_amplification(&levels, 2), 1);
+        // A table that appears nowhere has zero amplification.
+        assert_eq!(table_read_amplification(&levels, 3), 0);
+    }
+}
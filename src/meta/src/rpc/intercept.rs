@@ -51,7 +51,7 @@ pub struct MetricsMiddleware<S> {
 
 impl<S> Service<http::Request<BoxBody>> for MetricsMiddleware<S>
 where
-    S: Service<http::Request<BoxBody>> + Clone + Send + 'static,
+    S: Service<http::Request<BoxBody>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
     S::Future: Send + 'static,
 {
     type Error = S::Error;
@@ -73,15 +73,19 @@ where
         let metrics = self.metrics.clone();
 
         async move {
-            let path = req.uri().path();
-            let timer = metrics
-                .grpc_latency
-                .with_label_values(&[path])
-                .start_timer();
+            let path = req.uri().path().to_owned();
+            let start = std::time::Instant::now();
 
             let response = inner.call(req).await?;
 
-            timer.observe_duration();
+            let status = match response.headers().get("grpc-status") {
+                Some(code) if code.as_bytes() != b"0" => "err",
+                _ => "ok",
+            };
+            metrics
+                .grpc_latency
+                .with_label_values(&[&path, status])
+                .observe(start.elapsed().as_secs_f64());
 
             Ok(response)
         }
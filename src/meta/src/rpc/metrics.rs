@@ -59,7 +59,7 @@ pub struct MetaMetrics {
     pub meta_type: IntGaugeVec,
 
     // ********************************** gRPC ************************************
-    /// gRPC latency of meta services
+    /// gRPC latency of meta services, labeled by `path` and `status` (`ok`/`err`)
     pub grpc_latency: HistogramVec,
 
     // ********************************** Barrier ************************************
@@ -68,6 +68,12 @@ pub struct MetaMetrics {
     pub barrier_latency: LabelGuardedHistogramVec,
     /// The duration from barrier complete to commit
     pub barrier_wait_commit_latency: Histogram,
+    /// The duration from barrier injection until all actors have collected it, as reported
+    /// by compute nodes via `BarrierCompleteResponse`
+    pub barrier_inflight_latency: Histogram,
+    /// The state store sync latency of checkpoint barriers, as reported by compute nodes via
+    /// `BarrierCompleteResponse`
+    pub barrier_sync_latency: Histogram,
     /// Latency between each barrier send
     pub barrier_send_latency: LabelGuardedHistogramVec,
     /// The number of all barriers. It is the sum of barriers that are in-flight or completed but
@@ -101,6 +107,9 @@ pub struct MetaMetrics {
     pub min_committed_epoch: IntGauge,
     /// The number of SSTs in each level
     pub level_sst_num: IntGaugeVec,
+    /// For each table, the largest number of SSTs across levels whose key range may hold that
+    /// table's data, i.e. how many SSTs a point read might need to check in the worst level.
+    pub table_read_amplification: IntGaugeVec,
     /// The number of SSTs to be merged to next level in each level
     pub level_compact_cnt: IntGaugeVec,
     /// The number of compact tasks
@@ -163,6 +172,8 @@ pub struct MetaMetrics {
     pub compact_skip_frequency: IntCounterVec,
     /// Bytes of lsm tree needed to reach balance
     pub compact_pending_bytes: IntGaugeVec,
+    /// Age, in seconds, of the oldest un-dispatched pending compaction task per group
+    pub compact_oldest_pending_task_age_secs: IntGaugeVec,
     /// Per level compression ratio
     pub compact_level_compression_ratio: GenericGaugeVec<AtomicF64>,
     /// Per level number of running compaction task
@@ -202,6 +213,10 @@ pub struct MetaMetrics {
     /// Write throughput of commit epoch for each stable
     pub table_write_throughput: IntCounterVec,
 
+    /// The number of `cache_may_stale` events observed while updating a state table's vnode
+    /// bitmap, e.g. during scaling, labeled by table id.
+    pub state_table_cache_stale_total: IntCounterVec,
+
     /// The number of compaction groups that have been triggered to move
     pub merge_compaction_group_count: IntCounterVec,
 
@@ -229,7 +244,7 @@ impl MetaMetrics {
             exponential_buckets(0.0001, 2.0, 20).unwrap() // max 52s
         );
         let grpc_latency =
-            register_histogram_vec_with_registry!(opts, &["path"], registry).unwrap();
+            register_histogram_vec_with_registry!(opts, &["path", "status"], registry).unwrap();
 
         let opts = histogram_opts!(
             "meta_barrier_duration_seconds",
@@ -248,6 +263,20 @@ impl MetaMetrics {
         let barrier_wait_commit_latency =
             register_histogram_with_registry!(opts, registry).unwrap();
 
+        let opts = histogram_opts!(
+            "meta_barrier_inflight_duration_seconds",
+            "barrier_inflight_latency",
+            exponential_buckets(0.1, 1.5, 20).unwrap() // max 221s
+        );
+        let barrier_inflight_latency = register_histogram_with_registry!(opts, registry).unwrap();
+
+        let opts = histogram_opts!(
+            "meta_barrier_sync_duration_seconds",
+            "barrier_sync_latency",
+            exponential_buckets(0.1, 1.5, 20).unwrap() // max 221s
+        );
+        let barrier_sync_latency = register_histogram_with_registry!(opts, registry).unwrap();
+
         let opts = histogram_opts!(
             "meta_barrier_send_duration_seconds",
             "barrier send latency",
@@ -343,6 +372,14 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let table_read_amplification = register_int_gauge_vec_with_registry!(
+            "storage_table_read_amplification",
+            "the max number of SSTs overlapping a table's key range in any single level",
+            &["table_id"],
+            registry
+        )
+        .unwrap();
+
         let level_compact_cnt = register_int_gauge_vec_with_registry!(
             "storage_level_compact_cnt",
             "num of SSTs to be merged to next level in each level",
@@ -584,6 +621,14 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let compact_oldest_pending_task_age_secs = register_int_gauge_vec_with_registry!(
+            "storage_compact_oldest_pending_task_age_secs",
+            "age in seconds of the oldest un-dispatched pending compaction task, by group",
+            &["group"],
+            registry
+        )
+        .unwrap();
+
         let compact_level_compression_ratio = register_gauge_vec_with_registry!(
             "storage_compact_level_compression_ratio",
             "compression ratio of each level of the lsm tree",
@@ -732,6 +777,14 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let state_table_cache_stale_total = register_int_counter_vec_with_registry!(
+            "state_table_cache_stale_total",
+            "The number of cache_may_stale events observed while updating a state table's vnode bitmap",
+            &["table_id"],
+            registry
+        )
+        .unwrap();
+
         let split_compaction_group_count = register_int_counter_vec_with_registry!(
             "storage_split_compaction_group_count",
             "Count of trigger split compaction group",
@@ -831,6 +884,8 @@ impl MetaMetrics {
             grpc_latency,
             barrier_latency,
             barrier_wait_commit_latency,
+            barrier_inflight_latency,
+            barrier_sync_latency,
             barrier_send_latency,
             all_barrier_nums,
             in_flight_barrier_nums,
@@ -846,6 +901,7 @@ impl MetaMetrics {
             max_committed_epoch,
             min_committed_epoch,
             level_sst_num,
+            table_read_amplification,
             level_compact_cnt,
             compact_frequency,
             compact_skip_frequency,
@@ -880,6 +936,7 @@ impl MetaMetrics {
             worker_num,
             meta_type,
             compact_pending_bytes,
+            compact_oldest_pending_task_age_secs,
             compact_level_compression_ratio,
             level_compact_task_cnt,
             object_store_metric,
@@ -895,6 +952,7 @@ impl MetaMetrics {
             compact_task_batch_count,
             compact_task_trivial_move_sst_count,
             table_write_throughput,
+            state_table_cache_stale_total,
             split_compaction_group_count,
             state_table_count,
             branched_sst_count,
@@ -1214,3 +1272,98 @@ pub fn start_fragment_info_monitor(
 
     (join_handle, shutdown_tx)
 }
+
+#[cfg(test)]
+mod tests {
+    use prometheus::Registry;
+
+    use super::*;
+
+    #[test]
+    fn test_state_table_cache_stale_total_registered() {
+        let registry = Registry::new();
+        let metrics = MetaMetrics::for_test(&registry);
+        let metric = metrics
+            .state_table_cache_stale_total
+            .with_label_values(&["1"]);
+        assert_eq!(metric.get(), 0);
+    }
+
+    #[test]
+    fn test_compact_oldest_pending_task_age_secs_registered() {
+        let registry = Registry::new();
+        let metrics = MetaMetrics::for_test(&registry);
+        let metric = metrics
+            .compact_oldest_pending_task_age_secs
+            .with_label_values(&["1"]);
+        assert_eq!(metric.get(), 0);
+    }
+
+    #[test]
+    fn test_grpc_latency_has_status_label() {
+        let registry = Registry::new();
+        let metrics = MetaMetrics::for_test(&registry);
+
+        metrics
+            .grpc_latency
+            .with_label_values(&["/some.Service/Method", "ok"])
+            .observe(0.0);
+        metrics
+            .grpc_latency
+            .with_label_values(&["/some.Service/Method", "err"])
+            .observe(0.0);
+
+        let family = registry
+            .gather()
+            .into_iter()
+            .find(|family| family.name() == "meta_grpc_duration_seconds")
+            .expect("grpc_latency should have been observed");
+        assert_eq!(family.get_metric().len(), 2);
+        for metric in family.get_metric() {
+            assert_eq!(metric.get_label().len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_barrier_latency_breakdown_histograms_registered() {
+        let registry = Registry::new();
+        let metrics = MetaMetrics::for_test(&registry);
+
+        // Observe once so each histogram is included in `gather()`.
+        metrics
+            .barrier_latency
+            .with_guarded_label_values(&["0"])
+            .observe(0.0);
+        metrics.barrier_inflight_latency.observe(0.0);
+        metrics.barrier_sync_latency.observe(0.0);
+
+        let bucket_counts: HashMap<String, usize> = registry
+            .gather()
+            .into_iter()
+            .map(|family| {
+                (
+                    family.name().to_owned(),
+                    family.get_metric()[0].get_histogram().get_bucket().len(),
+                )
+            })
+            .collect();
+
+        let expected = bucket_counts["meta_barrier_duration_seconds"];
+        assert_eq!(
+            bucket_counts["meta_barrier_inflight_duration_seconds"],
+            expected
+        );
+        assert_eq!(
+            bucket_counts["meta_barrier_sync_duration_seconds"],
+            expected
+        );
+    }
+
+    #[test]
+    fn test_table_read_amplification_registered() {
+        let registry = Registry::new();
+        let metrics = MetaMetrics::for_test(&registry);
+        let metric = metrics.table_read_amplification.with_label_values(&["1"]);
+        assert_eq!(metric.get(), 0);
+    }
+}
@@ -28,10 +28,11 @@
 #![feature(negative_impls)]
 
 use std::any::type_name;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::future::Future;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, anyhow};
 use async_trait::async_trait;
@@ -91,12 +92,22 @@ pub trait RpcClient: Send + Sync + 'static + Clone {
     }
 }
 
+/// After this many consecutive RPC failures reported against an endpoint via
+/// [`RpcClientPool::report_failure`], its cached connections are evicted so that the next
+/// [`RpcClientPool::get_by_addr`] call reconnects from scratch, instead of reusing a connection
+/// that is likely dead (e.g. because the compute node behind it was restarted).
+const CONSECUTIVE_FAILURE_EVICTION_THRESHOLD: u32 = 3;
+
 #[derive(Clone)]
 pub struct RpcClientPool<S> {
     connection_pool_size: u16,
 
     clients: Cache<HostAddr, Arc<Vec<S>>>,
 
+    /// Number of consecutive RPC failures reported against each endpoint since its last
+    /// successful connection or eviction. Reset on eviction.
+    consecutive_failures: Arc<Mutex<HashMap<HostAddr, u32>>>,
+
     opts: RpcClientConfig,
 }
 
@@ -123,6 +134,7 @@ where
         Self {
             connection_pool_size,
             clients: Cache::new(u64::MAX),
+            consecutive_failures: Arc::new(Mutex::new(HashMap::new())),
             opts,
         }
     }
@@ -156,7 +168,7 @@ where
     /// Gets the RPC client for the given addr. If the connection is not established, a
     /// new client will be created and returned.
     pub async fn get_by_addr(&self, addr: HostAddr) -> Result<S> {
-        Ok(self
+        let client = self
             .clients
             .try_get_with(
                 addr.clone(),
@@ -166,12 +178,48 @@ where
             .with_context(|| format!("failed to create RPC client to {addr}"))?
             .choose(&mut rand::rng())
             .unwrap()
-            .clone())
+            .clone();
+        // A successful connection means `addr` is reachable again, so forget any failures
+        // reported against it previously.
+        self.consecutive_failures.lock().unwrap().remove(&addr);
+        Ok(client)
     }
 
     pub fn invalidate_all(&self) {
         self.clients.invalidate_all()
     }
+
+    /// Number of endpoints with a cached connection.
+    pub fn len(&self) -> u64 {
+        self.clients.entry_count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reports a transport-level RPC failure against `addr`. Should be called by the RPC
+    /// dispatch path whenever a request to `addr` fails with a transport error (as opposed to,
+    /// say, an application-level error returned by an otherwise healthy connection).
+    ///
+    /// Once [`CONSECUTIVE_FAILURE_EVICTION_THRESHOLD`] consecutive failures have been reported
+    /// against `addr` with no intervening success, its cached connections are evicted so the
+    /// next [`Self::get_by_addr`] reconnects instead of reusing a connection that is likely to a
+    /// node that has restarted or is otherwise gone. Returns `true` if this call caused an
+    /// eviction.
+    pub async fn report_failure(&self, addr: HostAddr) -> bool {
+        let should_evict = {
+            let mut failures = self.consecutive_failures.lock().unwrap();
+            let count = failures.entry(addr.clone()).or_insert(0);
+            *count += 1;
+            *count >= CONSECUTIVE_FAILURE_EVICTION_THRESHOLD
+        };
+        if should_evict {
+            self.consecutive_failures.lock().unwrap().remove(&addr);
+            self.clients.invalidate(&addr).await;
+        }
+        should_evict
+    }
 }
 
 #[macro_export]
@@ -373,3 +421,68 @@ impl<REQ, RSP> UnboundedBidiStreamHandle<REQ, RSP> {
             .map_err(|_| anyhow!("unable to send request {}", type_name::<REQ>()).into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct FakeClient;
+
+    static CONNECT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    #[async_trait]
+    impl RpcClient for FakeClient {
+        async fn new_client(_host_addr: HostAddr, _opts: &RpcClientConfig) -> Result<Self> {
+            CONNECT_COUNT.fetch_add(1, Ordering::SeqCst);
+            Ok(Self)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_failure_evicts_and_reconnects() {
+        let pool = RpcClientPool::<FakeClient>::adhoc();
+        let addr = HostAddr::try_from("127.0.0.1:1234").unwrap();
+
+        pool.get_by_addr(addr.clone()).await.unwrap();
+        pool.clients.run_pending_tasks().await;
+        let connects_after_first_get = CONNECT_COUNT.load(Ordering::SeqCst);
+        assert_eq!(pool.len(), 1);
+
+        // Getting the client again should reuse the cached connection.
+        pool.get_by_addr(addr.clone()).await.unwrap();
+        assert_eq!(CONNECT_COUNT.load(Ordering::SeqCst), connects_after_first_get);
+
+        // Failures below the threshold should not evict the cached connection.
+        for _ in 0..CONSECUTIVE_FAILURE_EVICTION_THRESHOLD - 1 {
+            assert!(!pool.report_failure(addr.clone()).await);
+        }
+        pool.clients.run_pending_tasks().await;
+        assert_eq!(pool.len(), 1);
+        assert_eq!(CONNECT_COUNT.load(Ordering::SeqCst), connects_after_first_get);
+
+        // The failure that reaches the threshold evicts the cached connection.
+        assert!(pool.report_failure(addr.clone()).await);
+        pool.clients.run_pending_tasks().await;
+        assert_eq!(pool.len(), 0);
+
+        // The next `get_by_addr` reconnects rather than reusing the (evicted) connection.
+        pool.get_by_addr(addr.clone()).await.unwrap();
+        pool.clients.run_pending_tasks().await;
+        assert_eq!(pool.len(), 1);
+        assert_eq!(
+            CONNECT_COUNT.load(Ordering::SeqCst),
+            connects_after_first_get + 1
+        );
+
+        // A successful call resets the failure count, so it again takes the full threshold to
+        // trigger another eviction.
+        for _ in 0..CONSECUTIVE_FAILURE_EVICTION_THRESHOLD - 1 {
+            assert!(!pool.report_failure(addr.clone()).await);
+        }
+        pool.clients.run_pending_tasks().await;
+        assert_eq!(pool.len(), 1);
+    }
+}
@@ -897,10 +897,20 @@ pub mod range_delete_backward_compatibility_serde_struct {
     use risingwave_common::catalog::TableId;
     use serde::{Deserialize, Serialize};
 
-    #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
     pub struct TableKey(Vec<u8>);
 
-    #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+    impl TableKey {
+        pub fn new(table_key: Vec<u8>) -> Self {
+            TableKey(table_key)
+        }
+
+        pub fn as_slice(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
     pub struct UserKey {
         // When comparing `UserKey`, we first compare `table_id`, then `table_key`. So the order of
         // declaration matters.
@@ -927,7 +937,7 @@ pub mod range_delete_backward_compatibility_serde_struct {
         }
     }
 
-    #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
     pub struct PointRange {
         // When comparing `PointRange`, we first compare `left_user_key`, then
         // `is_exclude_left_key`. Therefore the order of declaration matters.
@@ -60,10 +60,11 @@ pub(crate) mod tests {
     use risingwave_storage::hummock::compactor::compactor_runner::{
         CompactorRunner, compact_with_agent,
     };
+    use risingwave_storage::hummock::compactor::compaction_utils::build_multi_compaction_filter;
     use risingwave_storage::hummock::compactor::fast_compactor_runner::CompactorRunner as FastCompactorRunner;
     use risingwave_storage::hummock::compactor::{
-        CompactionExecutor, CompactorContext, DummyCompactionFilter, StateCleanUpCompactionFilter,
-        TaskProgress,
+        CompactionExecutor, CompactionFilter, CompactorContext, DummyCompactionFilter,
+        MultiCompactionFilter, StateCleanUpCompactionFilter, TaskProgress,
     };
     use risingwave_storage::hummock::iterator::test_utils::mock_sstable_store;
     use risingwave_storage::hummock::iterator::{
@@ -1404,6 +1405,181 @@ pub(crate) mod tests {
         check_compaction_result(compact_ctx.sstable_store, ret, fast_ret, capacity).await;
     }
 
+    /// Like [`run_fast_and_normal_runner`], but with a caller-provided compaction filter instead
+    /// of [`DummyCompactionFilter`], so filter-driven key drops (e.g. TTL) can be exercised
+    /// through both the slow and fast (raw-block-copy) paths.
+    async fn run_fast_and_normal_runner_with_filter<F: CompactionFilter + Clone + 'static>(
+        compact_ctx: CompactorContext,
+        task: CompactTask,
+        compaction_catalog_agent_ref: CompactionCatalogAgentRef,
+        compaction_filter: F,
+    ) -> (Vec<SstableInfo>, Vec<SstableInfo>) {
+        let slow_compact_runner = CompactorRunner::new(
+            0,
+            compact_ctx.clone(),
+            task.clone(),
+            SharedComapctorObjectIdManager::for_test(VecDeque::from_iter([
+                5, 6, 7, 8, 9, 10, 11, 12, 13,
+            ])),
+        );
+
+        let fast_compact_runner = FastCompactorRunner::new(
+            compact_ctx.clone(),
+            task.clone(),
+            compaction_catalog_agent_ref.clone(),
+            SharedComapctorObjectIdManager::for_test(VecDeque::from_iter([
+                22, 23, 24, 25, 26, 27, 28, 29,
+            ])),
+            Arc::new(TaskProgress::default()),
+            compaction_filter.clone(),
+        );
+        let (_, ret1, _) = slow_compact_runner
+            .run(
+                compaction_filter,
+                compaction_catalog_agent_ref,
+                Arc::new(TaskProgress::default()),
+            )
+            .await
+            .unwrap();
+        let ret = ret1.into_iter().map(|sst| sst.sst_info).collect_vec();
+        let (ssts, _) = fast_compact_runner.run().await.unwrap();
+        let fast_ret = ssts.into_iter().map(|sst| sst.sst_info).collect_vec();
+        (ret, fast_ret)
+    }
+
+    /// Keys older than the 1-second TTL must be dropped by the compaction filter, including when
+    /// they'd otherwise be eligible for the raw-block-copy ("trivial move") path that carries a
+    /// block's filter forward from a single input sstable without rebuilding it. This asserts
+    /// that an expired key never leaks into a carried-forward filter as a false negative, and that
+    /// expired keys are actually gone from the output rather than merely skipped by the
+    /// comparison.
+    #[tokio::test]
+    async fn test_fast_compact_ttl_filter() {
+        let (env, hummock_manager_ref, cluster_ctl_ref, worker_id) = setup_compute_env(8080).await;
+        let hummock_meta_client: Arc<dyn HummockMetaClient> = Arc::new(MockHummockMetaClient::new(
+            hummock_manager_ref.clone(),
+            worker_id as _,
+        ));
+        let existing_table_id: u32 = 1;
+        let storage = get_hummock_storage(
+            hummock_meta_client.clone(),
+            get_notification_client_for_test(
+                env,
+                hummock_manager_ref.clone(),
+                cluster_ctl_ref,
+                worker_id,
+            )
+            .await,
+            &hummock_manager_ref,
+            &[existing_table_id],
+        )
+        .await;
+        hummock_manager_ref.get_new_object_ids(10).await.unwrap();
+        let compact_ctx = get_compactor_context(&storage);
+        let compaction_catalog_agent_ref =
+            CompactionCatalogAgent::for_test(vec![existing_table_id]);
+        let sstable_store = compact_ctx.sstable_store.clone();
+        let options = SstableBuilderOptions {
+            capacity: 256 * 1024,
+            block_capacity: 2048,
+            restart_interval: 16,
+            bloom_false_positive: 0.1,
+            compression_algorithm: CompressionAlgorithm::Lz4,
+            ..Default::default()
+        };
+
+        let now = Epoch::now();
+        let expired_epoch = now.subtract_ms(60_000).0;
+        let fresh_epoch = now.0;
+
+        const KEY_COUNT: usize = 2000;
+        let mut expired_data = Vec::with_capacity(KEY_COUNT);
+        let mut fresh_data = Vec::with_capacity(KEY_COUNT);
+        for i in 0..KEY_COUNT as u64 {
+            let key = FullKey::new(
+                TableId::new(existing_table_id),
+                TableKey(i.to_be_bytes().to_vec()),
+                expired_epoch,
+            );
+            expired_data.push((key, HummockValue::put(format!("expired-{i}").into_bytes())));
+
+            let key = FullKey::new(
+                TableId::new(existing_table_id),
+                TableKey((i + KEY_COUNT as u64).to_be_bytes().to_vec()),
+                fresh_epoch,
+            );
+            fresh_data.push((key, HummockValue::put(format!("fresh-{i}").into_bytes())));
+        }
+
+        let sst1 =
+            gen_test_sstable_info(options.clone(), 1, expired_data, sstable_store.clone()).await;
+        let sst2 = gen_test_sstable_info(options.clone(), 2, fresh_data, sstable_store.clone()).await;
+
+        let task = CompactTask {
+            input_ssts: vec![
+                InputLevel {
+                    level_idx: 5,
+                    level_type: risingwave_pb::hummock::LevelType::Nonoverlapping,
+                    table_infos: vec![sst1],
+                },
+                InputLevel {
+                    level_idx: 6,
+                    level_type: risingwave_pb::hummock::LevelType::Nonoverlapping,
+                    table_infos: vec![sst2],
+                },
+            ],
+            existing_table_ids: vec![existing_table_id],
+            task_id: 1,
+            splits: vec![KeyRange::inf()],
+            target_level: 6,
+            base_level: 4,
+            target_file_size: options.capacity as u64,
+            compression_algorithm: 1,
+            gc_delete_keys: true,
+            compaction_filter_mask: CompactionFilterFlag::TTL.bits(),
+            table_options: BTreeMap::from_iter([(
+                existing_table_id,
+                TableOption {
+                    retention_seconds: Some(1),
+                },
+            )]),
+            current_epoch_time: now.0,
+            ..Default::default()
+        };
+
+        let compaction_filter: MultiCompactionFilter = build_multi_compaction_filter(&task);
+        let (ret, fast_ret) = run_fast_and_normal_runner_with_filter(
+            compact_ctx.clone(),
+            task,
+            compaction_catalog_agent_ref,
+            compaction_filter,
+        )
+        .await;
+
+        // Compares the slow and fast runner outputs key-by-key and asserts `may_match_hash`
+        // returns true for every surviving key in both sstable sets, i.e. no false negatives.
+        check_compaction_result(
+            sstable_store.clone(),
+            ret.clone(),
+            fast_ret,
+            options.capacity as u64,
+        )
+        .await;
+
+        // The expired keys must actually be gone, not merely excluded from the comparison above.
+        let mut stats = StoreLocalStatistic::default();
+        let mut key_count = 0;
+        for sst_info in &ret {
+            key_count += sstable_store
+                .sstable(sst_info, &mut stats)
+                .await
+                .unwrap()
+                .meta
+                .key_count;
+        }
+        assert_eq!(key_count as usize, KEY_COUNT);
+    }
+
     #[tokio::test]
     async fn test_fast_compact() {
         const KEY_COUNT: usize = 20000;
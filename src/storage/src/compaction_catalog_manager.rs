@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -33,7 +34,7 @@ use crate::hummock::{HummockError, HummockResult};
 
 /// `FilterKeyExtractor` generally used to extract key which will store in BloomFilter
 pub trait FilterKeyExtractor: Send + Sync {
-    fn extract<'a>(&self, full_key: &'a [u8]) -> &'a [u8];
+    fn extract<'a>(&self, full_key: &'a [u8]) -> Cow<'a, [u8]>;
 }
 
 pub enum FilterKeyExtractorImpl {
@@ -42,6 +43,7 @@ pub enum FilterKeyExtractorImpl {
     Dummy(DummyFilterKeyExtractor),
     Multi(MultiFilterKeyExtractor),
     FixedLength(FixedLengthFilterKeyExtractor),
+    Composite(CompositeFilterKeyExtractor),
 }
 
 impl FilterKeyExtractorImpl {
@@ -61,7 +63,7 @@ impl FilterKeyExtractorImpl {
 macro_rules! impl_filter_key_extractor {
     ($( { $variant_name:ident } ),*) => {
         impl FilterKeyExtractorImpl {
-            pub fn extract<'a>(&self, full_key: &'a [u8]) -> &'a [u8]{
+            pub fn extract<'a>(&self, full_key: &'a [u8]) -> Cow<'a, [u8]>{
                 match self {
                     $( Self::$variant_name(inner) => inner.extract(full_key), )*
                 }
@@ -78,7 +80,8 @@ macro_rules! for_all_filter_key_extractor_variants {
             { FullKey },
             { Dummy },
             { Multi },
-            { FixedLength }
+            { FixedLength },
+            { Composite }
         }
     };
 }
@@ -89,16 +92,16 @@ for_all_filter_key_extractor_variants! { impl_filter_key_extractor }
 pub struct FullKeyFilterKeyExtractor;
 
 impl FilterKeyExtractor for FullKeyFilterKeyExtractor {
-    fn extract<'a>(&self, user_key: &'a [u8]) -> &'a [u8] {
-        user_key
+    fn extract<'a>(&self, user_key: &'a [u8]) -> Cow<'a, [u8]> {
+        Cow::Borrowed(user_key)
     }
 }
 
 #[derive(Default)]
 pub struct DummyFilterKeyExtractor;
 impl FilterKeyExtractor for DummyFilterKeyExtractor {
-    fn extract<'a>(&self, _full_key: &'a [u8]) -> &'a [u8] {
-        &[]
+    fn extract<'a>(&self, _full_key: &'a [u8]) -> Cow<'a, [u8]> {
+        Cow::Borrowed(&[])
     }
 }
 
@@ -109,8 +112,8 @@ pub struct FixedLengthFilterKeyExtractor {
 }
 
 impl FilterKeyExtractor for FixedLengthFilterKeyExtractor {
-    fn extract<'a>(&self, full_key: &'a [u8]) -> &'a [u8] {
-        &full_key[0..self.fixed_length]
+    fn extract<'a>(&self, full_key: &'a [u8]) -> Cow<'a, [u8]> {
+        Cow::Borrowed(&full_key[0..self.fixed_length])
     }
 }
 
@@ -134,9 +137,9 @@ pub struct SchemaFilterKeyExtractor {
 }
 
 impl FilterKeyExtractor for SchemaFilterKeyExtractor {
-    fn extract<'a>(&self, full_key: &'a [u8]) -> &'a [u8] {
+    fn extract<'a>(&self, full_key: &'a [u8]) -> Cow<'a, [u8]> {
         if full_key.len() < TABLE_PREFIX_LEN + VirtualNode::SIZE {
-            return &[];
+            return Cow::Borrowed(&[]);
         }
 
         let (_table_prefix, key) = full_key.split_at(TABLE_PREFIX_LEN);
@@ -151,7 +154,7 @@ impl FilterKeyExtractor for SchemaFilterKeyExtractor {
             .unwrap();
 
         let end_position = TABLE_PREFIX_LEN + VirtualNode::SIZE + bloom_filter_key_len;
-        &full_key[TABLE_PREFIX_LEN + VirtualNode::SIZE..end_position]
+        Cow::Borrowed(&full_key[TABLE_PREFIX_LEN + VirtualNode::SIZE..end_position])
     }
 }
 
@@ -211,9 +214,9 @@ impl Debug for MultiFilterKeyExtractor {
 }
 
 impl FilterKeyExtractor for MultiFilterKeyExtractor {
-    fn extract<'a>(&self, full_key: &'a [u8]) -> &'a [u8] {
+    fn extract<'a>(&self, full_key: &'a [u8]) -> Cow<'a, [u8]> {
         if full_key.len() < TABLE_PREFIX_LEN + VirtualNode::SIZE {
-            return full_key;
+            return Cow::Borrowed(full_key);
         }
 
         let table_id = get_table_id(full_key);
@@ -224,6 +227,38 @@ impl FilterKeyExtractor for MultiFilterKeyExtractor {
     }
 }
 
+/// [`CompositeFilterKeyExtractor`] chains several sub-extractors and concatenates their outputs
+/// to form the bloom filter key. This allows expressing read-prefix hints that don't correspond
+/// to a single contiguous slice of the encoded key, e.g. "the table/vnode prefix plus the first
+/// two primary key columns of variable length".
+///
+/// As with the other extractors, if any sub-extractor yields an empty slice for a row, the whole
+/// row is excluded from the filter by returning an empty slice here too.
+#[derive(Default)]
+pub struct CompositeFilterKeyExtractor {
+    extractors: Vec<FilterKeyExtractorImpl>,
+}
+
+impl FilterKeyExtractor for CompositeFilterKeyExtractor {
+    fn extract<'a>(&self, full_key: &'a [u8]) -> Cow<'a, [u8]> {
+        let mut composite_key = Vec::new();
+        for extractor in &self.extractors {
+            let key = extractor.extract(full_key);
+            if key.is_empty() {
+                return Cow::Borrowed(&[]);
+            }
+            composite_key.extend_from_slice(&key);
+        }
+        Cow::Owned(composite_key)
+    }
+}
+
+impl CompositeFilterKeyExtractor {
+    pub fn new(extractors: Vec<FilterKeyExtractorImpl>) -> Self {
+        Self { extractors }
+    }
+}
+
 #[async_trait::async_trait]
 pub trait StateTableAccessor: Send + Sync {
     async fn get_tables(&self, table_ids: &[u32]) -> RpcResult<HashMap<u32, Table>>;
@@ -464,7 +499,7 @@ impl CompactionCatalogAgent {
 }
 
 impl CompactionCatalogAgent {
-    pub fn extract<'a>(&self, full_key: &'a [u8]) -> &'a [u8] {
+    pub fn extract<'a>(&self, full_key: &'a [u8]) -> Cow<'a, [u8]> {
         self.filter_key_extractor_manager.extract(full_key)
     }
 
@@ -574,7 +609,8 @@ mod tests {
 
     use super::{DummyFilterKeyExtractor, FilterKeyExtractor, SchemaFilterKeyExtractor};
     use crate::compaction_catalog_manager::{
-        FilterKeyExtractorImpl, FullKeyFilterKeyExtractor, MultiFilterKeyExtractor,
+        CompositeFilterKeyExtractor, FilterKeyExtractorImpl, FixedLengthFilterKeyExtractor,
+        FullKeyFilterKeyExtractor, MultiFilterKeyExtractor,
     };
     const fn dummy_vnode() -> [u8; VirtualNode::SIZE] {
         VirtualNode::from_index(233).to_be_bytes()
@@ -586,12 +622,35 @@ mod tests {
         let full_key = "full_key".as_bytes();
         let output_key = dummy_filter_key_extractor.extract(full_key);
 
-        assert_eq!("".as_bytes(), output_key);
+        assert_eq!("".as_bytes(), output_key.as_ref());
 
         let full_key_filter_key_extractor = FullKeyFilterKeyExtractor;
         let output_key = full_key_filter_key_extractor.extract(full_key);
 
-        assert_eq!(full_key, output_key);
+        assert_eq!(full_key, output_key.as_ref());
+    }
+
+    #[test]
+    fn test_composite_filter_key_extractor() {
+        let full_key = "full_key".as_bytes();
+
+        // Concatenates the outputs of its sub-extractors, so no part of either sub-extractor's
+        // output is ever dropped, which is the property that rules out false negatives: a row
+        // that would be kept by any individual extractor's bloom key is also kept here.
+        let composite_filter_key_extractor = CompositeFilterKeyExtractor::new(vec![
+            FilterKeyExtractorImpl::FixedLength(FixedLengthFilterKeyExtractor::new(4)),
+            FilterKeyExtractorImpl::FixedLength(FixedLengthFilterKeyExtractor::new(8)),
+        ]);
+        let output_key = composite_filter_key_extractor.extract(full_key);
+        assert_eq!([&full_key[0..4], &full_key[0..8]].concat(), output_key.as_ref());
+
+        // If any sub-extractor yields an empty slice, the whole row is excluded from the filter.
+        let composite_with_dummy = CompositeFilterKeyExtractor::new(vec![
+            FilterKeyExtractorImpl::FixedLength(FixedLengthFilterKeyExtractor::new(4)),
+            FilterKeyExtractorImpl::Dummy(DummyFilterKeyExtractor),
+        ]);
+        let output_key = composite_with_dummy.extract(full_key);
+        assert!(output_key.is_empty());
     }
 
     fn build_table_with_prefix_column_num(column_count: u32) -> PbTable {
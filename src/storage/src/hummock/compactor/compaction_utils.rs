@@ -84,9 +84,10 @@ impl<W: SstableWriterFactory, F: FilterBuilder> TableBuilderFactory for RemoteBu
         let builder = SstableBuilder::new(
             table_id,
             writer,
-            Self::Filter::create(
+            Self::Filter::create_with_xor16_threshold(
                 self.options.bloom_false_positive,
                 self.options.capacity / DEFAULT_ENTRY_SIZE + 1,
+                self.options.xor16_kv_count_threshold,
             ),
             self.options.clone(),
             self.compaction_catalog_agent_ref.clone(),
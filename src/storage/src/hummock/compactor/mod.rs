@@ -84,7 +84,7 @@ pub use self::compaction_utils::{
 pub use self::task_progress::TaskProgress;
 use super::multi_builder::CapacitySplitTableBuilder;
 use super::{
-    GetObjectId, HummockResult, ObjectIdManager, SstableBuilderOptions, Xor16FilterBuilder,
+    AdaptiveXorFilterBuilder, GetObjectId, HummockResult, ObjectIdManager, SstableBuilderOptions,
 };
 use crate::compaction_catalog_manager::{
     CompactionCatalogAgentRef, CompactionCatalogManager, CompactionCatalogManagerRef,
@@ -170,7 +170,7 @@ impl Compactor {
                 .instrument_await("compact".verbose())
                 .await?
             } else {
-                self.compact_key_range_impl::<_, Xor16FilterBuilder>(
+                self.compact_key_range_impl::<_, AdaptiveXorFilterBuilder>(
                     factory,
                     iter,
                     compaction_filter,
@@ -26,8 +26,12 @@ pub enum HummockErrorInner {
     MagicMismatch { expected: u32, found: u32 },
     #[error("Invalid format version: {0}")]
     InvalidFormatVersion(u32),
-    #[error("Checksum mismatch: expected {expected}, found: {found}")]
-    ChecksumMismatch { expected: u64, found: u64 },
+    #[error("Checksum mismatch in {region}: expected {expected}, found: {found}")]
+    ChecksumMismatch {
+        expected: u64,
+        found: u64,
+        region: String,
+    },
     #[error("Invalid block")]
     InvalidBlock,
     #[error("Encode error: {0}")]
@@ -95,8 +99,13 @@ impl HummockError {
         HummockErrorInner::MagicMismatch { expected, found }.into()
     }
 
-    pub fn checksum_mismatch(expected: u64, found: u64) -> HummockError {
-        HummockErrorInner::ChecksumMismatch { expected, found }.into()
+    pub fn checksum_mismatch(expected: u64, found: u64, region: impl ToString) -> HummockError {
+        HummockErrorInner::ChecksumMismatch {
+            expected,
+            found,
+            region: region.to_string(),
+        }
+        .into()
     }
 
     pub fn meta_error(error: impl ToString) -> HummockError {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::Ordering::{Equal, Less};
+use std::ops::Bound;
 use std::sync::Arc;
 
 use foyer::Hint;
@@ -44,6 +45,12 @@ pub struct BackwardSstableIterator {
 
     // used for checking if the block is valid, filter out the block that is not in the table-id range
     read_block_meta_range: (usize, usize),
+
+    options: Arc<SstableIteratorReadOptions>,
+    /// Set once a key satisfying `must_iterated_begin_user_key` has been visited. Checked on
+    /// exhaustion so a reverse scan that stops early is caught immediately instead of silently
+    /// returning a truncated result.
+    reached_begin_bound: bool,
 }
 
 impl BackwardSstableIterator {
@@ -51,6 +58,20 @@ impl BackwardSstableIterator {
         sstable: TableHolder,
         sstable_store: SstableStoreRef,
         sstable_info_ref: &SstableInfo,
+    ) -> Self {
+        Self::new_with_options(
+            sstable,
+            sstable_store,
+            sstable_info_ref,
+            Arc::new(SstableIteratorReadOptions::default()),
+        )
+    }
+
+    pub fn new_with_options(
+        sstable: TableHolder,
+        sstable_store: SstableStoreRef,
+        sstable_info_ref: &SstableInfo,
+        options: Arc<SstableIteratorReadOptions>,
     ) -> Self {
         let mut start_idx = 0;
         let mut end_idx = sstable.meta.block_metas.len() - 1;
@@ -130,6 +151,8 @@ impl BackwardSstableIterator {
             sstable_store,
             stats: StoreLocalStatistic::default(),
             read_block_meta_range: (start_idx, end_idx),
+            options,
+            reached_begin_bound: false,
         }
     }
 
@@ -171,12 +194,31 @@ impl HummockIterator for BackwardSstableIterator {
 
     async fn next(&mut self) -> HummockResult<()> {
         self.stats.total_key_count += 1;
+        if let Some(bound) = self.options.must_iterated_begin_user_key.as_ref()
+            && !self.reached_begin_bound
+        {
+            let current_user_key = self.key().user_key;
+            self.reached_begin_bound = match bound {
+                Bound::Included(begin_key) => current_user_key <= begin_key.as_ref(),
+                Bound::Excluded(begin_key) => current_user_key < begin_key.as_ref(),
+                Bound::Unbounded => true,
+            };
+        }
+
         let block_iter = self.block_iter.as_mut().expect("no block iter");
         if block_iter.try_prev() {
             Ok(())
         } else {
             // seek to the previous block
-            self.seek_idx(self.cur_idx as isize - 1, None).await
+            self.seek_idx(self.cur_idx as isize - 1, None).await?;
+            if !self.is_valid() {
+                debug_assert!(
+                    self.options.must_iterated_begin_user_key.is_none() || self.reached_begin_bound,
+                    "backward sstable iterator exhausted without reaching must_iterated_begin_user_key {:?}",
+                    self.options.must_iterated_begin_user_key
+                );
+            }
+            Ok(())
         }
     }
 
@@ -197,11 +239,13 @@ impl HummockIterator for BackwardSstableIterator {
     /// Instead of setting idx to 0th block, a `BackwardSstableIterator` rewinds to the last block
     /// in the sstable.
     async fn rewind(&mut self) -> HummockResult<()> {
+        self.reached_begin_bound = false;
         self.seek_idx(self.read_block_meta_range.1 as isize, None)
             .await
     }
 
     async fn seek<'a>(&'a mut self, key: FullKey<&'a [u8]>) -> HummockResult<()> {
+        self.reached_begin_bound = false;
         let block_idx = self
             .sst
             .meta
@@ -241,16 +285,17 @@ impl SstableIteratorType for BackwardSstableIterator {
     fn create(
         sstable: TableHolder,
         sstable_store: SstableStoreRef,
-        _: Arc<SstableIteratorReadOptions>,
+        options: Arc<SstableIteratorReadOptions>,
         sstable_info_ref: &SstableInfo,
     ) -> Self {
-        BackwardSstableIterator::new(sstable, sstable_store, sstable_info_ref)
+        BackwardSstableIterator::new_with_options(sstable, sstable_store, sstable_info_ref, options)
     }
 }
 
 /// Mirror the tests used for `SstableIterator`
 #[cfg(test)]
 mod tests {
+    use bytes::Bytes;
     use itertools::Itertools;
     use rand::prelude::*;
     use rand::rng as thread_rng;
@@ -258,7 +303,7 @@ mod tests {
     use risingwave_common::hash::VirtualNode;
     use risingwave_common::util::epoch::test_epoch;
     use risingwave_hummock_sdk::EpochWithGap;
-    use risingwave_hummock_sdk::key::UserKey;
+    use risingwave_hummock_sdk::key::{TableKey, UserKey};
     use risingwave_hummock_sdk::sstable_info::SstableInfoInner;
 
     use super::*;
@@ -583,4 +628,67 @@ mod tests {
             }
         }
     }
+
+    fn user_key_of(idx: usize) -> UserKey<Bytes> {
+        let full_key = test_key_of(idx);
+        UserKey::new(
+            full_key.user_key.table_id,
+            TableKey(Bytes::from(full_key.user_key.table_key.0)),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_backward_sstable_iterator_must_iterated_begin_user_key() {
+        let sstable_store = mock_sstable_store().await;
+        let (handle, sstable_info) =
+            gen_default_test_sstable(default_builder_opt_for_test(), 0, sstable_store.clone())
+                .await;
+        let options = Arc::new(SstableIteratorReadOptions {
+            cache_policy: crate::hummock::CachePolicy::NotFill,
+            must_iterated_end_user_key: None,
+            must_iterated_begin_user_key: Some(Bound::Included(user_key_of(0))),
+            max_preload_retry_times: 0,
+            prefetch_for_large_query: false,
+        });
+        let mut sstable_iter =
+            BackwardSstableIterator::create(handle, sstable_store, options, &sstable_info);
+        sstable_iter.rewind().await.unwrap();
+        while sstable_iter.is_valid() {
+            sstable_iter.next().await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "must_iterated_begin_user_key"))]
+    async fn test_backward_sstable_iterator_violates_must_iterated_begin_user_key() {
+        let sstable_store = mock_sstable_store().await;
+        let (handle, sstable_info) =
+            gen_default_test_sstable(default_builder_opt_for_test(), 0, sstable_store.clone())
+                .await;
+        // A key smaller than every key actually stored in the sstable, so the reverse scan can
+        // never reach it and must debug-assert on exhaustion.
+        let unreachable_begin_key = UserKey::new(
+            TableId::default(),
+            TableKey(Bytes::from(
+                [
+                    VirtualNode::ZERO.to_be_bytes().as_slice(),
+                    format!("key_aaaa_{:05}", 0).as_bytes(),
+                ]
+                .concat(),
+            )),
+        );
+        let options = Arc::new(SstableIteratorReadOptions {
+            cache_policy: crate::hummock::CachePolicy::NotFill,
+            must_iterated_end_user_key: None,
+            must_iterated_begin_user_key: Some(Bound::Included(unreachable_begin_key)),
+            max_preload_retry_times: 0,
+            prefetch_for_large_query: false,
+        });
+        let mut sstable_iter =
+            BackwardSstableIterator::create(handle, sstable_store, options, &sstable_info);
+        sstable_iter.rewind().await.unwrap();
+        while sstable_iter.is_valid() {
+            sstable_iter.next().await.unwrap();
+        }
+    }
 }
@@ -209,15 +209,51 @@ impl Block {
         Self::decode_with_copy(buf, uncompressed_capacity, false)
     }
 
+    /// Like [`Self::decode`], but names the block's index within its sstable so a checksum
+    /// mismatch can be reported against e.g. `"block 3"` instead of the generic `"block"`.
+    pub fn decode_at(
+        buf: Bytes,
+        uncompressed_capacity: usize,
+        block_index: usize,
+    ) -> HummockResult<Self> {
+        Self::decode_with_copy_at(buf, uncompressed_capacity, false, block_index)
+    }
+
+    /// Like [`Self::decode_with_copy`], but names the block's index within its sstable so a
+    /// checksum mismatch can be reported against e.g. `"block 3"` instead of the generic
+    /// `"block"`.
+    pub fn decode_with_copy_at(
+        buf: Bytes,
+        uncompressed_capacity: usize,
+        copy: bool,
+        block_index: usize,
+    ) -> HummockResult<Self> {
+        Self::decode_with_copy_in_region(
+            buf,
+            uncompressed_capacity,
+            copy,
+            format!("block {block_index}"),
+        )
+    }
+
     pub fn decode_with_copy(
         buf: Bytes,
         uncompressed_capacity: usize,
         copy: bool,
+    ) -> HummockResult<Self> {
+        Self::decode_with_copy_in_region(buf, uncompressed_capacity, copy, "block".to_owned())
+    }
+
+    fn decode_with_copy_in_region(
+        buf: Bytes,
+        uncompressed_capacity: usize,
+        copy: bool,
+        region: String,
     ) -> HummockResult<Self> {
         // Verify checksum.
 
         let xxhash64_checksum = (&buf[buf.len() - 8..]).get_u64_le();
-        xxhash64_verify(&buf[..buf.len() - 8], xxhash64_checksum)?;
+        xxhash64_verify(&buf[..buf.len() - 8], xxhash64_checksum, region)?;
 
         // Decompress.
         let compression = CompressionAlgorithm::decode(&mut &buf[buf.len() - 9..buf.len() - 8])?;
@@ -724,7 +760,7 @@ impl BlockBuilder {
     ) -> HummockResult<Bytes> {
         // Verify checksum.
         let checksum = (&buf[buf.len() - 8..]).get_u64_le();
-        xxhash64_verify(&buf[..buf.len() - 8], checksum)?;
+        xxhash64_verify(&buf[..buf.len() - 8], checksum, "block")?;
         // Decompress.
         let compression = CompressionAlgorithm::decode(&mut &buf[buf.len() - 9..buf.len() - 8])?;
         let compressed_data = &buf[..buf.len() - 9];
@@ -858,6 +894,19 @@ mod tests {
         assert!(!bi.is_valid());
     }
 
+    #[test]
+    fn test_block_decode_at_checksum_mismatch_names_block_index() {
+        let options = BlockBuilderOptions::default();
+        let mut builder = BlockBuilder::new(options);
+        builder.add_for_test(construct_full_key_struct_for_test(0, b"k1", 1), b"v01");
+        let capacity = builder.uncompressed_block_size();
+        let mut buf = builder.build().to_vec();
+        // Corrupt a byte in the block body, leaving the trailing checksum untouched.
+        buf[0] ^= 0xff;
+        let err = Block::decode_at(buf.into(), capacity, 3).unwrap_err();
+        assert!(err.to_string().contains("block 3"));
+    }
+
     #[test]
     fn test_compressed_block_enc_dec() {
         inner_test_compressed(CompressionAlgorithm::Lz4);
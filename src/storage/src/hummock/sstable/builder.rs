@@ -26,10 +26,11 @@ use risingwave_hummock_sdk::table_stats::{TableStats, TableStatsMap};
 use risingwave_hummock_sdk::{HummockEpoch, HummockSstableObjectId, LocalSstableInfo};
 use risingwave_pb::hummock::BloomFilterType;
 
-use super::utils::CompressionAlgorithm;
+use super::utils::{ChecksumAlgorithm, CompressionAlgorithm};
 use super::{
-    BlockBuilder, BlockBuilderOptions, BlockMeta, DEFAULT_BLOCK_SIZE, DEFAULT_ENTRY_SIZE,
-    DEFAULT_RESTART_INTERVAL, SstableMeta, SstableWriter, VERSION,
+    BlockBuilder, BlockBuilderOptions, BlockMeta, DEFAULT_BLOCK_SIZE,
+    DEFAULT_ENTRY_SIZE, DEFAULT_RESTART_INTERVAL, DEFAULT_XOR8_TO_XOR16_KV_COUNT_THRESHOLD,
+    SstableMeta, SstableWriter, VERSION,
 };
 use crate::compaction_catalog_manager::{
     CompactionCatalogAgent, CompactionCatalogAgentRef, FilterKeyExtractorImpl,
@@ -58,9 +59,19 @@ pub struct SstableBuilderOptions {
     pub restart_interval: usize,
     /// False positive probability of bloom filter.
     pub bloom_false_positive: f64,
+    /// Number of unique keys above which [`AdaptiveXorFilterBuilder`] upgrades from an 8-bit
+    /// xor filter to a 16-bit one.
+    ///
+    /// [`AdaptiveXorFilterBuilder`]: super::xor_filter::AdaptiveXorFilterBuilder
+    pub xor16_kv_count_threshold: usize,
     /// Compression algorithm.
     pub compression_algorithm: CompressionAlgorithm,
     pub max_sst_size: u64,
+    /// Column indices whose per-block min/max should be tracked and recorded in
+    /// [`SstableMeta::block_column_stats`]. Currently accepted but not acted upon: see the
+    /// comment at the `block_column_stats` field of the constructed [`SstableMeta`] in
+    /// [`SstableBuilder::finish`] for why.
+    pub track_column_min_max: Vec<usize>,
 }
 
 impl From<&StorageOpts> for SstableBuilderOptions {
@@ -71,8 +82,10 @@ impl From<&StorageOpts> for SstableBuilderOptions {
             block_capacity: (options.block_size_kb as usize) * (1 << 10),
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: options.bloom_false_positive,
+            xor16_kv_count_threshold: options.xor16_kv_count_threshold,
             compression_algorithm: CompressionAlgorithm::None,
             max_sst_size: options.compactor_max_sst_size,
+            track_column_min_max: vec![],
         }
     }
 }
@@ -84,8 +97,10 @@ impl Default for SstableBuilderOptions {
             block_capacity: DEFAULT_BLOCK_SIZE,
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: DEFAULT_BLOOM_FALSE_POSITIVE,
+            xor16_kv_count_threshold: DEFAULT_XOR8_TO_XOR16_KV_COUNT_THRESHOLD,
             compression_algorithm: CompressionAlgorithm::None,
             max_sst_size: DEFAULT_MAX_SST_SIZE,
+            track_column_min_max: vec![],
         }
     }
 }
@@ -129,6 +144,14 @@ pub struct SstableBuilder<W: SstableWriter, F: FilterBuilder> {
     memory_limiter: Option<Arc<MemoryLimiter>>,
 
     block_size_vec: Vec<usize>, // for statistics
+
+    /// Running totals used to compute the average `key + value` size seen so far, which drives
+    /// how early `add_impl` starts checking whether the next row would overflow the current
+    /// block (see the `lookahead_threshold` there). Kept as running sums rather than the fixed
+    /// `capacity / 4 * 3` margin used previously, so a workload of wide rows doesn't get caught
+    /// out by a single row-sized overshoot right at the tail of a block.
+    total_kv_size: u64,
+    kv_count: u64,
 }
 
 impl<W: SstableWriter> SstableBuilder<W, Xor16FilterBuilder> {
@@ -191,6 +214,8 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
             epoch_set: BTreeSet::default(),
             memory_limiter,
             block_size_vec: Vec::new(),
+            total_kv_size: 0,
+            kv_count: 0,
         }
     }
 
@@ -317,10 +342,25 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
         let table_id = full_key.user_key.table_id.table_id();
         let is_new_table = self.last_table_id.is_none() || self.last_table_id.unwrap() != table_id;
         let current_block_size = self.current_block_size();
+        let kv_len = self.raw_key.len() + self.raw_value.len();
+        // A single row wider than a whole block can never share a block with anything else:
+        // give it a block of its own instead of letting it grow whatever block it lands in past
+        // capacity.
+        let is_oversized_row = kv_len >= self.options.block_capacity;
+        // Once the block is within one average-sized row of capacity, start checking the exact
+        // fit of the row about to be added. The lookahead window is sized off the running
+        // average `key + value` size instead of a fixed fraction of `block_capacity`, so it
+        // naturally widens for tables with large rows and narrows for tables with small ones.
+        let avg_kv_size = if self.kv_count == 0 {
+            kv_len
+        } else {
+            (self.total_kv_size / self.kv_count) as usize
+        };
+        let lookahead_threshold = self.options.block_capacity.saturating_sub(avg_kv_size);
         let is_block_full = current_block_size >= self.options.block_capacity
-            || (current_block_size > self.options.block_capacity / 4 * 3
-                && current_block_size + self.raw_value.len() + self.raw_key.len()
-                    > self.options.block_capacity);
+            || is_oversized_row
+            || (current_block_size >= lookahead_threshold
+                && current_block_size + kv_len > self.options.block_capacity);
 
         if is_new_table {
             assert!(
@@ -362,15 +402,18 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
                 uncompressed_size: 0,
                 total_key_count: 0,
                 stale_key_count: 0,
+                // Filled in by `build_block` once the block is sealed.
+                compression_algorithm: CompressionAlgorithm::None,
             });
         }
 
         let table_id = full_key.user_key.table_id.table_id();
-        let mut extract_key = user_key(&self.raw_key);
-        extract_key = self.compaction_catalog_agent_ref.extract(extract_key);
+        let extract_key = self
+            .compaction_catalog_agent_ref
+            .extract(user_key(&self.raw_key));
         // add bloom_filter check
         if !extract_key.is_empty() {
-            self.filter_builder.add_key(extract_key, table_id);
+            self.filter_builder.add_key(&extract_key, table_id);
         }
         self.block_builder.add(full_key, self.raw_value.as_ref());
         self.block_metas.last_mut().unwrap().total_key_count += 1;
@@ -379,12 +422,19 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
         }
         self.last_table_stats.total_key_size += full_key.encoded_len() as i64;
         self.last_table_stats.total_value_size += value.encoded_len() as i64;
+        self.total_kv_size += kv_len as u64;
+        self.kv_count += 1;
 
         self.last_full_key.clear();
         self.last_full_key.extend_from_slice(&self.raw_key);
 
         self.raw_key.clear();
         self.raw_value.clear();
+
+        if is_oversized_row && could_switch_block {
+            // Close the block immediately so nothing else gets appended past this row.
+            self.build_block().await?;
+        }
         Ok(())
     }
 
@@ -437,6 +487,17 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
             .map(|block_meta| block_meta.uncompressed_size as u64)
             .sum::<u64>();
 
+        let (min_epoch, max_epoch) = {
+            if self.epoch_set.is_empty() {
+                (HummockEpoch::MAX, u64::MIN)
+            } else {
+                (
+                    *self.epoch_set.first().unwrap(),
+                    *self.epoch_set.last().unwrap(),
+                )
+            }
+        };
+
         #[expect(deprecated)]
         let mut meta = SstableMeta {
             block_metas: self.block_metas,
@@ -450,9 +511,19 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
             }),
             smallest_key,
             largest_key,
+            smallest_epoch: min_epoch,
+            largest_epoch: max_epoch,
             version: VERSION,
             meta_offset,
             monotonic_tombstone_events: vec![],
+            // `SstableBuilder::add`/`add_impl` only see the already key/value-encoded bytes of
+            // each row and have no access to the row's `DataType`s, so they cannot decode a
+            // tracked column into a comparable value here. `track_column_min_max` is accepted
+            // for forward compatibility but not yet collected against; wiring real collection
+            // needs a schema-aware call site (e.g. compaction) to hand in decoded column values
+            // directly, rather than this byte-oriented layer inferring them.
+            block_column_stats: vec![],
+            checksum_algorithm: ChecksumAlgorithm::default(),
         };
 
         let meta_encode_size = meta.encoded_size();
@@ -508,17 +579,6 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
             }
         };
 
-        let (min_epoch, max_epoch) = {
-            if self.epoch_set.is_empty() {
-                (HummockEpoch::MAX, u64::MIN)
-            } else {
-                (
-                    *self.epoch_set.first().unwrap(),
-                    *self.epoch_set.last().unwrap(),
-                )
-            }
-        };
-
         let sst_info: SstableInfo = SstableInfoInner {
             object_id: self.sst_object_id,
             // use the same sst_id as object_id for initial sst
@@ -618,6 +678,7 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
                     self.block_builder.table_id(),
                 )
             });
+        block_meta.compression_algorithm = self.options.compression_algorithm;
         let block = self.block_builder.build();
         self.writer.write_block(block, block_meta).await?;
         self.block_size_vec.push(block.len());
@@ -800,6 +861,110 @@ pub(super) mod tests {
         assert_eq!(meta2, meta);
     }
 
+    #[tokio::test]
+    async fn test_block_size_tracks_capacity_with_mixed_width_rows() {
+        let opt = SstableBuilderOptions {
+            capacity: 0,
+            block_capacity: 4096,
+            restart_interval: 16,
+            bloom_false_positive: 0.001,
+            ..Default::default()
+        };
+
+        let table_id_to_vnode = HashMap::from_iter(vec![(0, VirtualNode::COUNT_FOR_TEST)]);
+        let table_id_to_watermark_serde = HashMap::from_iter(vec![(0, None)]);
+        let mut b = SstableBuilder::for_test(
+            0,
+            mock_sst_writer(&opt),
+            opt.clone(),
+            table_id_to_vnode,
+            table_id_to_watermark_serde,
+        );
+
+        // `test_value_of` cycles the value length from 1 to 100 bytes, so this exercises the
+        // running-average lookahead against genuinely mixed-width rows rather than uniform ones.
+        for i in 0..TEST_KEYS_COUNT {
+            b.add_for_test(
+                test_key_of(i).to_ref(),
+                HummockValue::put(&test_value_of(i)),
+            )
+            .await
+            .unwrap();
+        }
+
+        let output = b.finish().await.unwrap();
+        let (_, meta) = output.writer_output;
+
+        let block_count = meta.block_metas.len();
+        assert!(block_count > 1, "test data too small to span multiple blocks");
+        // Every block but the last (which is simply whatever is left over) should land close to
+        // the target capacity: neither stopping far short of it nor overshooting it by much more
+        // than a single row.
+        for (i, block) in meta.block_metas[..block_count - 1].iter().enumerate() {
+            let size = block.uncompressed_size as usize;
+            assert!(
+                size >= opt.block_capacity / 2,
+                "block {i} is too small: {size}"
+            );
+            assert!(
+                size <= opt.block_capacity * 3 / 2,
+                "block {i} is too large: {size}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oversized_row_gets_its_own_block() {
+        let opt = SstableBuilderOptions {
+            capacity: 0,
+            block_capacity: 4096,
+            restart_interval: 16,
+            bloom_false_positive: 0.001,
+            ..Default::default()
+        };
+
+        let table_id_to_vnode = HashMap::from_iter(vec![(0, VirtualNode::COUNT_FOR_TEST)]);
+        let table_id_to_watermark_serde = HashMap::from_iter(vec![(0, None)]);
+        let mut b = SstableBuilder::for_test(
+            0,
+            mock_sst_writer(&opt),
+            opt.clone(),
+            table_id_to_vnode,
+            table_id_to_watermark_serde,
+        );
+
+        for i in 0..5 {
+            b.add_for_test(
+                test_key_of(i).to_ref(),
+                HummockValue::put(&test_value_of(i)),
+            )
+            .await
+            .unwrap();
+        }
+        let huge_value = vec![0u8; opt.block_capacity * 2];
+        b.add_for_test(test_key_of(5).to_ref(), HummockValue::put(&huge_value))
+            .await
+            .unwrap();
+        for i in 6..11 {
+            b.add_for_test(
+                test_key_of(i).to_ref(),
+                HummockValue::put(&test_value_of(i)),
+            )
+            .await
+            .unwrap();
+        }
+
+        let output = b.finish().await.unwrap();
+        let (_, meta) = output.writer_output;
+
+        let big_block = meta
+            .block_metas
+            .iter()
+            .find(|block| block.uncompressed_size as usize >= huge_value.len())
+            .expect("no block is large enough to hold the oversized row");
+        assert_eq!(big_block.total_key_count, 1);
+    }
+
     async fn test_with_bloom_filter<F: FilterBuilder>(with_blooms: bool) {
         let key_count = 1000;
 
@@ -856,6 +1021,38 @@ pub(super) mod tests {
         test_with_bloom_filter::<BlockedXor16FilterBuilder>(true).await;
     }
 
+    #[tokio::test]
+    async fn test_filter_false_positive_estimate() {
+        let opts = SstableBuilderOptions {
+            capacity: 0,
+            block_capacity: 4096,
+            restart_interval: 16,
+            bloom_false_positive: 0.01,
+            ..Default::default()
+        };
+
+        let sstable_store = mock_sstable_store().await;
+        let table_id_to_vnode = HashMap::from_iter(vec![(0, VirtualNode::COUNT_FOR_TEST)]);
+        let table_id_to_watermark_serde = HashMap::from_iter(vec![(0, None)]);
+        let sst_info = gen_test_sstable_impl::<Vec<u8>, Xor16FilterBuilder>(
+            opts,
+            0,
+            (0..TEST_KEYS_COUNT).map(|i| (test_key_of(i), HummockValue::put(test_value_of(i)))),
+            sstable_store.clone(),
+            CachePolicy::NotFill,
+            table_id_to_vnode,
+            table_id_to_watermark_serde,
+        )
+        .await;
+        let table = sstable_store
+            .sstable(&sst_info, &mut StoreLocalStatistic::default())
+            .await
+            .unwrap();
+
+        assert!(table.has_bloom_filter());
+        assert!(table.filter_false_positive_estimate() < 0.0001);
+    }
+
     #[tokio::test]
     async fn test_no_bloom_filter_block() {
         let opts = SstableBuilderOptions::default();
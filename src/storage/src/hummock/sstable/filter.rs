@@ -27,6 +27,21 @@ pub trait FilterBuilder: Send {
     fn approximate_len(&self) -> usize;
 
     fn create(fpr: f64, capacity: usize) -> Self;
+
+    /// Like [`Self::create`], but additionally passes the configured xor8-to-xor16 upgrade
+    /// threshold. Only [`super::AdaptiveXorFilterBuilder`] uses it; other implementations keep
+    /// the default, which just forwards to [`Self::create`].
+    fn create_with_xor16_threshold(
+        fpr: f64,
+        capacity: usize,
+        _xor16_kv_count_threshold: usize,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::create(fpr, capacity)
+    }
+
     fn switch_block(&mut self, _memory_limiter: Option<Arc<MemoryLimiter>>) {}
     /// approximate memory when finish filter
     fn approximate_building_memory(&self) -> usize;
@@ -27,6 +27,19 @@ use crate::hummock::sstable::SstableIteratorReadOptions;
 use crate::hummock::{BlockIterator, SstableStoreRef, TableHolder};
 use crate::monitor::StoreLocalStatistic;
 
+/// When an iterator has no known end key, cap how many rows (estimated via `BlockMeta`'s
+/// persisted `total_key_count`) get prefetched in one go. This is also the starting point for
+/// the adaptive ramp-up in [`SstableIterator::ramp_up_unbounded_prefetch`]: a scan that keeps
+/// consuming past its current window doubles this budget for the next one, up to
+/// `MAX_UNBOUNDED_PREFETCH_RAMP_FACTOR` times the base value.
+const MAX_UNBOUNDED_PREFETCH_KEY_COUNT: usize = 65536;
+
+/// Upper bound on how far [`SstableIterator::ramp_up_unbounded_prefetch`] may grow the
+/// unbounded-prefetch key budget, relative to [`MAX_UNBOUNDED_PREFETCH_KEY_COUNT`]. The actual
+/// number of blocks fetched in one request is separately capped by
+/// `StorageOpts::max_prefetch_block_number` inside `SstableStore::prefetch_blocks`.
+const MAX_UNBOUNDED_PREFETCH_RAMP_FACTOR: usize = 8;
+
 pub trait SstableIteratorType: HummockIterator + 'static {
     fn create(
         sstable: TableHolder,
@@ -49,6 +62,15 @@ pub struct SstableIterator {
     pub sst: TableHolder,
     preload_end_block_idx: usize,
     preload_retry_times: usize,
+    /// Whether the current prefetch window was computed against
+    /// `must_iterated_end_user_key: Some(Unbounded)`, i.e. there's no key to stop at and the
+    /// window is instead sized off an estimated key budget. Only this case is eligible for
+    /// [`Self::ramp_up_unbounded_prefetch`]; a window sized off a concrete end key never grows
+    /// past it.
+    prefetch_is_unbounded: bool,
+    /// Key budget backing the unbounded-prefetch window, doubled each time the scan catches up
+    /// to the end of its current window. See [`MAX_UNBOUNDED_PREFETCH_KEY_COUNT`].
+    unbounded_prefetch_key_budget: usize,
 
     sstable_store: SstableStoreRef,
     stats: StoreLocalStatistic,
@@ -153,6 +175,8 @@ impl SstableIterator {
             options,
             preload_end_block_idx: 0,
             preload_retry_times: 0,
+            prefetch_is_unbounded: false,
+            unbounded_prefetch_key_budget: MAX_UNBOUNDED_PREFETCH_KEY_COUNT,
             block_start_idx_inclusive,
             block_end_idx_inclusive,
         }
@@ -165,13 +189,29 @@ impl SstableIterator {
         );
 
         self.preload_end_block_idx = 0;
+        self.prefetch_is_unbounded = false;
         if let Some(bound) = self.options.must_iterated_end_user_key.as_ref() {
             let block_metas = &self.sst.meta.block_metas
                 [self.block_start_idx_inclusive..=self.block_end_idx_inclusive];
             let next_to_start_idx = start_idx + 1;
             if next_to_start_idx <= self.block_end_idx_inclusive {
                 let end_idx = match bound {
-                    Unbounded => self.block_end_idx_inclusive + 1,
+                    // Without an explicit end key we would otherwise prefetch every remaining
+                    // block; instead cap the window using the per-block key counts persisted in
+                    // `BlockMeta` so a single wide-open scan doesn't over-fetch. The budget grows
+                    // over the lifetime of a long scan via `ramp_up_unbounded_prefetch`.
+                    Unbounded => {
+                        self.prefetch_is_unbounded = true;
+                        let mut key_budget = self.unbounded_prefetch_key_budget;
+                        let mut idx = next_to_start_idx;
+                        while idx <= self.block_end_idx_inclusive && key_budget > 0 {
+                            key_budget = key_budget
+                                .saturating_sub(self.sst.meta.block_metas[idx].total_key_count as usize);
+                            idx += 1;
+                        }
+                        idx.max(next_to_start_idx + 1)
+                            .min(self.block_end_idx_inclusive + 1)
+                    }
                     Included(dest_key) => {
                         let dest_key = dest_key.as_ref();
                         self.block_start_idx_inclusive
@@ -203,6 +243,17 @@ impl SstableIterator {
         }
     }
 
+    /// Widens the unbounded-prefetch window: called when the scan has consumed its whole
+    /// current window but more of the sstable remains, which signals that consumption is
+    /// keeping pace with (or outrunning) prefetch. Doubling the key budget makes a long,
+    /// wide-open scan converge toward fetching in large batches, while a short scan that never
+    /// reaches the end of its first window never pays for more than it needs.
+    fn ramp_up_unbounded_prefetch(&mut self, cur_idx: usize) {
+        self.unbounded_prefetch_key_budget = (self.unbounded_prefetch_key_budget * 2)
+            .min(MAX_UNBOUNDED_PREFETCH_KEY_COUNT * MAX_UNBOUNDED_PREFETCH_RAMP_FACTOR);
+        self.init_block_prefetch_range(cur_idx);
+    }
+
     /// Seeks to a block, and then seeks to the key if `seek_key` is given.
     async fn seek_idx(
         &mut self,
@@ -328,6 +379,16 @@ impl SstableIterator {
 
         self.cur_idx = idx;
 
+        // The scan has caught up to the end of its unbounded-prefetch window while there's
+        // still more sstable ahead of it: ramp the budget up for the next window instead of
+        // falling back to fetching one block at a time.
+        if self.prefetch_is_unbounded
+            && idx < self.block_end_idx_inclusive
+            && idx + 1 >= self.preload_end_block_idx
+        {
+            self.ramp_up_unbounded_prefetch(idx);
+        }
+
         Ok(())
     }
 
@@ -596,6 +657,7 @@ mod tests {
         let options = Arc::new(SstableIteratorReadOptions {
             cache_policy: CachePolicy::Fill(Hint::Normal),
             must_iterated_end_user_key: Some(Bound::Included(uk.clone())),
+            must_iterated_begin_user_key: None,
             max_preload_retry_times: 0,
             prefetch_for_large_query: false,
         });
@@ -857,4 +919,95 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_unbounded_prefetch_ramps_up() {
+        let sstable_store = mock_sstable_store().await;
+        let (sstable, sstable_info) =
+            gen_default_test_sstable(default_builder_opt_for_test(), 0, sstable_store.clone())
+                .await;
+        assert!(sstable.meta.block_metas.len() > 10);
+
+        let options = Arc::new(SstableIteratorReadOptions {
+            cache_policy: CachePolicy::Fill(Hint::Normal),
+            must_iterated_end_user_key: Some(Bound::Unbounded),
+            must_iterated_begin_user_key: None,
+            max_preload_retry_times: 0,
+            prefetch_for_large_query: false,
+        });
+        let mut sstable_iter =
+            SstableIterator::create(sstable, sstable_store, options, &sstable_info);
+
+        // Force a narrow starting window so the ramp-up has room to grow within this small test
+        // sstable.
+        sstable_iter.unbounded_prefetch_key_budget = 1;
+        sstable_iter.rewind().await.unwrap();
+        assert!(sstable_iter.prefetch_is_unbounded);
+        let initial_window = sstable_iter.preload_end_block_idx;
+        assert!(
+            initial_window <= sstable_iter.block_end_idx_inclusive + 1,
+            "initial window must not exceed the sstable"
+        );
+
+        let mut last_window = initial_window;
+        let mut widened = false;
+        while sstable_iter.is_valid() {
+            sstable_iter.next().await.unwrap();
+            if !sstable_iter.is_valid() {
+                break;
+            }
+            assert!(sstable_iter.preload_end_block_idx >= last_window);
+            if sstable_iter.preload_end_block_idx > last_window {
+                widened = true;
+            }
+            last_window = sstable_iter.preload_end_block_idx;
+        }
+
+        assert!(
+            widened,
+            "a scan that outlives its first narrow window should ramp up prefetch depth"
+        );
+        assert_eq!(
+            last_window,
+            sstable_iter.block_end_idx_inclusive + 1,
+            "by the end of the scan the window should have grown to cover the rest of the sstable"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bounded_prefetch_never_crosses_end_key() {
+        let sstable_store = mock_sstable_store().await;
+        let (sstable, sstable_info) =
+            gen_default_test_sstable(default_builder_opt_for_test(), 0, sstable_store.clone())
+                .await;
+        assert!(sstable.meta.block_metas.len() > 10);
+
+        let end_key = test_key_of(TEST_KEYS_COUNT / 2);
+        let uk = UserKey::new(
+            end_key.user_key.table_id,
+            TableKey(Bytes::from(end_key.user_key.table_key.0)),
+        );
+        let options = Arc::new(SstableIteratorReadOptions {
+            cache_policy: CachePolicy::Fill(Hint::Normal),
+            must_iterated_end_user_key: Some(Bound::Included(uk)),
+            must_iterated_begin_user_key: None,
+            max_preload_retry_times: 0,
+            prefetch_for_large_query: false,
+        });
+        let mut sstable_iter =
+            SstableIterator::create(sstable, sstable_store, options, &sstable_info);
+        sstable_iter.rewind().await.unwrap();
+        assert!(!sstable_iter.prefetch_is_unbounded);
+
+        let bounded_window = sstable_iter.preload_end_block_idx;
+        assert!(bounded_window <= sstable_iter.block_end_idx_inclusive);
+
+        while sstable_iter.is_valid() {
+            sstable_iter.next().await.unwrap();
+            // A bounded window never ramps: it's sized directly off the end key and consuming
+            // more of the scan must not push it further out.
+            assert_eq!(sstable_iter.preload_end_block_idx, bounded_window);
+            assert!(!sstable_iter.prefetch_is_unbounded);
+        }
+    }
 }
@@ -28,8 +28,10 @@ mod bloom;
 mod xor_filter;
 pub use bloom::BloomFilterBuilder;
 use serde::{Deserialize, Serialize};
+pub(crate) use xor_filter::DEFAULT_XOR8_TO_XOR16_KV_COUNT_THRESHOLD;
 pub use xor_filter::{
-    BlockedXor16FilterBuilder, Xor8FilterBuilder, Xor16FilterBuilder, XorFilterReader,
+    AdaptiveXorFilterBuilder, BlockedXor16FilterBuilder, Xor8FilterBuilder, Xor16FilterBuilder,
+    XorFilterReader,
 };
 pub mod builder;
 pub use builder::*;
@@ -50,7 +52,7 @@ mod filter;
 mod utils;
 
 pub use filter::FilterBuilder;
-pub use utils::{CompressionAlgorithm, xxhash64_checksum, xxhash64_verify};
+pub use utils::{ChecksumAlgorithm, CompressionAlgorithm, xxhash64_checksum, xxhash64_verify};
 use utils::{get_length_prefixed_slice, put_length_prefixed_slice};
 use xxhash_rust::{xxh32, xxh64};
 
@@ -60,7 +62,23 @@ use crate::store::ReadOptions;
 
 const MAGIC: u32 = 0x5785ab73;
 const OLD_VERSION: u32 = 1;
-const VERSION: u32 = 2;
+/// `version` 2 always checksums the meta block with `XxHash64` and has no
+/// `checksum_algorithm` byte; `version` 3 adds a `checksum_algorithm` byte right before the
+/// checksum so a different algorithm can be selected per-meta. `version` 4 additionally
+/// records a `compression_algorithm` byte in each `BlockMeta`, so blocks within the same
+/// sstable can be compressed with different algorithms. `version` 5 additionally records the
+/// smallest and largest epoch among the sstable's keys, so a reader at a historical epoch can
+/// skip the whole sstable without opening it. `version` 6 additionally records a per-block
+/// column min/max section (see [`SstableMeta::block_column_stats`]). `version` 7 additionally
+/// appends a list of tagged, length-prefixed sections after `meta_offset` (see
+/// [`SstableMeta::skip_unknown_sections`]) so that fields added by a future version can be
+/// skipped by an older decoder instead of failing to parse.
+const VERSION_WITHOUT_CHECKSUM_ALGORITHM: u32 = 2;
+const VERSION_WITHOUT_BLOCK_COMPRESSION_ALGORITHM: u32 = 3;
+const VERSION_WITHOUT_EPOCH_RANGE: u32 = 4;
+const VERSION_WITHOUT_COLUMN_STATS: u32 = 5;
+const VERSION_WITHOUT_SECTIONS: u32 = 6;
+const VERSION: u32 = 7;
 
 /// Assume that watermark1 is 5, watermark2 is 7, watermark3 is 11, delete ranges
 /// `{ [0, wmk1) in epoch1, [wmk1, wmk2) in epoch2, [wmk2, wmk3) in epoch3 }`
@@ -75,6 +93,20 @@ const VERSION: u32 = 2;
 /// next event key wmk2 (7) (not inclusive).
 /// If there is no range deletes between current event key and next event key, `new_epoch` will be
 /// `HummockEpoch::MAX`.
+// NOTE: this snapshot no longer has a `CompactionDeleteRangesBuilder` (nor the
+// `create_monotonic_events_from_compaction_delete_events` helper that used to build
+// `MonotonicDeleteEvent`s from per-compaction-input tombstones): range-tombstone construction
+// during compaction was reworked upstream and that builder was removed from this codebase.
+// `MonotonicDeleteEvent` itself only survives here for decoding sstables written by older
+// versions. A sorted-input fast path for a builder that doesn't exist has nothing to attach to;
+// if that construction path is reintroduced, this is the natural place to add
+// `add_tombstones_sorted` alongside a debug-assert that the input is sorted.
+//
+// This is also where a pre-pass that coalesces adjacent `DeleteRangeTombstone`s sharing a
+// `sequence` (respecting `is_left_open`/`is_right_close` so an exclusive gap is never merged
+// across) would belong, since it only makes sense as a step of that builder, run before turning
+// the coalesced ranges into `MonotonicDeleteEvent`s. There is no standalone
+// `DeleteRangeTombstone` type in this codebase to write such a pass against today.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct MonotonicDeleteEvent {
     pub event_key:
@@ -162,6 +194,19 @@ impl Sstable {
         !self.filter_reader.is_empty()
     }
 
+    /// Theoretical false-positive rate of this SST's xor filter, derived from its fingerprint
+    /// width: roughly `2^-8` for an 8-bit filter (`Xor8FilterBuilder`) and `2^-16` for a 16-bit
+    /// one (`Xor16FilterBuilder`/`BlockedXor16FilterBuilder`). Unlike a Bloom filter, an xor
+    /// filter's false-positive rate is essentially independent of the number of entries once
+    /// built, so it's determined entirely by the fingerprint width. Returns `0.0` when
+    /// [`Self::has_bloom_filter`] is `false`.
+    pub fn filter_false_positive_estimate(&self) -> f64 {
+        match self.filter_reader.fingerprint_bits() {
+            Some(bits) => 2.0f64.powi(-(bits as i32)),
+            None => 0.0,
+        }
+    }
+
     pub fn calculate_block_info(&self, block_index: usize) -> (Range<usize>, usize) {
         let block_meta = &self.meta.block_metas[block_index];
         let range =
@@ -189,11 +234,99 @@ impl Sstable {
         self.filter_reader.may_match(user_key_range, hash)
     }
 
+    /// Batched variant of [`Self::may_match_hash`]: checks many prefix hashes against this SST
+    /// in one call, first skipping the whole SST via [`Self::range_may_match`] if none of the
+    /// hashes could possibly be present, avoiding a bloom filter probe per key.
+    pub fn may_exist_multi(&self, user_key_range: &UserKeyRangeRef<'_>, hashes: &[u64]) -> Vec<bool> {
+        if !self.range_may_match(user_key_range) {
+            return vec![false; hashes.len()];
+        }
+        hashes
+            .iter()
+            .map(|&hash| self.may_match_hash(user_key_range, hash))
+            .collect()
+    }
+
+    /// Returns `false` if `user_key_range` definitely does not overlap with this SST's
+    /// `[smallest_key, largest_key]`, allowing callers to skip the whole SST (including the
+    /// bloom filter probe) before paying for block reads.
+    pub fn range_may_match(&self, user_key_range: &UserKeyRangeRef<'_>) -> bool {
+        let smallest = FullKey::decode(&self.meta.smallest_key).user_key;
+        let largest = FullKey::decode(&self.meta.largest_key).user_key;
+        crate::hummock::utils::range_overlap(
+            user_key_range,
+            &smallest,
+            Bound::Included(&largest),
+        )
+    }
+
+    /// Returns `false` if this SST's `[smallest_epoch, largest_epoch]` is entirely newer than
+    /// `read_epoch`, allowing callers reading at a historical epoch to skip the whole SST.
+    #[inline(always)]
+    pub fn epoch_overlaps(&self, read_epoch: HummockEpoch) -> bool {
+        self.meta.smallest_epoch <= read_epoch
+    }
+
     #[inline(always)]
     pub fn block_count(&self) -> usize {
         self.meta.block_metas.len()
     }
 
+    /// Binary-searches [`SstableMeta::block_metas`] for the index of the block that may contain
+    /// `user_key`, letting seek-heavy workloads skip the linear scan iterators otherwise do.
+    /// A key before the first block's smallest key returns block `0`; a key after the last
+    /// block's smallest key returns the last block's index.
+    pub fn block_index_for_key(&self, user_key: &UserKey<&[u8]>) -> usize {
+        self.meta
+            .block_metas
+            .partition_point(|block_meta| {
+                FullKey::decode(&block_meta.smallest_key).user_key.le(user_key)
+            })
+            .saturating_sub(1)
+    }
+
+    /// Returns the span of block indices that may overlap `user_key_range`, so callers (e.g. a
+    /// scan executor warming the block cache before a large ordered scan) can issue batched
+    /// prefetches for exactly the blocks a scan would otherwise fault in one at a time. Uses
+    /// [`Self::block_index_for_key`] on both ends and is therefore an over-approximation at the
+    /// boundaries, same as that method; always clamped to `[0, block_count()]`.
+    pub fn blocks_in_range(&self, user_key_range: &UserKeyRangeRef<'_>) -> Range<usize> {
+        if self.block_count() == 0 || !self.range_may_match(user_key_range) {
+            return 0..0;
+        }
+        let start = match &user_key_range.0 {
+            Bound::Included(key) | Bound::Excluded(key) => self.block_index_for_key(&key.as_ref()),
+            Bound::Unbounded => 0,
+        };
+        let end = match &user_key_range.1 {
+            Bound::Included(key) | Bound::Excluded(key) => {
+                self.block_index_for_key(&key.as_ref()) + 1
+            }
+            Bound::Unbounded => self.block_count(),
+        };
+        let start = start.min(self.block_count());
+        let end = end.clamp(start, self.block_count());
+        start..end
+    }
+
+    /// Returns the `(min, max)` byte range recorded for `col` (an index into whatever columns
+    /// were requested via [`SstableBuilderOptions::track_column_min_max`]) within block
+    /// `block_idx`, or `None` if no such block/column stat was recorded (either the
+    /// sstable was written before `VERSION_WITHOUT_SECTIONS`, the column wasn't tracked, or the
+    /// block has no non-null value for it).
+    ///
+    /// The bounds are still in their on-disk encoded representation: this storage layer has no
+    /// access to the column's `DataType`, so decoding them into a comparable value is left to
+    /// the caller (see `risingwave_common::util::value_encoding`).
+    pub fn block_column_range(&self, block_idx: usize, col: usize) -> Option<(&[u8], &[u8])> {
+        self.meta
+            .block_column_stats
+            .get(block_idx)?
+            .get(col)?
+            .as_ref()
+            .map(|(min, max)| (min.as_slice(), max.as_slice()))
+    }
+
     #[inline(always)]
     pub fn estimate_size(&self) -> usize {
         8 /* id */ + self.filter_reader.estimate_size() + self.meta.encoded_size()
@@ -208,13 +341,17 @@ pub struct BlockMeta {
     pub uncompressed_size: u32,
     pub total_key_count: u32,
     pub stale_key_count: u32,
+    /// Compression algorithm used to compress this block, as chosen by the sstable builder
+    /// when it sealed the block. Blocks within the same sstable may use different algorithms,
+    /// e.g. to keep recently written blocks uncompressed while compressing older ones.
+    pub compression_algorithm: CompressionAlgorithm,
 }
 
 impl BlockMeta {
     /// Format:
     ///
     /// ```plain
-    /// | offset (4B) | len (4B) | uncompressed size (4B) | smallest key len (4B) | smallest key |
+    /// | offset (4B) | len (4B) | uncompressed size (4B) | total key count (4B) | stale key count (4B) | compression algorithm (1B) | smallest key len (4B) | smallest key |
     /// ```
     pub fn encode(&self, mut buf: impl BufMut) {
         buf.put_u32_le(self.offset);
@@ -222,6 +359,7 @@ impl BlockMeta {
         buf.put_u32_le(self.uncompressed_size);
         buf.put_u32_le(self.total_key_count);
         buf.put_u32_le(self.stale_key_count);
+        self.compression_algorithm.encode(&mut buf);
         put_length_prefixed_slice(buf, &self.smallest_key);
     }
 
@@ -230,6 +368,30 @@ impl BlockMeta {
         let len = buf.get_u32_le();
         let uncompressed_size = buf.get_u32_le();
 
+        let total_key_count = buf.get_u32_le();
+        let stale_key_count = buf.get_u32_le();
+        let compression_algorithm = CompressionAlgorithm::decode(buf)
+            .expect("invalid compression algorithm in block meta");
+        let smallest_key = get_length_prefixed_slice(buf);
+        Self {
+            smallest_key,
+            offset,
+            len,
+            uncompressed_size,
+            total_key_count,
+            stale_key_count,
+            compression_algorithm,
+        }
+    }
+
+    /// Decodes a `BlockMeta` encoded by a builder older than
+    /// `VERSION_WITHOUT_EPOCH_RANGE`, i.e. one that does not record a per-block
+    /// [`CompressionAlgorithm`]. Defaults `compression_algorithm` to
+    /// [`CompressionAlgorithm::None`].
+    pub fn decode_without_compression_algorithm(buf: &mut &[u8]) -> Self {
+        let offset = buf.get_u32_le();
+        let len = buf.get_u32_le();
+        let uncompressed_size = buf.get_u32_le();
         let total_key_count = buf.get_u32_le();
         let stale_key_count = buf.get_u32_le();
         let smallest_key = get_length_prefixed_slice(buf);
@@ -240,6 +402,7 @@ impl BlockMeta {
             uncompressed_size,
             total_key_count,
             stale_key_count,
+            compression_algorithm: CompressionAlgorithm::None,
         }
     }
 
@@ -257,12 +420,13 @@ impl BlockMeta {
             uncompressed_size,
             total_key_count,
             stale_key_count,
+            compression_algorithm: CompressionAlgorithm::None,
         }
     }
 
     #[inline]
     pub fn encoded_size(&self) -> usize {
-        24 /* offset + len + key len + uncompressed size + total key count + stale key count */ + self.smallest_key.len()
+        25 /* offset + len + key len + uncompressed size + total key count + stale key count + compression algorithm */ + self.smallest_key.len()
     }
 
     pub fn table_id(&self) -> TableId {
@@ -278,6 +442,13 @@ pub struct SstableMeta {
     pub key_count: u32,
     pub smallest_key: Vec<u8>,
     pub largest_key: Vec<u8>,
+    /// Minimum and maximum [`HummockEpoch`] (the epoch part of [`FullKey`]) among this
+    /// sstable's keys, so a reader at a historical epoch can skip the whole sstable via
+    /// [`Sstable::epoch_overlaps`] without opening it. Sstables written before
+    /// `VERSION_WITHOUT_COLUMN_STATS` don't have this recorded and default to
+    /// `(0, HummockEpoch::MAX)` on decode, i.e. never pruned.
+    pub smallest_epoch: HummockEpoch,
+    pub largest_epoch: HummockEpoch,
     pub meta_offset: u64,
     /// Assume that watermark1 is 5, watermark2 is 7, watermark3 is 11, delete ranges
     /// `{ [0, wmk1) in epoch1, [wmk1, wmk2) in epoch2, [wmk2, wmk3) in epoch3 }`
@@ -294,8 +465,19 @@ pub struct SstableMeta {
     /// be `HummockEpoch::MAX`.
     #[deprecated]
     pub monotonic_tombstone_events: Vec<MonotonicDeleteEvent>,
+    /// Per-block min/max byte range of the columns requested via
+    /// [`SstableBuilderOptions::track_column_min_max`], one entry per block, each holding one
+    /// `Option<(min, max)>` per tracked column in the same
+    /// order they were requested (`None` if the block has no non-null value for that column).
+    /// Sstables written before `VERSION_WITHOUT_SECTIONS` don't have this recorded and default
+    /// to empty on decode. [`SstableBuilder`] does not collect these stats and always writes an
+    /// empty list; only a meta constructed with entries here carries any.
+    pub block_column_stats: Vec<Vec<Option<(Vec<u8>, Vec<u8>)>>>,
     /// Format version, for further compatibility.
     pub version: u32,
+    /// Algorithm used to checksum this meta block. Older meta (`version < 3`) was always
+    /// checksummed with `XxHash64`, so this defaults accordingly on decode.
+    pub checksum_algorithm: ChecksumAlgorithm,
 }
 
 impl SstableMeta {
@@ -308,11 +490,21 @@ impl SstableMeta {
     /// | estimated size (4B) | key count (4B) |
     /// | smallest key len (4B) | smallest key |
     /// | largest key len (4B) | largest key |
+    /// | smallest epoch (8B) | largest epoch (8B) |
     /// | K (4B) |
     /// | tombstone-event 0 | ... | tombstone-event K-1 |
+    /// | block count M (4B) |
+    /// | for each block: column count C (4B), then C entries of |
+    /// |   present (1B) | [min len (4B) | min | max len (4B) | max] if present |
     /// | file offset of this meta block (8B) |
-    /// | checksum (8B) | version (4B) | magic (4B) |
+    /// | section count S (4B) |
+    /// | for each section: tag (1B) | length (4B) | section bytes |
+    /// | checksum algorithm (1B) | checksum (8B) | version (4B) | magic (4B) |
     /// ```
+    ///
+    /// The section list is the extension point for future versions: a decoder that doesn't
+    /// recognize a section's tag skips it by length instead of failing, so it can still read the
+    /// fields it does understand. See [`Self::skip_unknown_sections`].
     pub fn encode_to_bytes(&self) -> Vec<u8> {
         let encoded_size = self.encoded_size();
         let mut buf = Vec::with_capacity(encoded_size);
@@ -341,6 +533,8 @@ impl SstableMeta {
         buf.put_u32_le(self.key_count);
         put_length_prefixed_slice(&mut buf, &self.smallest_key);
         put_length_prefixed_slice(&mut buf, &self.largest_key);
+        buf.put_u64_le(self.smallest_epoch);
+        buf.put_u64_le(self.largest_epoch);
         #[expect(deprecated)]
         buf.put_u32_le(
             utils::checked_into_u32(self.monotonic_tombstone_events.len()).unwrap_or_else(|_| {
@@ -356,11 +550,38 @@ impl SstableMeta {
         for monotonic_tombstone_event in &self.monotonic_tombstone_events {
             monotonic_tombstone_event.encode(&mut buf);
         }
+        buf.put_u32_le(utils::checked_into_u32(self.block_column_stats.len()).unwrap_or_else(
+            |_| panic!(
+                "WARN overflow can't convert block_column_stats_len {} into u32",
+                self.block_column_stats.len(),
+            ),
+        ));
+        for columns in &self.block_column_stats {
+            buf.put_u32_le(utils::checked_into_u32(columns.len()).unwrap_or_else(|_| {
+                panic!("WARN overflow can't convert column count {} into u32", columns.len())
+            }));
+            for column in columns {
+                match column {
+                    Some((min, max)) => {
+                        buf.put_u8(1);
+                        put_length_prefixed_slice(&mut buf, min);
+                        put_length_prefixed_slice(&mut buf, max);
+                    }
+                    None => buf.put_u8(0),
+                }
+            }
+        }
         buf.put_u64_le(self.meta_offset);
+        // No sections are defined yet; future fields should be appended here as a new tagged
+        // section rather than inserted into the fixed layout above, so that decoders built
+        // against an older version can skip them (see `skip_unknown_sections`) instead of
+        // misparsing the rest of the meta block.
+        buf.put_u32_le(0);
 
         let end = buf.as_ref().len();
 
-        let checksum = xxhash64_checksum(&buf.as_ref()[start..end]);
+        let checksum = self.checksum_algorithm.checksum(&buf.as_ref()[start..end]);
+        buf.put_u8(self.checksum_algorithm.into());
         buf.put_u64_le(checksum);
         buf.put_u32_le(VERSION);
         buf.put_u32_le(MAGIC);
@@ -377,14 +598,35 @@ impl SstableMeta {
 
         cursor -= 4;
         let version = (&buf[cursor..cursor + 4]).get_u32_le();
-        if version != VERSION && version != OLD_VERSION {
+        if version != VERSION
+            && version != VERSION_WITHOUT_SECTIONS
+            && version != VERSION_WITHOUT_COLUMN_STATS
+            && version != VERSION_WITHOUT_EPOCH_RANGE
+            && version != VERSION_WITHOUT_BLOCK_COMPRESSION_ALGORITHM
+            && version != VERSION_WITHOUT_CHECKSUM_ALGORITHM
+            && version != OLD_VERSION
+        {
             return Err(HummockError::invalid_format_version(version));
         }
 
         cursor -= 8;
         let checksum = (&buf[cursor..cursor + 8]).get_u64_le();
+
+        let checksum_algorithm = if version == VERSION
+            || version == VERSION_WITHOUT_SECTIONS
+            || version == VERSION_WITHOUT_COLUMN_STATS
+            || version == VERSION_WITHOUT_EPOCH_RANGE
+            || version == VERSION_WITHOUT_BLOCK_COMPRESSION_ALGORITHM
+        {
+            cursor -= 1;
+            ChecksumAlgorithm::try_from(buf[cursor])?
+        } else {
+            // Old meta was always checksummed with `XxHash64`.
+            ChecksumAlgorithm::XxHash64
+        };
+
         let buf = &mut &buf[..cursor];
-        xxhash64_verify(buf, checksum)?;
+        checksum_algorithm.verify(buf, checksum, "meta")?;
 
         let block_meta_count = buf.get_u32_le() as usize;
         let mut block_metas = Vec::with_capacity(block_meta_count);
@@ -392,10 +634,18 @@ impl SstableMeta {
             for _ in 0..block_meta_count {
                 block_metas.push(BlockMeta::decode_from_v1(buf));
             }
-        } else {
+        } else if version == VERSION
+            || version == VERSION_WITHOUT_SECTIONS
+            || version == VERSION_WITHOUT_COLUMN_STATS
+            || version == VERSION_WITHOUT_EPOCH_RANGE
+        {
             for _ in 0..block_meta_count {
                 block_metas.push(BlockMeta::decode(buf));
             }
+        } else {
+            for _ in 0..block_meta_count {
+                block_metas.push(BlockMeta::decode_without_compression_algorithm(buf));
+            }
         }
 
         let bloom_filter = get_length_prefixed_slice(buf);
@@ -403,13 +653,51 @@ impl SstableMeta {
         let key_count = buf.get_u32_le();
         let smallest_key = get_length_prefixed_slice(buf);
         let largest_key = get_length_prefixed_slice(buf);
+        // Sstables written before `VERSION_WITHOUT_EPOCH_RANGE` don't record an epoch range:
+        // default to the widest possible range so epoch-based pruning never incorrectly skips
+        // them.
+        let (smallest_epoch, largest_epoch) = if version == VERSION
+            || version == VERSION_WITHOUT_SECTIONS
+            || version == VERSION_WITHOUT_COLUMN_STATS
+        {
+            (buf.get_u64_le(), buf.get_u64_le())
+        } else {
+            (0, HummockEpoch::MAX)
+        };
         let tomb_event_count = buf.get_u32_le() as usize;
         let mut monotonic_tombstone_events = Vec::with_capacity(tomb_event_count);
         for _ in 0..tomb_event_count {
             let monotonic_tombstone_event = MonotonicDeleteEvent::decode(buf);
             monotonic_tombstone_events.push(monotonic_tombstone_event);
         }
+        // Sstables written before `VERSION_WITHOUT_SECTIONS` don't have a column-stats section.
+        let block_column_stats = if version == VERSION || version == VERSION_WITHOUT_SECTIONS {
+            let block_count = buf.get_u32_le() as usize;
+            let mut block_column_stats = Vec::with_capacity(block_count);
+            for _ in 0..block_count {
+                let column_count = buf.get_u32_le() as usize;
+                let mut columns = Vec::with_capacity(column_count);
+                for _ in 0..column_count {
+                    let present = buf.get_u8();
+                    columns.push(if present == 1 {
+                        let min = get_length_prefixed_slice(buf);
+                        let max = get_length_prefixed_slice(buf);
+                        Some((min, max))
+                    } else {
+                        None
+                    });
+                }
+                block_column_stats.push(columns);
+            }
+            block_column_stats
+        } else {
+            vec![]
+        };
         let meta_offset = buf.get_u64_le();
+        // Sstables written before `VERSION` have no trailing sections at all.
+        if version == VERSION {
+            Self::skip_unknown_sections(buf)?;
+        }
 
         if !monotonic_tombstone_events.is_empty() {
             warn!(
@@ -429,12 +717,43 @@ impl SstableMeta {
             key_count,
             smallest_key,
             largest_key,
+            smallest_epoch,
+            largest_epoch,
             meta_offset,
             monotonic_tombstone_events,
+            block_column_stats,
             version,
+            checksum_algorithm,
         })
     }
 
+    /// Consumes the tagged section list appended by [`Self::encode_to`] at the end of the
+    /// `VERSION` layout. No section tags are recognized yet, so every section is skipped by its
+    /// declared length; a future version that needs new data should add a tag here instead of
+    /// changing the fixed layout above, so that this decoder keeps working against metas written
+    /// by that version.
+    fn skip_unknown_sections(buf: &mut &[u8]) -> HummockResult<()> {
+        let section_count = buf.get_u32_le() as usize;
+        for _ in 0..section_count {
+            if buf.remaining() < 1 + 4 {
+                return Err(HummockError::decode_error(
+                    "unexpected eof while reading sstable meta section header",
+                ));
+            }
+            let _tag = buf.get_u8();
+            let length = buf.get_u32_le() as usize;
+            if buf.remaining() < length {
+                return Err(HummockError::decode_error(format!(
+                    "sstable meta section of length {} truncated, only {} bytes remain",
+                    length,
+                    buf.remaining()
+                )));
+            }
+            buf.advance(length);
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn encoded_size(&self) -> usize {
         4 // block meta count
@@ -452,17 +771,143 @@ impl SstableMeta {
             + self.smallest_key.len()
             + 4 // key len
             + self.largest_key.len()
+            + 8 // smallest epoch
+            + 8 // largest epoch
+            + 4 // block column stats block count
+            + self
+            .block_column_stats
+            .iter()
+            .map(|columns| {
+                4 // column count
+                    + columns
+                    .iter()
+                    .map(|column| {
+                        1 // present flag
+                            + column
+                            .as_ref()
+                            .map(|(min, max)| 4 + min.len() + 4 + max.len())
+                            .unwrap_or(0)
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>()
             + 8 // footer
+            + 4 // section count
+            + 1 // checksum algorithm
             + 8 // checksum
             + 4 // version
             + 4 // magic
     }
+
+    /// Binary-searches the (deprecated) `monotonic_tombstone_events` for the interval governing
+    /// `user_key`, returning the epoch below which a point read at `user_key` must treat the key
+    /// as deleted, or `HummockEpoch::MAX` if no interval covers it.
+    #[expect(deprecated)]
+    pub fn governing_delete_epoch(&self, user_key: &UserKey<&[u8]>) -> HummockEpoch {
+        // The interval governing `user_key` is the one started by the last event whose point
+        // range is at or before `user_key`, respecting `is_exclude_left_key` on ties.
+        let idx = self
+            .monotonic_tombstone_events
+            .partition_point(|event| Self::point_range_le_user_key(&event.event_key, user_key));
+        if idx == 0 {
+            HummockEpoch::MAX
+        } else {
+            self.monotonic_tombstone_events[idx - 1].new_epoch
+        }
+    }
+
+    /// Binary-searches `monotonic_tombstone_events` for the first event whose key is at or after
+    /// `target`, i.e. what a stateful `SstableDeleteRangeIterator::seek` would position to if this
+    /// codebase still had one (see the note on `MonotonicDeleteEvent` above for why it doesn't).
+    /// Returns the index of that event together with the epoch governing the range it opens, or
+    /// `None` if `target` is past the last event.
+    #[expect(deprecated)]
+    pub fn seek_delete_range_epoch(
+        &self,
+        target: &UserKey<&[u8]>,
+    ) -> Option<(usize, HummockEpoch)> {
+        let idx = self
+            .monotonic_tombstone_events
+            .partition_point(|event| Self::point_range_key_lt_user_key(&event.event_key, target));
+        self.monotonic_tombstone_events
+            .get(idx)
+            .map(|event| (idx, event.new_epoch))
+    }
+
+    /// Finds the block containing byte `offset` within the encoded SST, for diagnostics that only
+    /// have a raw file offset to work with (e.g. a crash dump pointing into the file). Returns
+    /// `None` if `offset` falls in the meta/footer region past the last block.
+    pub fn block_for_offset(&self, offset: u32) -> Option<usize> {
+        let idx = self
+            .block_metas
+            .partition_point(|block_meta| block_meta.offset <= offset);
+        if idx == 0 {
+            return None;
+        }
+        let block_meta = &self.block_metas[idx - 1];
+        if offset < block_meta.offset + block_meta.len {
+            Some(idx - 1)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the backward-compatibility `PointRange` sorts at or before `user_key`, i.e.
+    /// `point_range.left_user_key < user_key`, or they're equal and the point range includes its
+    /// own left key (`!is_exclude_left_key`).
+    fn point_range_le_user_key(
+        point_range: &risingwave_hummock_sdk::key::range_delete_backward_compatibility_serde_struct::PointRange,
+        user_key: &UserKey<&[u8]>,
+    ) -> bool {
+        match point_range
+            .left_user_key
+            .table_id
+            .cmp(&user_key.table_id)
+        {
+            std::cmp::Ordering::Less => true,
+            std::cmp::Ordering::Greater => false,
+            std::cmp::Ordering::Equal => {
+                match point_range
+                    .left_user_key
+                    .table_key
+                    .as_slice()
+                    .cmp(user_key.table_key.as_ref())
+                {
+                    std::cmp::Ordering::Less => true,
+                    std::cmp::Ordering::Greater => false,
+                    std::cmp::Ordering::Equal => !point_range.is_exclude_left_key,
+                }
+            }
+        }
+    }
+
+    /// Whether the backward-compatibility `PointRange`'s key sorts strictly before `user_key`,
+    /// ignoring `is_exclude_left_key` (which only matters for range membership, not ordering).
+    fn point_range_key_lt_user_key(
+        point_range: &risingwave_hummock_sdk::key::range_delete_backward_compatibility_serde_struct::PointRange,
+        user_key: &UserKey<&[u8]>,
+    ) -> bool {
+        match point_range
+            .left_user_key
+            .table_id
+            .cmp(&user_key.table_id)
+        {
+            std::cmp::Ordering::Less => true,
+            std::cmp::Ordering::Greater => false,
+            std::cmp::Ordering::Equal => {
+                point_range.left_user_key.table_key.as_slice() < user_key.table_key.as_ref()
+            }
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct SstableIteratorReadOptions {
     pub cache_policy: CachePolicy,
     pub must_iterated_end_user_key: Option<Bound<UserKey<KeyPayloadType>>>,
+    /// Symmetric counterpart of `must_iterated_end_user_key` for the backward iterator: the
+    /// lower bound a reverse scan must reach before it can be considered exhausted.
+    pub must_iterated_begin_user_key: Option<Bound<UserKey<KeyPayloadType>>>,
     pub max_preload_retry_times: usize,
     pub prefetch_for_large_query: bool,
 }
@@ -472,6 +917,7 @@ impl SstableIteratorReadOptions {
         Self {
             cache_policy: read_options.cache_policy,
             must_iterated_end_user_key: None,
+            must_iterated_begin_user_key: None,
             max_preload_retry_times: 0,
             prefetch_for_large_query: read_options.prefetch_options.for_large_query,
         }
@@ -480,12 +926,14 @@ impl SstableIteratorReadOptions {
 
 #[cfg(test)]
 mod tests {
+    use bytes::Bytes;
+
     use super::*;
-    use crate::hummock::HummockValue;
     use crate::hummock::iterator::test_utils::{
-        default_builder_opt_for_test, iterator_test_key_of,
+        default_builder_opt_for_test, iterator_test_key_of, iterator_test_user_key_of,
     };
     use crate::hummock::test_utils::gen_test_sstable_data;
+    use crate::hummock::{BlockHolder, BlockIterator, HummockValue};
 
     #[test]
     fn test_sstable_meta_enc_dec() {
@@ -509,9 +957,16 @@ mod tests {
             key_count: 123,
             smallest_key: b"0-smallest-key".to_vec(),
             largest_key: b"9-largest-key".to_vec(),
+            smallest_epoch: 1,
+            largest_epoch: 9,
             meta_offset: 123,
             monotonic_tombstone_events: vec![],
+            block_column_stats: vec![
+                vec![Some((b"a".to_vec(), b"z".to_vec())), None],
+                vec![Some((b"0".to_vec(), b"9".to_vec()))],
+            ],
             version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::XxHash64,
         };
         let sz = meta.encoded_size();
         let buf = meta.encode_to_bytes();
@@ -522,6 +977,485 @@ mod tests {
         println!("buf: {}", buf.len());
     }
 
+    #[test]
+    fn test_sstable_block_column_stats_enc_dec() {
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            block_metas: vec![BlockMeta {
+                smallest_key: b"0-smallest-key".to_vec(),
+                len: 100,
+                ..Default::default()
+            }],
+            bloom_filter: vec![],
+            estimated_size: 123,
+            key_count: 123,
+            smallest_key: b"0-smallest-key".to_vec(),
+            largest_key: b"9-largest-key".to_vec(),
+            smallest_epoch: 1,
+            largest_epoch: 9,
+            meta_offset: 123,
+            monotonic_tombstone_events: vec![],
+            block_column_stats: vec![vec![
+                Some((b"a".to_vec(), b"z".to_vec())),
+                None,
+            ]],
+            version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::XxHash64,
+        };
+        let buf = meta.encode_to_bytes();
+        let decoded_meta = SstableMeta::decode(&buf[..]).unwrap();
+        let sstable = Sstable::new(0.into(), decoded_meta);
+
+        assert_eq!(
+            sstable.block_column_range(0, 0),
+            Some((b"a".as_slice(), b"z".as_slice()))
+        );
+        assert_eq!(sstable.block_column_range(0, 1), None);
+        assert_eq!(sstable.block_column_range(0, 2), None);
+        assert_eq!(sstable.block_column_range(1, 0), None);
+
+        // An sstable written before `VERSION_WITHOUT_SECTIONS` has no column-stats section and
+        // decodes with an empty one, rather than failing. `encode_to` always writes the `VERSION`
+        // layout, so the `VERSION_WITHOUT_COLUMN_STATS` layout is built from it: drop the
+        // column-stats block count (0 here) before `meta_offset` and the section count after it,
+        // then re-checksum and stamp the old version.
+        #[expect(deprecated)]
+        let old_meta = SstableMeta {
+            block_column_stats: vec![],
+            ..meta
+        };
+        let buf = old_meta.encode_to_bytes();
+        let footer_len = 1 /* checksum algorithm */ + 8 /* checksum */ + 4 /* version */ + 4 /* magic */;
+        let section_count_offset = buf.len() - footer_len - 4;
+        let meta_offset_offset = section_count_offset - 8;
+        let mut old_buf = buf[..meta_offset_offset - 4].to_vec();
+        old_buf.extend_from_slice(&buf[meta_offset_offset..section_count_offset]);
+        let checksum = old_meta.checksum_algorithm.checksum(&old_buf);
+        old_buf.put_u8(old_meta.checksum_algorithm.into());
+        old_buf.put_u64_le(checksum);
+        old_buf.put_u32_le(VERSION_WITHOUT_COLUMN_STATS);
+        old_buf.put_u32_le(MAGIC);
+        let decoded_old_meta = SstableMeta::decode(&old_buf[..]).unwrap();
+        assert!(decoded_old_meta.block_column_stats.is_empty());
+    }
+
+    #[test]
+    fn test_sstable_meta_decode_checksum_mismatch_names_meta_region() {
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            block_metas: vec![BlockMeta {
+                smallest_key: b"0-smallest-key".to_vec(),
+                len: 100,
+                ..Default::default()
+            }],
+            bloom_filter: b"0123456789".to_vec(),
+            estimated_size: 123,
+            key_count: 123,
+            smallest_key: b"0-smallest-key".to_vec(),
+            largest_key: b"9-largest-key".to_vec(),
+            smallest_epoch: 1,
+            largest_epoch: 9,
+            meta_offset: 123,
+            monotonic_tombstone_events: vec![],
+            block_column_stats: vec![],
+            version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::XxHash64,
+        };
+        let mut buf = meta.encode_to_bytes();
+        // Flip a byte within the checksummed meta body, leaving the trailing
+        // checksum/version/magic footer untouched.
+        buf[0] ^= 0xff;
+        let err = SstableMeta::decode(&buf[..]).unwrap_err();
+        assert!(err.to_string().contains("meta"));
+    }
+
+    #[test]
+    fn test_sstable_meta_enc_dec_crc32c() {
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            block_metas: vec![BlockMeta {
+                smallest_key: b"0-smallest-key".to_vec(),
+                len: 100,
+                ..Default::default()
+            }],
+            bloom_filter: b"0123456789".to_vec(),
+            estimated_size: 123,
+            key_count: 123,
+            smallest_key: b"0-smallest-key".to_vec(),
+            largest_key: b"9-largest-key".to_vec(),
+            smallest_epoch: 1,
+            largest_epoch: 9,
+            meta_offset: 123,
+            monotonic_tombstone_events: vec![],
+            block_column_stats: vec![],
+            version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::Crc32C,
+        };
+        let buf = meta.encode_to_bytes();
+        let decoded_meta = SstableMeta::decode(&buf[..]).unwrap();
+        assert_eq!(decoded_meta, meta);
+    }
+
+    #[test]
+    fn test_block_meta_mixed_compression_algorithm() {
+        fn build_block(algorithm: CompressionAlgorithm) -> (Vec<u8>, usize) {
+            let options = BlockBuilderOptions {
+                compression_algorithm: algorithm,
+                ..Default::default()
+            };
+            let mut builder = BlockBuilder::new(options);
+            builder.add_for_test(iterator_test_key_of(0).to_ref(), b"v0");
+            let capacity = builder.uncompressed_block_size();
+            (builder.build().to_vec(), capacity)
+        }
+
+        let (block0, capacity0) = build_block(CompressionAlgorithm::None);
+        let (block1, capacity1) = build_block(CompressionAlgorithm::Zstd);
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&block0);
+        let block1_offset = data.len() as u32;
+        data.extend_from_slice(&block1);
+
+        let block_metas = vec![
+            BlockMeta {
+                smallest_key: b"k0".to_vec(),
+                offset: 0,
+                len: block0.len() as u32,
+                uncompressed_size: capacity0 as u32,
+                compression_algorithm: CompressionAlgorithm::None,
+                ..Default::default()
+            },
+            BlockMeta {
+                smallest_key: b"k1".to_vec(),
+                offset: block1_offset,
+                len: block1.len() as u32,
+                uncompressed_size: capacity1 as u32,
+                compression_algorithm: CompressionAlgorithm::Zstd,
+                ..Default::default()
+            },
+        ];
+
+        // The compression algorithm recorded in each block's meta survives an encode/decode
+        // round trip, and matches what was actually used to build that block.
+        for block_meta in &block_metas {
+            let mut buf = vec![];
+            block_meta.encode(&mut buf);
+            let decoded = BlockMeta::decode(&mut &buf[..]);
+            assert_eq!(decoded, *block_meta);
+        }
+
+        // Each block is self-describing on disk, so it decodes correctly using only the
+        // bytes and the uncompressed size recorded in its own `BlockMeta`, regardless of the
+        // fact that neighbouring blocks in the same sstable used a different algorithm.
+        for block_meta in &block_metas {
+            let raw =
+                &data[block_meta.offset as usize..(block_meta.offset + block_meta.len) as usize];
+            let block = Block::decode(
+                Bytes::copy_from_slice(raw),
+                block_meta.uncompressed_size as usize,
+            )
+            .unwrap();
+            let mut bi = BlockIterator::new(BlockHolder::from_owned_block(Box::new(block)));
+            bi.seek_to_first();
+            assert!(bi.is_valid());
+            assert_eq!(iterator_test_key_of(0).to_ref(), bi.key());
+            assert_eq!(b"v0", bi.value());
+        }
+    }
+
+    #[test]
+    fn test_block_index_for_key() {
+        use risingwave_hummock_sdk::key::TableKey;
+
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            block_metas: (0..5)
+                .map(|idx| BlockMeta {
+                    smallest_key: iterator_test_key_of(idx * 10).encode(),
+                    ..Default::default()
+                })
+                .collect(),
+            bloom_filter: vec![],
+            estimated_size: 0,
+            key_count: 0,
+            smallest_key: iterator_test_key_of(0).encode(),
+            largest_key: iterator_test_key_of(40).encode(),
+            smallest_epoch: 0,
+            largest_epoch: HummockEpoch::MAX,
+            meta_offset: 0,
+            monotonic_tombstone_events: vec![],
+            block_column_stats: vec![],
+            version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::XxHash64,
+        };
+        let sstable = Sstable::new(0.into(), meta);
+
+        // A key before the first block's smallest key falls back to block 0. An empty table key
+        // sorts before every key produced by `iterator_test_user_key_of`.
+        let before_first = UserKey {
+            table_id: iterator_test_user_key_of(0).table_id,
+            table_key: TableKey(&[] as &[u8]),
+        };
+        assert_eq!(sstable.block_index_for_key(&before_first), 0);
+
+        // Exactly on a block's smallest key: that block.
+        for (block_idx, key_idx) in (0..5).map(|i| (i, i * 10)) {
+            let user_key = iterator_test_user_key_of(key_idx);
+            assert_eq!(
+                sstable.block_index_for_key(&user_key.as_ref()),
+                block_idx,
+                "key {} should land in block {}",
+                key_idx,
+                block_idx
+            );
+        }
+
+        // Interior of a block's range: still that block.
+        let interior = iterator_test_user_key_of(25);
+        assert_eq!(sstable.block_index_for_key(&interior.as_ref()), 2);
+
+        // A key after the last block's smallest key returns the last block.
+        let after_last = iterator_test_user_key_of(999);
+        assert_eq!(
+            sstable.block_index_for_key(&after_last.as_ref()),
+            sstable.block_count() - 1
+        );
+    }
+
+    #[test]
+    fn test_blocks_in_range() {
+        use risingwave_hummock_sdk::key::TableKey;
+
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            block_metas: (0..6)
+                .map(|idx| BlockMeta {
+                    smallest_key: iterator_test_key_of(idx * 10).encode(),
+                    ..Default::default()
+                })
+                .collect(),
+            bloom_filter: vec![],
+            estimated_size: 0,
+            key_count: 0,
+            smallest_key: iterator_test_key_of(0).encode(),
+            largest_key: iterator_test_key_of(50).encode(),
+            smallest_epoch: 0,
+            largest_epoch: HummockEpoch::MAX,
+            meta_offset: 0,
+            monotonic_tombstone_events: vec![],
+            block_column_stats: vec![],
+            version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::XxHash64,
+        };
+        let sstable = Sstable::new(0.into(), meta);
+
+        // An interior range spanning the smallest keys of blocks 2..=3 overlaps blocks 2 and 3.
+        let lo = iterator_test_user_key_of(20);
+        let hi = iterator_test_user_key_of(35);
+        let interior_range = (
+            Bound::Included(lo.as_ref()),
+            Bound::Included(hi.as_ref()),
+        );
+        assert_eq!(sstable.blocks_in_range(&interior_range), 2..4);
+
+        // A range entirely before the first block's smallest key: an empty span, since there is
+        // nothing before the table's start to prefetch. An empty table key sorts before every
+        // key produced by `iterator_test_user_key_of`.
+        let before_first = UserKey {
+            table_id: iterator_test_user_key_of(0).table_id,
+            table_key: TableKey(&[] as &[u8]),
+        };
+        let empty_range = (Bound::Unbounded, Bound::Excluded(before_first));
+        assert_eq!(sstable.blocks_in_range(&empty_range), 0..0);
+
+        // An unbounded range covers every block.
+        let full_range = (Bound::Unbounded, Bound::Unbounded);
+        assert_eq!(
+            sstable.blocks_in_range(&full_range),
+            0..sstable.block_count()
+        );
+    }
+
+    #[test]
+    fn test_governing_delete_epoch() {
+        use risingwave_common::catalog::TableId;
+        use risingwave_hummock_sdk::key::TableKey;
+        use risingwave_hummock_sdk::key::range_delete_backward_compatibility_serde_struct::{
+            PointRange, TableKey as BwcTableKey, UserKey as BwcUserKey,
+        };
+
+        fn event(
+            key: &[u8],
+            is_exclude_left_key: bool,
+            new_epoch: HummockEpoch,
+        ) -> MonotonicDeleteEvent {
+            MonotonicDeleteEvent {
+                event_key: PointRange {
+                    left_user_key: BwcUserKey {
+                        table_id: TableId::new(0),
+                        table_key: BwcTableKey::new(key.to_vec()),
+                    },
+                    is_exclude_left_key,
+                },
+                new_epoch,
+            }
+        }
+
+        fn user_key(key: &[u8]) -> UserKey<&[u8]> {
+            UserKey {
+                table_id: TableId::new(0),
+                table_key: TableKey(key),
+            }
+        }
+
+        // Three delete-range intervals: [b, d) at epoch1, [d, f) at epoch2, [f, h) at epoch3.
+        // Encoded, per the doc comment on `MonotonicDeleteEvent`, as:
+        // <b, epoch1>, <d, epoch2>, <f, epoch3>, <h, MAX>.
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            block_metas: vec![],
+            bloom_filter: vec![],
+            estimated_size: 0,
+            key_count: 0,
+            smallest_key: vec![],
+            largest_key: vec![],
+            smallest_epoch: 0,
+            largest_epoch: HummockEpoch::MAX,
+            meta_offset: 0,
+            monotonic_tombstone_events: vec![
+                event(b"b", false, 1),
+                event(b"d", false, 2),
+                event(b"f", false, 3),
+                event(b"h", false, HummockEpoch::MAX),
+            ],
+            version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::XxHash64,
+        };
+
+        // Before the first interval: nothing is deleted.
+        assert_eq!(meta.governing_delete_epoch(&user_key(b"a")), HummockEpoch::MAX);
+        // Interiors of each interval.
+        assert_eq!(meta.governing_delete_epoch(&user_key(b"c")), 1);
+        assert_eq!(meta.governing_delete_epoch(&user_key(b"e")), 2);
+        assert_eq!(meta.governing_delete_epoch(&user_key(b"g")), 3);
+        // Exactly on boundary keys: the boundary belongs to the interval it opens.
+        assert_eq!(meta.governing_delete_epoch(&user_key(b"b")), 1);
+        assert_eq!(meta.governing_delete_epoch(&user_key(b"d")), 2);
+        assert_eq!(meta.governing_delete_epoch(&user_key(b"f")), 3);
+        // At and beyond the terminal event: no interval covers it.
+        assert_eq!(
+            meta.governing_delete_epoch(&user_key(b"h")),
+            HummockEpoch::MAX
+        );
+        assert_eq!(
+            meta.governing_delete_epoch(&user_key(b"z")),
+            HummockEpoch::MAX
+        );
+    }
+
+    #[test]
+    fn test_seek_delete_range_epoch() {
+        use risingwave_common::catalog::TableId;
+        use risingwave_hummock_sdk::key::TableKey;
+        use risingwave_hummock_sdk::key::range_delete_backward_compatibility_serde_struct::{
+            PointRange, TableKey as BwcTableKey, UserKey as BwcUserKey,
+        };
+
+        fn event(
+            key: &[u8],
+            is_exclude_left_key: bool,
+            new_epoch: HummockEpoch,
+        ) -> MonotonicDeleteEvent {
+            MonotonicDeleteEvent {
+                event_key: PointRange {
+                    left_user_key: BwcUserKey {
+                        table_id: TableId::new(0),
+                        table_key: BwcTableKey::new(key.to_vec()),
+                    },
+                    is_exclude_left_key,
+                },
+                new_epoch,
+            }
+        }
+
+        fn user_key(key: &[u8]) -> UserKey<&[u8]> {
+            UserKey {
+                table_id: TableId::new(0),
+                table_key: TableKey(key),
+            }
+        }
+
+        // Same three delete-range intervals as `test_governing_delete_epoch`:
+        // [b, d) at epoch1, [d, f) at epoch2, [f, h) at epoch3.
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            monotonic_tombstone_events: vec![
+                event(b"b", false, 1),
+                event(b"d", false, 2),
+                event(b"f", false, 3),
+                event(b"h", false, HummockEpoch::MAX),
+            ],
+            ..Default::default()
+        };
+
+        // Seeking into the middle of a range lands on the event that opened it.
+        assert_eq!(meta.seek_delete_range_epoch(&user_key(b"c")), Some((0, 1)));
+        assert_eq!(meta.seek_delete_range_epoch(&user_key(b"e")), Some((1, 2)));
+        // Seeking before the first event also lands on it.
+        assert_eq!(meta.seek_delete_range_epoch(&user_key(b"a")), Some((0, 1)));
+        // Seeking exactly onto a boundary lands on the event at that key, not the one before it.
+        assert_eq!(meta.seek_delete_range_epoch(&user_key(b"d")), Some((1, 2)));
+        assert_eq!(meta.seek_delete_range_epoch(&user_key(b"f")), Some((2, 3)));
+        assert_eq!(
+            meta.seek_delete_range_epoch(&user_key(b"h")),
+            Some((3, HummockEpoch::MAX))
+        );
+        // Seeking past the end finds nothing to position on.
+        assert_eq!(meta.seek_delete_range_epoch(&user_key(b"z")), None);
+    }
+
+    #[test]
+    fn test_block_for_offset() {
+        // Three blocks at known, non-contiguous-looking offsets (gaps would indicate padding,
+        // which doesn't happen in practice, but the search shouldn't assume contiguity).
+        let meta = SstableMeta {
+            block_metas: vec![
+                BlockMeta {
+                    offset: 0,
+                    len: 100,
+                    ..Default::default()
+                },
+                BlockMeta {
+                    offset: 100,
+                    len: 50,
+                    ..Default::default()
+                },
+                BlockMeta {
+                    offset: 150,
+                    len: 200,
+                    ..Default::default()
+                },
+            ],
+            meta_offset: 350,
+            ..Default::default()
+        };
+
+        // Interior offsets of each block.
+        assert_eq!(meta.block_for_offset(0), Some(0));
+        assert_eq!(meta.block_for_offset(50), Some(0));
+        assert_eq!(meta.block_for_offset(120), Some(1));
+        assert_eq!(meta.block_for_offset(300), Some(2));
+
+        // Exactly on a block boundary: the offset belongs to the block it opens.
+        assert_eq!(meta.block_for_offset(100), Some(1));
+        assert_eq!(meta.block_for_offset(150), Some(2));
+
+        // Past the last block, into the meta/footer region: not covered by any block.
+        assert_eq!(meta.block_for_offset(350), None);
+        assert_eq!(meta.block_for_offset(1000), None);
+    }
+
     #[tokio::test]
     async fn test_sstable_serde() {
         let (_, meta) = gen_test_sstable_data(
@@ -543,4 +1477,102 @@ mod tests {
 
         println!("{} vs {}", buffer.len(), meta.encoded_size());
     }
+
+    #[test]
+    fn test_sstable_meta_skips_unknown_trailing_section() {
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            block_metas: vec![BlockMeta {
+                smallest_key: b"0-smallest-key".to_vec(),
+                len: 100,
+                ..Default::default()
+            }],
+            bloom_filter: b"0123456789".to_vec(),
+            estimated_size: 123,
+            key_count: 123,
+            smallest_key: b"0-smallest-key".to_vec(),
+            largest_key: b"9-largest-key".to_vec(),
+            smallest_epoch: 1,
+            largest_epoch: 9,
+            meta_offset: 123,
+            monotonic_tombstone_events: vec![],
+            block_column_stats: vec![],
+            version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::XxHash64,
+        };
+        let buf = meta.encode_to_bytes();
+
+        // `encode_to` always writes a section count of 0 right after `meta_offset`. Splice in one
+        // unknown section there, ahead of the checksum/version/magic footer that `encode_to`
+        // appended, and fix up the section count and checksum to match.
+        let footer_len = 1 /* checksum algorithm */ + 8 /* checksum */ + 4 /* version */ + 4 /* magic */;
+        let section_count_offset = buf.len() - footer_len - 4;
+        assert_eq!((&buf[section_count_offset..]).get_u32_le(), 0);
+
+        let mut extra_section = vec![];
+        extra_section.put_u8(99); // an unrecognized tag
+        put_length_prefixed_slice(&mut extra_section, b"from a future version");
+
+        let mut patched = buf[..section_count_offset].to_vec();
+        patched.put_u32_le(1); // section count
+        patched.extend_from_slice(&extra_section);
+        let checksummed_end = patched.len();
+        let checksum = meta
+            .checksum_algorithm
+            .checksum(&patched[..checksummed_end]);
+        patched.put_u8(meta.checksum_algorithm.into());
+        patched.put_u64_le(checksum);
+        patched.put_u32_le(VERSION);
+        patched.put_u32_le(MAGIC);
+
+        let decoded = SstableMeta::decode(&patched).unwrap();
+        assert_eq!(decoded.block_metas, meta.block_metas);
+        assert_eq!(decoded.meta_offset, meta.meta_offset);
+    }
+
+    #[test]
+    fn test_sstable_meta_truncated_section_errors() {
+        #[expect(deprecated)]
+        let meta = SstableMeta {
+            block_metas: vec![BlockMeta {
+                smallest_key: b"0-smallest-key".to_vec(),
+                len: 100,
+                ..Default::default()
+            }],
+            bloom_filter: b"0123456789".to_vec(),
+            estimated_size: 123,
+            key_count: 123,
+            smallest_key: b"0-smallest-key".to_vec(),
+            largest_key: b"9-largest-key".to_vec(),
+            smallest_epoch: 1,
+            largest_epoch: 9,
+            meta_offset: 123,
+            monotonic_tombstone_events: vec![],
+            block_column_stats: vec![],
+            version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::XxHash64,
+        };
+        let buf = meta.encode_to_bytes();
+
+        let footer_len = 1 /* checksum algorithm */ + 8 /* checksum */ + 4 /* version */ + 4 /* magic */;
+        let section_count_offset = buf.len() - footer_len - 4;
+
+        // Claim a section of length 100 but only provide a handful of bytes for it, then
+        // recompute the checksum and footer so the corruption is only in the section body.
+        let mut patched = buf[..section_count_offset].to_vec();
+        patched.put_u32_le(1); // section count
+        patched.put_u8(99); // an unrecognized tag
+        patched.put_u32_le(100); // claimed length
+        patched.extend_from_slice(b"too short");
+        let checksummed_end = patched.len();
+        let checksum = meta
+            .checksum_algorithm
+            .checksum(&patched[..checksummed_end]);
+        patched.put_u8(meta.checksum_algorithm.into());
+        patched.put_u64_le(checksum);
+        patched.put_u32_le(VERSION);
+        patched.put_u32_le(MAGIC);
+
+        assert!(SstableMeta::decode(&patched).is_err());
+    }
 }
@@ -159,6 +159,14 @@ where
         self.add_full_key(full_key, value, is_new_user_key).await
     }
 
+    /// Appends a block copied verbatim (including its filter data) from a single input SST,
+    /// skipping re-encoding and filter rebuild for that block.
+    ///
+    /// This is the carry-forward path for key ranges that don't need merging: see
+    /// [`crate::hummock::compactor::fast_compactor_runner::CompactorRunner::shall_copy_raw_block`],
+    /// which already falls back to the normal per-key [`Self::add_full_key`] path (rebuilding the
+    /// filter) whenever a watermark, compaction filter, or tombstone could drop the block's keys,
+    /// so a TTL-expired key can never leak into a carried-forward filter.
     pub async fn add_raw_block(
         &mut self,
         buf: Bytes,
@@ -18,6 +18,7 @@ use std::fmt::Display;
 use std::ptr;
 
 use risingwave_hummock_sdk::key::MAX_KEY_LEN;
+use serde::{Deserialize, Serialize};
 use xxhash_rust::xxh64;
 
 use super::{HummockError, HummockResult};
@@ -60,14 +61,88 @@ pub fn xxhash64_checksum(data: &[u8]) -> u64 {
 }
 
 /// Verifies the checksum of the data equals the given checksum with xxhash64.
-pub fn xxhash64_verify(data: &[u8], checksum: u64) -> HummockResult<()> {
+///
+/// `region` names the part of the sstable being verified (e.g. `"meta"` or `"block 3"`) and is
+/// surfaced in the resulting [`HummockError`] to help operators locate corruption.
+pub fn xxhash64_verify(data: &[u8], checksum: u64, region: impl Display) -> HummockResult<()> {
     let data_checksum = xxhash64_checksum(data);
     if data_checksum != checksum {
-        return Err(HummockError::checksum_mismatch(checksum, data_checksum));
+        return Err(HummockError::checksum_mismatch(
+            checksum,
+            data_checksum,
+            region.to_string(),
+        ));
     }
     Ok(())
 }
 
+/// Calculates the CRC32C (Castagnoli) checksum of the given data.
+pub fn crc32c_checksum(data: &[u8]) -> u64 {
+    crc32c::crc32c(data) as u64
+}
+
+/// Verifies the checksum of the data equals the given checksum with CRC32C.
+///
+/// See [`xxhash64_verify`] for the meaning of `region`.
+pub fn crc32c_verify(data: &[u8], checksum: u64, region: impl Display) -> HummockResult<()> {
+    let data_checksum = crc32c_checksum(data);
+    if data_checksum != checksum {
+        return Err(HummockError::checksum_mismatch(
+            checksum,
+            data_checksum,
+            region.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Algorithm used to checksum an [`super::SstableMeta`] block. `XxHash64` is the long-standing
+/// default; `Crc32C` is offered as an alternative for callers that prefer to match the checksum
+/// already used elsewhere in their storage stack (e.g. via hardware-accelerated CRC32C).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChecksumAlgorithm {
+    #[default]
+    XxHash64,
+    Crc32C,
+}
+
+impl ChecksumAlgorithm {
+    pub fn checksum(&self, data: &[u8]) -> u64 {
+        match self {
+            Self::XxHash64 => xxhash64_checksum(data),
+            Self::Crc32C => crc32c_checksum(data),
+        }
+    }
+
+    pub fn verify(&self, data: &[u8], checksum: u64, region: impl Display) -> HummockResult<()> {
+        match self {
+            Self::XxHash64 => xxhash64_verify(data, checksum, region),
+            Self::Crc32C => crc32c_verify(data, checksum, region),
+        }
+    }
+}
+
+impl From<ChecksumAlgorithm> for u8 {
+    fn from(ca: ChecksumAlgorithm) -> Self {
+        match ca {
+            ChecksumAlgorithm::XxHash64 => 0,
+            ChecksumAlgorithm::Crc32C => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for ChecksumAlgorithm {
+    type Error = HummockError;
+
+    fn try_from(v: u8) -> core::result::Result<Self, Self::Error> {
+        match v {
+            0 => Ok(Self::XxHash64),
+            1 => Ok(Self::Crc32C),
+            _ => Err(HummockError::decode_error("not valid checksum algorithm")),
+        }
+    }
+}
+
 use bytes::{Buf, BufMut};
 
 pub fn put_length_prefixed_slice(mut buf: impl BufMut, slice: &[u8]) {
@@ -84,8 +159,9 @@ pub fn get_length_prefixed_slice(buf: &mut &[u8]) -> Vec<u8> {
     v
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CompressionAlgorithm {
+    #[default]
     None,
     Lz4,
     Zstd,
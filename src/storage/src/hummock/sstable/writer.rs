@@ -478,7 +478,7 @@ mod tests {
     use rand::{Rng, SeedableRng};
     use risingwave_common::util::iter_util::ZipEqFast;
 
-    use crate::hummock::sstable::VERSION;
+    use crate::hummock::sstable::{ChecksumAlgorithm, VERSION};
     use crate::hummock::{BlockMeta, InMemWriter, SstableMeta, SstableWriter};
 
     fn get_sst() -> (Bytes, Vec<Bytes>, SstableMeta) {
@@ -507,9 +507,13 @@ mod tests {
             key_count: 0,
             smallest_key: Vec::new(),
             largest_key: Vec::new(),
+            smallest_epoch: 0,
+            largest_epoch: u64::MAX,
             meta_offset: data.len() as u64,
             monotonic_tombstone_events: vec![],
+            block_column_stats: vec![],
             version: VERSION,
+            checksum_algorithm: ChecksumAlgorithm::default(),
         };
 
         (data, blocks, meta)
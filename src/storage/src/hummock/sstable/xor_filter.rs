@@ -47,6 +47,16 @@ impl Xor8FilterBuilder {
         };
         Self { key_hash_entries }
     }
+
+    fn build_from_xor8(xor_filter: &Xor8) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(8 + 4 + xor_filter.fingerprints.len() + 1);
+        buf.put_u64_le(xor_filter.seed);
+        buf.put_u32_le(xor_filter.block_length as u32);
+        buf.put_slice(xor_filter.fingerprints.as_ref());
+        // Add footer to tell which kind of filter. 254 indicates a xor8 filter.
+        buf.put_u8(FOOTER_XOR8);
+        buf
+    }
 }
 
 impl Xor16FilterBuilder {
@@ -124,13 +134,7 @@ impl FilterBuilder for Xor8FilterBuilder {
         });
 
         let xor_filter = Xor8::from(&self.key_hash_entries);
-        let mut buf = Vec::with_capacity(8 + 4 + xor_filter.fingerprints.len() + 1);
-        buf.put_u64_le(xor_filter.seed);
-        buf.put_u32_le(xor_filter.block_length as u32);
-        buf.put_slice(xor_filter.fingerprints.as_ref());
-        // Add footer to tell which kind of filter. 254 indicates a xor8 filter.
-        buf.put_u8(FOOTER_XOR8);
-        buf
+        Self::build_from_xor8(&xor_filter)
     }
 
     fn approximate_len(&self) -> usize {
@@ -147,6 +151,100 @@ impl FilterBuilder for Xor8FilterBuilder {
     }
 }
 
+/// Number of unique keys above which [`AdaptiveXorFilterBuilder`] upgrades from an 8-bit xor
+/// filter to a 16-bit one. An 8-bit fingerprint gives a false positive rate around `2^-8`, which
+/// is fine for a small SST but starts costing real extra block reads once an SST holds millions of
+/// keys; 16-bit fingerprints bring that back down to `2^-16` at twice the filter size. This mirrors
+/// the role [`MAX_KV_COUNT_FOR_XOR16`] plays for the plain-vs-blocked 16-bit switch above.
+pub(crate) const DEFAULT_XOR8_TO_XOR16_KV_COUNT_THRESHOLD: usize = 128 * 1024;
+
+/// A [`FilterBuilder`] that starts out collecting key hashes like [`Xor8FilterBuilder`] and
+/// [`Xor16FilterBuilder`], and only decides which of the two to actually build once it knows the
+/// final (deduplicated) key count in [`Self::finish`]. The two encodings share the same footer
+/// byte ([`FOOTER_XOR8`] / [`FOOTER_XOR16`]) that [`XorFilterReader::new`] already dispatches on,
+/// so no reader changes are needed to support this.
+///
+/// Used in place of [`Xor16FilterBuilder`] wherever compaction doesn't need the block-based
+/// filter (see `Compactor::compact_key_range`), with the upgrade threshold sourced from
+/// [`crate::opts::StorageOpts::xor16_kv_count_threshold`] via [`FilterBuilder::create_with_xor16_threshold`].
+pub struct AdaptiveXorFilterBuilder {
+    key_hash_entries: Vec<u64>,
+    xor16_threshold: usize,
+}
+
+impl AdaptiveXorFilterBuilder {
+    pub fn new(capacity: usize) -> Self {
+        Self::with_threshold(capacity, DEFAULT_XOR8_TO_XOR16_KV_COUNT_THRESHOLD)
+    }
+
+    /// Like [`Self::new`], but with an explicit upgrade threshold instead of the default. Production
+    /// callers reach it through [`FilterBuilder::create_with_xor16_threshold`], which passes the
+    /// configured threshold; [`FilterBuilder::create`] uses the default.
+    pub fn with_threshold(capacity: usize, xor16_threshold: usize) -> Self {
+        let key_hash_entries = if capacity > 0 {
+            Vec::with_capacity(capacity)
+        } else {
+            vec![]
+        };
+        Self {
+            key_hash_entries,
+            xor16_threshold,
+        }
+    }
+}
+
+impl FilterBuilder for AdaptiveXorFilterBuilder {
+    fn add_key(&mut self, key: &[u8], table_id: u32) {
+        self.key_hash_entries
+            .push(Sstable::hash_for_bloom_filter(key, table_id));
+    }
+
+    fn finish(&mut self, memory_limiter: Option<Arc<MemoryLimiter>>) -> Vec<u8> {
+        if self.key_hash_entries.is_empty() {
+            return vec![];
+        }
+
+        self.key_hash_entries.sort();
+        self.key_hash_entries.dedup();
+
+        let _memory_tracker = memory_limiter.as_ref().map(|memory_limit| {
+            memory_limit.must_require_memory(self.approximate_building_memory() as u64)
+        });
+
+        if self.key_hash_entries.len() > self.xor16_threshold {
+            let xor_filter = Xor16::from(&self.key_hash_entries);
+            self.key_hash_entries.clear();
+            Xor16FilterBuilder::build_from_xor16(&xor_filter)
+        } else {
+            let xor_filter = Xor8::from(&self.key_hash_entries);
+            self.key_hash_entries.clear();
+            Xor8FilterBuilder::build_from_xor8(&xor_filter)
+        }
+    }
+
+    fn approximate_len(&self) -> usize {
+        self.key_hash_entries.len() * 4
+    }
+
+    fn create(_fpr: f64, capacity: usize) -> Self {
+        AdaptiveXorFilterBuilder::new(capacity)
+    }
+
+    fn create_with_xor16_threshold(
+        _fpr: f64,
+        capacity: usize,
+        xor16_kv_count_threshold: usize,
+    ) -> Self {
+        AdaptiveXorFilterBuilder::with_threshold(capacity, xor16_kv_count_threshold)
+    }
+
+    fn approximate_building_memory(&self) -> usize {
+        // related to https://github.com/ayazhafiz/xorf/blob/master/src/xor16.rs
+        const XOR_MEMORY_PROPORTION: usize = 123;
+        self.key_hash_entries.len() * XOR_MEMORY_PROPORTION
+    }
+}
+
 pub struct BlockedXor16FilterBuilder {
     current: Xor16FilterBuilder,
     data: Vec<u8>,
@@ -387,6 +485,18 @@ impl XorFilterReader {
         }
     }
 
+    /// Number of bits per fingerprint used by the underlying xor filter (8 or 16), or `None` if
+    /// the filter is empty.
+    pub fn fingerprint_bits(&self) -> Option<u32> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(match &self.filter {
+            XorFilter::Xor8(_) => 8,
+            XorFilter::Xor16(_) | XorFilter::BlockXor16(_) => 16,
+        })
+    }
+
     /// Judges whether the hash value is in the table with the given false positive rate.
     ///
     /// Note:
@@ -539,4 +649,40 @@ mod tests {
             panic!();
         }
     }
+
+    #[test]
+    fn test_adaptive_xor_filter_builder() {
+        let threshold = 100;
+        let full_range: UserKeyRangeRef<'_> = (Bound::Unbounded, Bound::Unbounded);
+
+        // Exactly at the threshold: stays an 8-bit filter.
+        let mut builder = AdaptiveXorFilterBuilder::with_threshold(0, threshold);
+        let hashes: Vec<u64> = (0..threshold as u64).collect();
+        for h in &hashes {
+            builder.key_hash_entries.push(*h);
+        }
+        let data = builder.finish(None);
+        let reader = XorFilterReader::new(&data, &[]);
+        assert_eq!(reader.fingerprint_bits(), Some(8));
+        for h in &hashes {
+            assert!(reader.may_match(&full_range, *h));
+        }
+
+        // Just above the threshold: upgrades to a 16-bit filter.
+        let mut builder = AdaptiveXorFilterBuilder::with_threshold(0, threshold);
+        let hashes: Vec<u64> = (0..(threshold as u64 + 1)).collect();
+        for h in &hashes {
+            builder.key_hash_entries.push(*h);
+        }
+        let data = builder.finish(None);
+        let reader = XorFilterReader::new(&data, &[]);
+        assert_eq!(reader.fingerprint_bits(), Some(16));
+        for h in &hashes {
+            assert!(reader.may_match(&full_range, *h));
+        }
+
+        // Empty input writes neither format.
+        let mut builder = AdaptiveXorFilterBuilder::with_threshold(0, threshold);
+        assert!(builder.finish(None).is_empty());
+    }
 }
@@ -426,10 +426,11 @@ impl SstableStore {
                 return Err(ObjectError::internal("read unexpected EOF").into());
             }
             // copy again to avoid holding a large data in memory.
-            let block = Block::decode_with_copy(
+            let block = Block::decode_with_copy_at(
                 buf.slice(offset..end),
                 sst.meta.block_metas[idx].uncompressed_size as usize,
                 true,
+                idx,
             )?;
             let holder = if let CachePolicy::Fill(hint) = policy {
                 let hint = if idx == block_index { hint } else { Hint::Low };
@@ -509,7 +510,7 @@ impl SstableStore {
                     }
                 };
                 let block = Box::new(
-                    Block::decode(block_data, uncompressed_capacity)
+                    Block::decode_at(block_data, uncompressed_capacity, block_index)
                         .map_err(foyer::Error::other)?,
                 );
                 Ok(block)
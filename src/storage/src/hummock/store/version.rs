@@ -1128,6 +1128,7 @@ impl HummockVersionReader {
         let read_options = Arc::new(SstableIteratorReadOptions {
             cache_policy: Default::default(),
             must_iterated_end_user_key: None,
+            must_iterated_begin_user_key: None,
             max_preload_retry_times: 0,
             prefetch_for_large_query: false,
         });
@@ -214,7 +214,7 @@ impl VectorBlock {
         {
             let checksum =
                 u64::from_le_bytes(buf[back_cursor_start..back_cursor_end].try_into().unwrap());
-            xxhash64_verify(payload, checksum)?;
+            xxhash64_verify(payload, checksum, "vector block")?;
         }
         Ok(Self::decode_payload(payload))
     }
@@ -347,7 +347,7 @@ impl VectorFileMeta {
         {
             let checksum =
                 u64::from_le_bytes(buf[back_cursor_start..back_cursor_end].try_into().unwrap());
-            xxhash64_verify(payload, checksum)?;
+            xxhash64_verify(payload, checksum, "footer")?;
         }
 
         Ok(Self::decode(payload))
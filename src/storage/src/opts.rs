@@ -30,6 +30,9 @@ pub struct StorageOpts {
     pub block_size_kb: u32,
     /// False positive probability of bloom filter.
     pub bloom_false_positive: f64,
+    /// Number of unique keys in an SST above which its bloom filter is built as a 16-bit xor
+    /// filter instead of an 8-bit one.
+    pub xor16_kv_count_threshold: usize,
     /// parallelism while syncing share buffers into L0 SST. Should NOT be 0.
     pub share_buffers_sync_parallelism: u32,
     /// Worker threads number of dedicated tokio runtime for share buffer compaction. 0 means use
@@ -211,6 +214,7 @@ impl From<(&RwConfig, &SystemParamsReader, &StorageMemoryConfig)> for StorageOpt
             min_sstable_size_mb: c.storage.min_sstable_size_mb,
             block_size_kb: p.block_size_kb(),
             bloom_false_positive: p.bloom_false_positive(),
+            xor16_kv_count_threshold: c.storage.xor16_kv_count_threshold,
             share_buffers_sync_parallelism: c.storage.share_buffers_sync_parallelism,
             share_buffer_compaction_worker_threads_number: c
                 .storage
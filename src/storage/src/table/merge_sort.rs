@@ -17,7 +17,8 @@ use std::collections::binary_heap::PeekMut;
 use std::error::Error;
 
 use futures::{Stream, StreamExt};
-use futures_async_stream::try_stream;
+use futures_async_stream::{for_await, try_stream};
+use risingwave_hummock_sdk::HummockEpoch;
 
 use super::{KeyedChangeLogRow, KeyedRow};
 
@@ -95,6 +96,51 @@ where
     }
 }
 
+/// Like [`merge_sort`], but when consecutive emitted items share the same user key (as determined
+/// by `key_fn`), only the one with the highest epoch (as determined by `epoch_fn`) is kept and the
+/// rest are dropped. This mirrors LSM read-path semantics, where overlapping snapshots of the same
+/// key are reconciled by epoch.
+#[try_stream(ok=KO, error=E)]
+pub async fn merge_sort_dedup<E, KO, R, K>(
+    streams: impl IntoIterator<Item = R>,
+    key_fn: impl Fn(&KO) -> K,
+    epoch_fn: impl Fn(&KO) -> HummockEpoch,
+)
+where
+    KO: NodePeek + Send + Sync,
+    E: Error,
+    R: Stream<Item = Result<KO, E>> + Unpin,
+    K: Eq,
+{
+    let merged = merge_sort(streams);
+    futures::pin_mut!(merged);
+
+    let mut buffered: Option<(K, HummockEpoch, KO)> = None;
+    #[for_await]
+    for item in merged {
+        let item = item?;
+        let key = key_fn(&item);
+        let epoch = epoch_fn(&item);
+        match &mut buffered {
+            Some((buf_key, buf_epoch, buf_item)) if *buf_key == key => {
+                if epoch > *buf_epoch {
+                    *buf_epoch = epoch;
+                    *buf_item = item;
+                }
+            }
+            _ => {
+                if let Some((_, _, buf_item)) = buffered.take() {
+                    yield buf_item;
+                }
+                buffered = Some((key, epoch, item));
+            }
+        }
+    }
+    if let Some((_, _, buf_item)) = buffered {
+        yield buf_item;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use futures_async_stream::for_await;
@@ -117,6 +163,26 @@ mod tests {
         ))
     }
 
+    /// Builds a `(key, epoch)`-tagged row: the key is fixed to a single vnode so that rows from
+    /// different streams can share the same user key, and the epoch is stashed in the row's sole
+    /// value column so tests can assert which one survived dedup.
+    fn gen_keyed_row_with_epoch(key_byte: u8, epoch: HummockEpoch) -> StorageResult<KeyedRow<Vec<u8>>> {
+        let vnode = VirtualNode::from_index(0);
+        let mut key = vnode.to_be_bytes().to_vec();
+        key.push(key_byte);
+        Ok(KeyedRow::new(
+            TableKey(key),
+            OwnedRow::new(vec![Some(ScalarImpl::Int64(epoch as _))]),
+        ))
+    }
+
+    fn row_epoch(row: &KeyedRow<Vec<u8>>) -> HummockEpoch {
+        match row.row().datum_at(0).unwrap() {
+            risingwave_common::types::ScalarRefImpl::Int64(epoch) => epoch as _,
+            _ => unreachable!(),
+        }
+    }
+
     #[tokio::test]
     async fn test_merge_sort() {
         let streams = vec![
@@ -150,4 +216,42 @@ mod tests {
             assert_eq!(actual.into_owned_row(), expected.into_owned_row());
         }
     }
+
+    #[tokio::test]
+    async fn test_merge_sort_dedup() {
+        // Keys 1 and 2 overlap across streams at different epochs; key 0 and 3 are only in one
+        // stream each and should pass through unchanged.
+        let streams = vec![
+            futures::stream::iter(vec![
+                gen_keyed_row_with_epoch(0, 1),
+                gen_keyed_row_with_epoch(1, 1),
+                gen_keyed_row_with_epoch(2, 3),
+            ]),
+            futures::stream::iter(vec![
+                gen_keyed_row_with_epoch(1, 5),
+                gen_keyed_row_with_epoch(3, 2),
+            ]),
+            futures::stream::iter(vec![gen_keyed_row_with_epoch(2, 2)]),
+        ];
+
+        let deduped = merge_sort_dedup(
+            streams,
+            |row: &KeyedRow<Vec<u8>>| row.key().to_vec(),
+            row_epoch,
+        );
+
+        let mut results = vec![];
+        #[for_await]
+        for result in deduped {
+            results.push(result.unwrap());
+        }
+
+        let expected_epochs = vec![(0u8, 1), (1u8, 5), (2u8, 3), (3u8, 2)];
+        assert_eq!(results.len(), expected_epochs.len());
+        for (row, (key_byte, epoch)) in results.iter().zip(expected_epochs) {
+            let expected_key = gen_keyed_row_with_epoch(key_byte, epoch).unwrap();
+            assert_eq!(row.key(), expected_key.key());
+            assert_eq!(row_epoch(row), epoch);
+        }
+    }
 }
@@ -75,6 +75,51 @@ where
     }
 }
 
+/// Like [`collect_data_chunk`], but also stops as soon as `stop` returns `true` for an appended
+/// row, even if the chunk is not yet full. This avoids over-reading the stream for consumers
+/// (e.g. LIMIT-style queries) that only need to keep pulling rows until some condition is met.
+pub async fn collect_data_chunk_until<E, S, R>(
+    stream: &mut S,
+    schema: &Schema,
+    chunk_size: Option<usize>,
+    stop: impl Fn(&R) -> bool,
+) -> Result<Option<DataChunk>, E>
+where
+    S: Stream<Item = Result<R, E>> + Unpin,
+    R: Row,
+{
+    let mut builders = schema.create_array_builders(chunk_size.unwrap_or(0));
+    let mut row_count = 0;
+    for _ in 0..chunk_size.unwrap_or(usize::MAX) {
+        match stream.next().await.transpose()? {
+            Some(row) => {
+                for (datum, builder) in row.iter().zip_eq_debug(builders.iter_mut()) {
+                    builder.append(datum);
+                }
+                row_count += 1;
+                if stop(&row) {
+                    break;
+                }
+            }
+            None => break,
+        }
+    }
+
+    let chunk = {
+        let columns: Vec<_> = builders
+            .into_iter()
+            .map(|builder| builder.finish().into())
+            .collect();
+        DataChunk::new(columns, row_count)
+    };
+
+    if chunk.cardinality() == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(chunk))
+    }
+}
+
 /// Collects data chunks from stream of rows.
 pub async fn collect_data_chunk_with_builder<E, S, R>(
     stream: &mut S,
@@ -156,3 +201,65 @@ pub fn deserialize_log_stream<'a>(
         log_value.try_map(|slice| Ok(OwnedRow::new(deserializer.deserialize(slice)?)))
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use risingwave_common::catalog::Field;
+    use risingwave_common::types::{DataType, ScalarImpl};
+
+    use super::*;
+
+    #[test]
+    fn test_keyed_row_into_parts() {
+        let table_key = TableKey(b"table_key".to_vec());
+        let row = OwnedRow::new(vec![Some(ScalarImpl::Int32(42))]);
+        let keyed_row = KeyedRow::new(table_key.clone(), row.clone());
+
+        let (parts_key, parts_row) = keyed_row.into_parts();
+        assert_eq!(parts_key, table_key);
+        assert_eq!(parts_row, row);
+    }
+
+    #[tokio::test]
+    async fn test_collect_data_chunk_until() {
+        let schema = Schema::new(vec![Field::unnamed(DataType::Int32)]);
+        let rows: Vec<Result<OwnedRow, String>> = (1..=5)
+            .map(|i| Ok(OwnedRow::new(vec![Some(ScalarImpl::Int32(i))])))
+            .collect();
+        let mut mock_stream = stream::iter(rows);
+
+        // Stop once we've appended the row whose value is 3, i.e. the third row.
+        let chunk = collect_data_chunk_until(&mut mock_stream, &schema, Some(10), |row| {
+            row.datum_at(0) == Some(ScalarImpl::Int32(3).as_scalar_ref_impl())
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(chunk.cardinality(), 3);
+
+        // The remaining rows (4 and 5) are left untouched in the stream.
+        let remaining = collect_data_chunk(&mut mock_stream, &schema, Some(10))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(remaining.cardinality(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_collect_data_chunk_until_stops_on_first_row() {
+        let schema = Schema::new(vec![Field::unnamed(DataType::Int32)]);
+        let rows: Vec<Result<OwnedRow, String>> = (1..=5)
+            .map(|i| Ok(OwnedRow::new(vec![Some(ScalarImpl::Int32(i))])))
+            .collect();
+        let mut mock_stream = stream::iter(rows);
+
+        let chunk = collect_data_chunk_until(&mut mock_stream, &schema, Some(10), |_| true)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(chunk.cardinality(), 1);
+    }
+}
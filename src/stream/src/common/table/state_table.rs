@@ -16,6 +16,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::marker::PhantomData;
 use std::ops::Bound;
 use std::ops::Bound::*;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -41,7 +42,7 @@ use risingwave_common::util::epoch::EpochPair;
 use risingwave_common::util::row_serde::OrderedRowSerde;
 use risingwave_common::util::sort_util::OrderType;
 use risingwave_common::util::value_encoding::BasicSerde;
-use risingwave_hummock_sdk::HummockReadEpoch;
+use risingwave_hummock_sdk::{HummockEpoch, HummockReadEpoch};
 use risingwave_hummock_sdk::key::{
     CopyFromSlice, TableKey, end_bound_of_prefix, next_key, prefix_slice_with_vnode,
     prefixed_range_with_vnode, start_bound_of_excluded_prefix,
@@ -905,8 +906,25 @@ where
 {
     /// Get a single row from state table.
     pub async fn get_row(&self, pk: impl Row) -> StreamExecutorResult<Option<OwnedRow>> {
+        self.get_row_with_cache_policy(pk, CachePolicy::Fill(Hint::Normal))
+            .await
+    }
+
+    /// Like [`Self::get_row`], but lets the caller override the block cache priority the read is
+    /// filled with. Point gets used for consistency checks or other latency-sensitive lookups
+    /// should keep using [`Self::get_row`]'s default of [`Hint::Normal`]; this is for callers such
+    /// as backfill that read at a scale where filling the cache with cold data would evict hotter
+    /// operator state.
+    pub async fn get_row_with_cache_policy(
+        &self,
+        pk: impl Row,
+        cache_policy: CachePolicy,
+    ) -> StreamExecutorResult<Option<OwnedRow>> {
         let (serialized_pk, prefix_hint) = self.serialize_pk_and_get_prefix_hint(&pk);
-        let row = self.row_store.get(serialized_pk, prefix_hint).await?;
+        let row = self
+            .row_store
+            .get(serialized_pk, prefix_hint, cache_policy)
+            .await?;
         match row {
             Some(row) => {
                 if IS_REPLICATED {
@@ -928,6 +946,98 @@ where
         self.row_store.exists(serialized_pk, prefix_hint).await
     }
 
+    /// Point-get multiple rows at once. Groups `pks` by vnode and reuses a single
+    /// [`ReadOptions`] template across the batch, instead of re-deriving one from
+    /// `self.row_store.table_option` for every key the way calling [`Self::get_row`] in a loop
+    /// would. Results are aligned to `pks`' input order; duplicate pks are looked up
+    /// independently and each get their own entry (no dedup).
+    pub async fn get_rows_batched(
+        &self,
+        pks: impl IntoIterator<Item = OwnedRow>,
+    ) -> StreamExecutorResult<Vec<Option<OwnedRow>>> {
+        let pks = pks.into_iter().collect_vec();
+
+        let mut by_vnode: HashMap<VirtualNode, Vec<usize>> = HashMap::new();
+        for (idx, pk) in pks.iter().enumerate() {
+            by_vnode
+                .entry(self.compute_vnode_by_pk(pk))
+                .or_default()
+                .push(idx);
+        }
+
+        let read_options_template = ReadOptions {
+            retention_seconds: self.row_store.table_option.retention_seconds,
+            cache_policy: CachePolicy::Fill(Hint::Normal),
+            ..Default::default()
+        };
+
+        let mut results: Vec<Option<OwnedRow>> = vec![None; pks.len()];
+        for indices in by_vnode.into_values() {
+            for idx in indices {
+                let (serialized_pk, prefix_hint) = self.serialize_pk_and_get_prefix_hint(&pks[idx]);
+                let read_options = ReadOptions {
+                    prefix_hint,
+                    ..read_options_template.clone()
+                };
+                let row = self
+                    .row_store
+                    .get_with_read_options(serialized_pk, read_options)
+                    .await?;
+                results[idx] = match row {
+                    Some(row) if IS_REPLICATED => {
+                        Some(row.project(&self.output_indices).into_owned_row())
+                    }
+                    other => other,
+                };
+            }
+        }
+        Ok(results)
+    }
+
+    /// Like [`Self::get_row`], but reads as of a specific committed `epoch` instead of the
+    /// table's latest state, for time-travel debugging reads. Opens a dedicated read snapshot
+    /// pinned at `epoch`, since which version a store reads from is fixed when the snapshot is
+    /// created rather than being a per-read option.
+    ///
+    /// Returns an error if `epoch` is newer than the last epoch committed for this table.
+    pub async fn get_row_at_epoch(
+        &self,
+        pk: impl Row,
+        epoch: HummockEpoch,
+    ) -> StreamExecutorResult<Option<OwnedRow>> {
+        let snapshot = self
+            .store
+            .new_read_snapshot(
+                HummockReadEpoch::TimeTravel(epoch),
+                NewReadSnapshotOptions {
+                    table_id: self.table_id,
+                },
+            )
+            .await?;
+
+        let (serialized_pk, prefix_hint) = self.serialize_pk_and_get_prefix_hint(&pk);
+        let read_options = ReadOptions {
+            prefix_hint,
+            retention_seconds: self.row_store.table_option.retention_seconds,
+            cache_policy: CachePolicy::Fill(Hint::Normal),
+            ..Default::default()
+        };
+        let row_serde = self.row_store.row_serde.clone();
+        let row = snapshot
+            .on_key_value(serialized_pk, read_options, move |_, value| {
+                let row = row_serde.deserialize(value)?;
+                Ok(OwnedRow::new(row))
+            })
+            .await?;
+
+        Ok(match row {
+            Some(row) if IS_REPLICATED => {
+                Some(row.project(&self.output_indices).into_owned_row())
+            }
+            other => other,
+        })
+    }
+
     fn serialize_pk(&self, pk: &impl Row) -> TableKey<Bytes> {
         assert!(pk.len() <= self.pk_indices.len());
         serialize_pk_with_vnode(pk, &self.pk_serde, self.compute_vnode_by_pk(pk))
@@ -955,6 +1065,7 @@ impl<LS: LocalStateStore, SD: ValueRowSerde> StateTableRowStore<LS, SD> {
         &self,
         key_bytes: TableKey<Bytes>,
         prefix_hint: Option<Bytes>,
+        cache_policy: CachePolicy,
     ) -> StreamExecutorResult<Option<OwnedRow>> {
         if let Some(rows) = &self.all_rows {
             let (vnode, key) = key_bytes.split_vnode();
@@ -963,7 +1074,7 @@ impl<LS: LocalStateStore, SD: ValueRowSerde> StateTableRowStore<LS, SD> {
         let read_options = ReadOptions {
             prefix_hint,
             retention_seconds: self.table_option.retention_seconds,
-            cache_policy: CachePolicy::Fill(Hint::Normal),
+            cache_policy,
             ..Default::default()
         };
 
@@ -979,6 +1090,29 @@ impl<LS: LocalStateStore, SD: ValueRowSerde> StateTableRowStore<LS, SD> {
             .map_err(Into::into)
     }
 
+    /// Like [`Self::get`], but takes an already-built [`ReadOptions`] instead of constructing one
+    /// from `self.table_option`. Lets a caller doing many lookups (e.g.
+    /// [`StateTableInner::get_rows_batched`]) build the options once and reuse them.
+    async fn get_with_read_options(
+        &self,
+        key_bytes: TableKey<Bytes>,
+        read_options: ReadOptions,
+    ) -> StreamExecutorResult<Option<OwnedRow>> {
+        if let Some(rows) = &self.all_rows {
+            let (vnode, key) = key_bytes.split_vnode();
+            return Ok(rows.get(&vnode).expect("covered vnode").get(key).cloned());
+        }
+        let row_serde = self.row_serde.clone();
+
+        self.state_store
+            .on_key_value(key_bytes, read_options, move |_, value| {
+                let row = row_serde.deserialize(value)?;
+                Ok(OwnedRow::new(row))
+            })
+            .await
+            .map_err(Into::into)
+    }
+
     async fn exists(
         &self,
         key_bytes: TableKey<Bytes>,
@@ -1220,6 +1354,33 @@ where
         })
     }
 
+    /// Deletes every row whose pk falls in `pk_range` under `vnode` (or, when `vnode` is `None`,
+    /// under every vnode the table owns). This is a convenience helper for executors that want
+    /// to express a range tombstone explicitly instead of point-deleting rows one at a time as
+    /// they are discovered elsewhere; it is implemented as a scan followed by point deletes, since
+    /// the storage layer does not expose an explicit range-tombstone write path.
+    pub async fn delete_range(
+        &mut self,
+        vnode: impl Into<Option<VirtualNode>>,
+        pk_range: &(Bound<impl Row>, Bound<impl Row>),
+    ) -> StreamExecutorResult<()> {
+        let rows = {
+            let stream = self
+                .iter_with_vnode(vnode, pk_range, Default::default())
+                .await?;
+            pin_mut!(stream);
+            let mut rows = Vec::new();
+            while let Some(row) = stream.next().await {
+                rows.push(row?.into_owned_row());
+            }
+            rows
+        };
+        for row in rows {
+            self.delete(row);
+        }
+        Ok(())
+    }
+
     /// Write a record into state table. Must have the same schema with the table.
     pub fn write_record(&mut self, record: Record<impl Row>) {
         match record {
@@ -1555,16 +1716,121 @@ where
         &self,
 
         // Optional vnode that returns an iterator only over the given range under that vnode.
-        // For now, we require this parameter, and will panic. In the future, when `None`, we can
-        // iterate over each vnode that the `StateTableInner` owns.
-        vnode: VirtualNode,
+        // When `None`, iterates over the range under every vnode that the `StateTableInner`
+        // owns, in vnode order.
+        vnode: impl Into<Option<VirtualNode>>,
         pk_range: &(Bound<impl Row>, Bound<impl Row>),
         prefetch_options: PrefetchOptions,
     ) -> StreamExecutorResult<impl RowStream<'_>> {
-        Ok(self
-            .iter_kv_with_pk_range::<()>(pk_range, vnode, prefetch_options)
-            .await?
-            .map_ok(|(_, row)| row))
+        match vnode.into() {
+            Some(vnode) => {
+                let stream = self
+                    .iter_kv_with_pk_range::<()>(
+                        pk_range,
+                        vnode,
+                        prefetch_options,
+                        CachePolicy::Fill(Hint::Normal),
+                    )
+                    .await?
+                    .map_ok(|(_, row)| row);
+                Ok(Box::pin(stream) as Pin<Box<dyn RowStream<'_>>>)
+            }
+            None => {
+                let mut streams = Vec::with_capacity(self.vnodes().count_ones());
+                for vnode in self.vnodes().iter_vnodes() {
+                    let stream = self
+                        .iter_kv_with_pk_range::<()>(
+                            pk_range,
+                            vnode,
+                            prefetch_options,
+                            CachePolicy::Fill(Hint::Normal),
+                        )
+                        .await?
+                        .map_ok(|(_, row)| row);
+                    streams.push(Box::pin(stream) as Pin<Box<dyn RowStream<'_>>>);
+                }
+                Ok(Box::pin(futures::stream::iter(streams).flatten()) as Pin<Box<dyn RowStream<'_>>>)
+            }
+        }
+    }
+
+    /// Like [`Self::iter_with_vnode`], but overrides the table's configured `retention_seconds`
+    /// for this read only when `retention_seconds` is `Some`. `None` keeps the current behavior
+    /// of [`Self::iter_with_vnode`]. Useful to validate that rows below the configured retention
+    /// really were cleaned up, e.g. for diagnostics or backfill validation.
+    pub async fn iter_row_with_retention(
+        &self,
+        vnode: impl Into<Option<VirtualNode>>,
+        pk_range: &(Bound<impl Row>, Bound<impl Row>),
+        prefetch_options: PrefetchOptions,
+        retention_seconds: Option<u32>,
+    ) -> StreamExecutorResult<impl RowStream<'_>> {
+        match vnode.into() {
+            Some(vnode) => {
+                let stream = self
+                    .iter_kv_with_pk_range_and_retention::<()>(
+                        pk_range,
+                        vnode,
+                        prefetch_options,
+                        retention_seconds,
+                        CachePolicy::Fill(Hint::Normal),
+                    )
+                    .await?
+                    .map_ok(|(_, row)| row);
+                Ok(Box::pin(stream) as Pin<Box<dyn RowStream<'_>>>)
+            }
+            None => {
+                let mut streams = Vec::with_capacity(self.vnodes().count_ones());
+                for vnode in self.vnodes().iter_vnodes() {
+                    let stream = self
+                        .iter_kv_with_pk_range_and_retention::<()>(
+                            pk_range,
+                            vnode,
+                            prefetch_options,
+                            retention_seconds,
+                            CachePolicy::Fill(Hint::Normal),
+                        )
+                        .await?
+                        .map_ok(|(_, row)| row);
+                    streams.push(Box::pin(stream) as Pin<Box<dyn RowStream<'_>>>);
+                }
+                Ok(Box::pin(futures::stream::iter(streams).flatten()) as Pin<Box<dyn RowStream<'_>>>)
+            }
+        }
+    }
+
+    /// Returns an approximate row count under the given `vnode`, e.g. for load-aware scheduling.
+    ///
+    /// As a first cut, this only iterates the key stream and never deserializes values into
+    /// rows, to minimize deserialization cost.
+    ///
+    /// Returns an error if `vnode` is not owned by this `StateTableInner`.
+    pub async fn count_rows_in_vnode(&self, vnode: VirtualNode) -> StreamExecutorResult<usize> {
+        if !self.vnodes().is_set(vnode.to_index()) {
+            return Err(anyhow::anyhow!(
+                "vnode {} is not owned by table {}",
+                vnode,
+                self.table_id()
+            )
+            .into());
+        }
+
+        let read_options = ReadOptions {
+            retention_seconds: self.row_store.table_option.retention_seconds,
+            cache_policy: CachePolicy::Fill(Hint::Normal),
+            ..Default::default()
+        };
+        let mut iter = self
+            .row_store
+            .state_store
+            .iter(prefixed_range_with_vnode::<Bytes>(.., vnode), read_options)
+            .await?;
+
+        let mut count = 0;
+        while iter.try_next().await?.is_some() {
+            count += 1;
+        }
+        Ok(count)
     }
 
     pub async fn iter_keyed_row_with_vnode(
@@ -1574,21 +1840,32 @@ where
         prefetch_options: PrefetchOptions,
     ) -> StreamExecutorResult<impl KeyedRowStream<'_>> {
         Ok(self
-            .iter_kv_with_pk_range(pk_range, vnode, prefetch_options)
+            .iter_kv_with_pk_range(
+                pk_range,
+                vnode,
+                prefetch_options,
+                CachePolicy::Fill(Hint::Normal),
+            )
             .await?
             .map_ok(|(key, row)| KeyedRow::new(TableKey(key), row)))
     }
 
+    /// Like [`Self::iter_with_vnode`], but for a replicated table's snapshot scan, projecting the
+    /// output through [`Self::output_indices`]. Callers such as arrangement backfill scan far more
+    /// data than a typical operator read, so this lets them opt into a `cache_policy` that avoids
+    /// evicting hotter operator state, e.g. `CachePolicy::Fill(Hint::Low)`.
     pub async fn iter_with_vnode_and_output_indices(
         &self,
         vnode: VirtualNode,
         pk_range: &(Bound<impl Row>, Bound<impl Row>),
         prefetch_options: PrefetchOptions,
+        cache_policy: CachePolicy,
     ) -> StreamExecutorResult<impl RowStream<'_>> {
         assert!(IS_REPLICATED);
         let stream = self
-            .iter_with_vnode(vnode, pk_range, prefetch_options)
-            .await?;
+            .iter_kv_with_pk_range::<()>(pk_range, vnode, prefetch_options, cache_policy)
+            .await?
+            .map_ok(|(_, row)| row);
         Ok(stream.map(|row| row.map(|row| row.project(&self.output_indices).into_owned_row())))
     }
 }
@@ -1604,6 +1881,8 @@ impl<LS: LocalStateStore, SD: ValueRowSerde> StateTableRowStore<LS, SD> {
         (start, end): (Bound<Bytes>, Bound<Bytes>),
         prefix_hint: Option<Bytes>,
         prefetch_options: PrefetchOptions,
+        retention_seconds_override: Option<u32>,
+        cache_policy: CachePolicy,
     ) -> StreamExecutorResult<impl PkRowStream<'_, K>> {
         if let Some(rows) = &self.all_rows {
             return Ok(futures::future::Either::Left(futures::stream::iter(
@@ -1615,9 +1894,9 @@ impl<LS: LocalStateStore, SD: ValueRowSerde> StateTableRowStore<LS, SD> {
         }
         let read_options = ReadOptions {
             prefix_hint,
-            retention_seconds: self.table_option.retention_seconds,
+            retention_seconds: retention_seconds_override.or(self.table_option.retention_seconds),
             prefetch_options,
-            cache_policy: CachePolicy::Fill(Hint::Normal),
+            cache_policy,
         };
 
         Ok(futures::future::Either::Right(
@@ -1790,7 +2069,14 @@ where
         } else {
             futures::future::Either::Right(
                 self.row_store
-                    .iter_kv(vnode, memcomparable_range, prefix_hint, prefetch_options)
+                    .iter_kv(
+                        vnode,
+                        memcomparable_range,
+                        prefix_hint,
+                        prefetch_options,
+                        None,
+                        CachePolicy::Fill(Hint::Normal),
+                    )
                     .await?,
             )
         })
@@ -1806,12 +2092,40 @@ where
         // iterate over each vnode that the `StateTableInner` owns.
         vnode: VirtualNode,
         prefetch_options: PrefetchOptions,
+        cache_policy: CachePolicy,
+    ) -> StreamExecutorResult<impl PkRowStream<'a, K>> {
+        self.iter_kv_with_pk_range_and_retention(
+            pk_range,
+            vnode,
+            prefetch_options,
+            None,
+            cache_policy,
+        )
+        .await
+    }
+
+    /// Like [`Self::iter_kv_with_pk_range`], but overrides the table's configured
+    /// `retention_seconds` for this read only when `retention_seconds_override` is `Some`.
+    async fn iter_kv_with_pk_range_and_retention<'a, K: CopyFromSlice + FromVnodeBytes>(
+        &'a self,
+        pk_range: &(Bound<impl Row>, Bound<impl Row>),
+        vnode: VirtualNode,
+        prefetch_options: PrefetchOptions,
+        retention_seconds_override: Option<u32>,
+        cache_policy: CachePolicy,
     ) -> StreamExecutorResult<impl PkRowStream<'a, K>> {
         let memcomparable_range = prefix_range_to_memcomparable(&self.pk_serde, pk_range);
 
         // TODO: provide a trace of useful params.
         self.row_store
-            .iter_kv(vnode, memcomparable_range, None, prefetch_options)
+            .iter_kv(
+                vnode,
+                memcomparable_range,
+                None,
+                prefetch_options,
+                retention_seconds_override,
+                cache_policy,
+            )
             .await
     }
 
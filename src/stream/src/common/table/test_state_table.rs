@@ -14,25 +14,30 @@
 
 use std::collections::HashSet;
 use std::ops::Bound::{self, *};
+use std::sync::Arc;
 
+use foyer::Hint;
 use futures::{StreamExt, pin_mut};
 use risingwave_common::array::{Op, StreamChunk};
-use risingwave_common::bitmap::Bitmap;
+use risingwave_common::bitmap::{Bitmap, BitmapBuilder};
 use risingwave_common::catalog::{ColumnDesc, ColumnId, TableId};
+use risingwave_common::hash::VirtualNode;
 use risingwave_common::row::{self, OwnedRow};
 use risingwave_common::types::{DataType, Scalar, ScalarImpl, Timestamptz};
 use risingwave_common::util::epoch::{EpochPair, test_epoch};
 use risingwave_common::util::sort_util::OrderType;
 use risingwave_common::util::value_encoding::BasicSerde;
 use risingwave_hummock_test::test_utils::prepare_hummock_test_env;
-use risingwave_storage::hummock::HummockStorage;
+use risingwave_storage::hummock::{CachePolicy, HummockStorage};
 use risingwave_storage::store::PrefetchOptions;
 use risingwave_storage::table::SINGLETON_VNODE;
 
 use crate::common::table::state_table::{
     ReplicatedStateTable, StateTable, WatermarkCacheStateTable,
 };
-use crate::common::table::test_utils::{gen_pbtable, gen_pbtable_with_value_indices};
+use crate::common::table::test_utils::{
+    gen_pbtable, gen_pbtable_with_dist_key, gen_pbtable_with_value_indices,
+};
 
 #[tokio::test]
 async fn test_state_table_update_insert() {
@@ -1979,7 +1984,12 @@ async fn test_replicated_state_table_replication() {
             .await
             .unwrap();
         let replicated_iter = replicated_state_table
-            .iter_with_vnode_and_output_indices(SINGLETON_VNODE, &range_bounds, Default::default())
+            .iter_with_vnode_and_output_indices(
+                SINGLETON_VNODE,
+                &range_bounds,
+                Default::default(),
+                CachePolicy::Fill(Hint::Normal),
+            )
             .await
             .unwrap();
         pin_mut!(iter);
@@ -2047,7 +2057,12 @@ async fn test_replicated_state_table_replication() {
             std::ops::Bound::Unbounded,
         );
         let replicated_iter = replicated_state_table
-            .iter_with_vnode_and_output_indices(SINGLETON_VNODE, &range_bounds, Default::default())
+            .iter_with_vnode_and_output_indices(
+                SINGLETON_VNODE,
+                &range_bounds,
+                Default::default(),
+                CachePolicy::Fill(Hint::Normal),
+            )
             .await
             .unwrap();
         pin_mut!(iter);
@@ -2078,7 +2093,12 @@ async fn test_replicated_state_table_replication() {
         let range_bounds: (Bound<OwnedRow>, Bound<OwnedRow>) =
             (std::ops::Bound::Unbounded, std::ops::Bound::Unbounded);
         let replicated_iter = replicated_state_table
-            .iter_with_vnode_and_output_indices(SINGLETON_VNODE, &range_bounds, Default::default())
+            .iter_with_vnode_and_output_indices(
+                SINGLETON_VNODE,
+                &range_bounds,
+                Default::default(),
+                CachePolicy::Fill(Hint::Normal),
+            )
             .await
             .unwrap();
         pin_mut!(replicated_iter);
@@ -2226,3 +2246,426 @@ async fn test_non_pk_prefix_watermark_read() {
         assert_eq!(r3, item_3);
     }
 }
+
+#[tokio::test]
+async fn test_state_table_count_rows_in_vnode() {
+    const TEST_TABLE_ID: TableId = TableId { table_id: 233 };
+    let test_env = prepare_hummock_test_env().await;
+
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int32),
+    ];
+    let order_types = vec![OrderType::ascending()];
+    let pk_index = vec![0_usize];
+    let read_prefix_len_hint = 1;
+    let table = gen_pbtable_with_dist_key(
+        TEST_TABLE_ID,
+        column_descs,
+        order_types,
+        pk_index,
+        read_prefix_len_hint,
+        vec![0],
+    );
+
+    test_env.register_table(table.clone()).await;
+
+    // Only own half of the vnodes, so that we can also exercise the unowned-vnode error path.
+    let mut owned_vnodes = BitmapBuilder::zeroed(VirtualNode::COUNT_FOR_TEST);
+    let unowned_vnode = VirtualNode::from_index(VirtualNode::COUNT_FOR_TEST - 1);
+    for i in 0..VirtualNode::COUNT_FOR_TEST - 1 {
+        owned_vnodes.set(i, true);
+    }
+    let owned_vnodes = Arc::new(owned_vnodes.finish());
+
+    let mut state_table = StateTable::from_table_catalog(
+        &table,
+        test_env.storage.clone(),
+        Some(owned_vnodes.clone()),
+    )
+    .await;
+
+    let mut epoch = EpochPair::new_test_epoch(test_epoch(1));
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.init_epoch(epoch).await.unwrap();
+
+    // Insert a bunch of rows and keep track of how many land in each vnode, by the same hashing
+    // the table itself uses for its distribution key.
+    let mut expected_counts = std::collections::HashMap::<VirtualNode, usize>::new();
+    for pk in 0..500_i32 {
+        let row = OwnedRow::new(vec![Some(pk.into()), Some((pk * 2).into())]);
+        let vnode = VirtualNode::compute_row_for_test(&row, &[0]);
+        *expected_counts.entry(vnode).or_insert(0) += 1;
+        state_table.insert(row);
+    }
+
+    epoch.inc_for_test();
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.commit_for_test(epoch).await.unwrap();
+
+    // Pick two distinct vnodes that actually received rows and check their counts.
+    let mut touched_vnodes = expected_counts.keys().copied().collect::<Vec<_>>();
+    assert!(touched_vnodes.len() >= 2, "test data is too small");
+    touched_vnodes.truncate(2);
+    for vnode in touched_vnodes {
+        let count = state_table.count_rows_in_vnode(vnode).await.unwrap();
+        assert_eq!(count, expected_counts[&vnode]);
+    }
+
+    // A vnode not owned by this state table should error out rather than silently
+    // returning an (incorrect) count of 0.
+    assert!(
+        state_table
+            .count_rows_in_vnode(unowned_vnode)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_iter_row_with_retention_override() {
+    const TEST_TABLE_ID: TableId = TableId { table_id: 233 };
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int32),
+    ];
+    let order_types = vec![OrderType::ascending()];
+    let pk_index = vec![0_usize];
+    let read_prefix_len_hint = 1;
+    // The table itself is configured without retention, so `iter_with_vnode` (which always
+    // uses the table's configured retention) keeps seeing the row no matter how far the read
+    // epoch advances.
+    let table = gen_pbtable(
+        TEST_TABLE_ID,
+        column_descs,
+        order_types,
+        pk_index,
+        read_prefix_len_hint,
+    );
+
+    let test_env = prepare_hummock_test_env().await;
+    test_env.register_table(table.clone()).await;
+    let mut state_table =
+        StateTable::from_table_catalog_inconsistent_op(&table, test_env.storage.clone(), None)
+            .await;
+
+    let mut epoch = EpochPair::new_test_epoch(test_epoch(1));
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.init_epoch(epoch).await.unwrap();
+
+    state_table.insert(OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())]));
+
+    epoch.inc_for_test();
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.commit_for_test(epoch).await.unwrap();
+
+    // Advance the read epoch far past the row's write epoch, simulating a long time passing
+    // without ever writing another value.
+    for _ in 0..10 {
+        epoch.inc_for_test();
+        test_env
+            .storage
+            .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+        state_table.commit_for_test(epoch).await.unwrap();
+    }
+
+    let pk_range = (
+        std::ops::Bound::<row::Empty>::Unbounded,
+        std::ops::Bound::<row::Empty>::Unbounded,
+    );
+
+    // Default behavior (no override) still sees the row: the table has no configured
+    // retention, so nothing is ever considered expired.
+    {
+        let iter = state_table
+            .iter_with_vnode(SINGLETON_VNODE, &pk_range, Default::default())
+            .await
+            .unwrap();
+        pin_mut!(iter);
+        assert!(iter.next().await.is_some());
+    }
+
+    // Overriding the retention to zero excludes the row: with a zero-second window the
+    // minimum readable epoch is exactly the current read epoch, and the row's write epoch is
+    // strictly older than that (several commits have happened since).
+    {
+        let iter = state_table
+            .iter_row_with_retention(SINGLETON_VNODE, &pk_range, Default::default(), Some(0))
+            .await
+            .unwrap();
+        pin_mut!(iter);
+        assert!(iter.next().await.is_none());
+    }
+
+    // `None` reproduces the un-overridden behavior.
+    {
+        let iter = state_table
+            .iter_row_with_retention(SINGLETON_VNODE, &pk_range, Default::default(), None)
+            .await
+            .unwrap();
+        pin_mut!(iter);
+        assert!(iter.next().await.is_some());
+    }
+}
+
+#[tokio::test]
+async fn test_get_row_at_epoch() {
+    const TEST_TABLE_ID: TableId = TableId { table_id: 233 };
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int32),
+    ];
+    let order_types = vec![OrderType::ascending()];
+    let pk_index = vec![0_usize];
+    let read_prefix_len_hint = 1;
+    let table = gen_pbtable(
+        TEST_TABLE_ID,
+        column_descs,
+        order_types,
+        pk_index,
+        read_prefix_len_hint,
+    );
+
+    let test_env = prepare_hummock_test_env().await;
+    test_env.register_table(table.clone()).await;
+    let mut state_table =
+        StateTable::from_table_catalog_inconsistent_op(&table, test_env.storage.clone(), None)
+            .await;
+
+    let mut epoch = EpochPair::new_test_epoch(test_epoch(1));
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.init_epoch(epoch).await.unwrap();
+
+    state_table.insert(OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())]));
+    let epoch_1 = epoch.curr;
+
+    epoch.inc_for_test();
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.commit_for_test(epoch).await.unwrap();
+
+    // Update the row's value at a later epoch.
+    state_table.delete(OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())]));
+    state_table.insert(OwnedRow::new(vec![Some(1_i32.into()), Some(20_i32.into())]));
+
+    epoch.inc_for_test();
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.commit_for_test(epoch).await.unwrap();
+
+    let pk = OwnedRow::new(vec![Some(1_i32.into())]);
+
+    // The latest read sees the updated value.
+    let latest = state_table.get_row(&pk).await.unwrap().unwrap();
+    assert_eq!(latest, OwnedRow::new(vec![Some(1_i32.into()), Some(20_i32.into())]));
+
+    // Reading as of the first commit's epoch time-travels back to the original value.
+    let historical = state_table
+        .get_row_at_epoch(&pk, epoch_1)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        historical,
+        OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())])
+    );
+}
+
+#[tokio::test]
+async fn test_get_row_with_cache_policy() {
+    const TEST_TABLE_ID: TableId = TableId { table_id: 233 };
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int32),
+    ];
+    let order_types = vec![OrderType::ascending()];
+    let pk_index = vec![0_usize];
+    let read_prefix_len_hint = 1;
+    let table = gen_pbtable(
+        TEST_TABLE_ID,
+        column_descs,
+        order_types,
+        pk_index,
+        read_prefix_len_hint,
+    );
+
+    let test_env = prepare_hummock_test_env().await;
+    test_env.register_table(table.clone()).await;
+    let mut state_table =
+        StateTable::from_table_catalog_inconsistent_op(&table, test_env.storage.clone(), None)
+            .await;
+
+    let mut epoch = EpochPair::new_test_epoch(test_epoch(1));
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.init_epoch(epoch).await.unwrap();
+
+    state_table.insert(OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())]));
+
+    epoch.inc_for_test();
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.commit_for_test(epoch).await.unwrap();
+
+    let pk = OwnedRow::new(vec![Some(1_i32.into())]);
+    let expected = OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())]);
+
+    // The default `Hint::Normal` priority (used by `get_row`) and an explicit `Hint::Low`
+    // override (as used by large backfill scans) must read the same value; the cache policy
+    // only affects what the block cache does with the fetched block, not what is returned.
+    assert_eq!(state_table.get_row(&pk).await.unwrap().unwrap(), expected);
+    assert_eq!(
+        state_table
+            .get_row_with_cache_policy(&pk, CachePolicy::Fill(Hint::Low))
+            .await
+            .unwrap()
+            .unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_iter_with_vnode_and_output_indices_cache_policy() {
+    type TestReplicatedStateTable = ReplicatedStateTable<HummockStorage, BasicSerde>;
+    const TEST_TABLE_ID: TableId = TableId { table_id: 233 };
+    let order_types = vec![OrderType::ascending()];
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int32),
+    ];
+    let pk_index = vec![0_usize];
+    let read_prefix_len_hint = 1;
+    let table = gen_pbtable(
+        TEST_TABLE_ID,
+        column_descs,
+        order_types,
+        pk_index,
+        read_prefix_len_hint,
+    );
+
+    let test_env = prepare_hummock_test_env().await;
+    test_env.register_table(table.clone()).await;
+
+    let mut state_table =
+        StateTable::from_table_catalog_inconsistent_op(&table, test_env.storage.clone(), None)
+            .await;
+    let output_column_ids = vec![ColumnId::from(1), ColumnId::from(0)];
+    let mut replicated_state_table: TestReplicatedStateTable =
+        TestReplicatedStateTable::new_replicated(
+            &table,
+            test_env.storage.clone(),
+            None,
+            output_column_ids,
+        )
+        .await;
+
+    let mut epoch = EpochPair::new_test_epoch(test_epoch(1));
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.init_epoch(epoch).await.unwrap();
+    state_table.insert(OwnedRow::new(vec![Some(1_i32.into()), Some(11_i32.into())]));
+
+    epoch.inc_for_test();
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.commit_for_test(epoch).await.unwrap();
+    test_env.commit_epoch(epoch.prev).await;
+    replicated_state_table.init_epoch(epoch).await.unwrap();
+
+    let range_bounds: (Bound<row::Empty>, Bound<row::Empty>) =
+        (std::ops::Bound::Unbounded, std::ops::Bound::Unbounded);
+
+    // Backfill's snapshot scan reads with `Hint::Low` so it doesn't evict hotter operator
+    // state; it must still see exactly what a normal-priority read would.
+    let iter = replicated_state_table
+        .iter_with_vnode_and_output_indices(
+            SINGLETON_VNODE,
+            &range_bounds,
+            Default::default(),
+            CachePolicy::Fill(Hint::Low),
+        )
+        .await
+        .unwrap();
+    pin_mut!(iter);
+    let res = iter.next().await.unwrap().unwrap();
+    assert_eq!(
+        &OwnedRow::new(vec![Some(11_i32.into()), Some(1_i32.into())]),
+        res.as_ref()
+    );
+    assert!(iter.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_get_rows_batched() {
+    const TEST_TABLE_ID: TableId = TableId { table_id: 233 };
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int32),
+    ];
+    let order_types = vec![OrderType::ascending()];
+    let pk_index = vec![0_usize];
+    let read_prefix_len_hint = 1;
+    let table = gen_pbtable(
+        TEST_TABLE_ID,
+        column_descs,
+        order_types,
+        pk_index,
+        read_prefix_len_hint,
+    );
+
+    let test_env = prepare_hummock_test_env().await;
+    test_env.register_table(table.clone()).await;
+    let mut state_table =
+        StateTable::from_table_catalog_inconsistent_op(&table, test_env.storage.clone(), None)
+            .await;
+
+    let mut epoch = EpochPair::new_test_epoch(test_epoch(1));
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.init_epoch(epoch).await.unwrap();
+
+    state_table.insert(OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())]));
+    state_table.insert(OwnedRow::new(vec![Some(2_i32.into()), Some(20_i32.into())]));
+
+    epoch.inc_for_test();
+    test_env
+        .storage
+        .start_epoch(epoch.curr, HashSet::from_iter([TEST_TABLE_ID]));
+    state_table.commit_for_test(epoch).await.unwrap();
+
+    // Present (1, twice), absent (3), and present (2) keys, out of order and with a duplicate.
+    let pks = vec![
+        OwnedRow::new(vec![Some(1_i32.into())]),
+        OwnedRow::new(vec![Some(3_i32.into())]),
+        OwnedRow::new(vec![Some(1_i32.into())]),
+        OwnedRow::new(vec![Some(2_i32.into())]),
+    ];
+    let rows = state_table.get_rows_batched(pks).await.unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Some(OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())])),
+            None,
+            Some(OwnedRow::new(vec![Some(1_i32.into()), Some(10_i32.into())])),
+            Some(OwnedRow::new(vec![Some(2_i32.into()), Some(20_i32.into())])),
+        ]
+    );
+}
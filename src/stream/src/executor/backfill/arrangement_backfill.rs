@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use either::Either;
+use foyer::Hint;
 use futures::stream::{select_all, select_with_strategy};
 use futures::{TryStreamExt, stream};
 use itertools::Itertools;
@@ -23,6 +24,7 @@ use risingwave_common::bail;
 use risingwave_common::hash::{VirtualNode, VnodeBitmapExt};
 use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
 use risingwave_common_rate_limit::{MonitoredRateLimiter, RateLimit, RateLimiter};
+use risingwave_storage::hummock::CachePolicy;
 use risingwave_storage::row_serde::value_serde::ValueRowSerde;
 use risingwave_storage::store::PrefetchOptions;
 
@@ -778,6 +780,7 @@ where
                     vnode,
                     &range_bounds,
                     PrefetchOptions::prefetch_for_small_range_scan(),
+                    CachePolicy::Fill(Hint::Low),
                 )
                 .await?;
 
@@ -46,6 +46,10 @@ pub struct DynamicFilterExecutor<S: StateStore, const USE_WATERMARK_CACHE: bool>
     source_r: Option<Executor>,
     key_l: usize,
     comparator: PbExprNodeType,
+    /// The upper bound comparator (`<` or `<=`) of a BETWEEN-style predicate with two dynamic
+    /// bounds, if any. When set, `right_table` has two columns: the lower bound (compared via
+    /// `comparator`) at index 0 and the upper bound (compared via `upper_comparator`) at index 1.
+    upper_comparator: Option<PbExprNodeType>,
     left_table: WatermarkCacheParameterizedStateTable<S, USE_WATERMARK_CACHE>,
     right_table: StateTable<S>,
     metrics: Arc<StreamingMetrics>,
@@ -64,6 +68,7 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
         source_r: Executor,
         key_l: usize,
         comparator: PbExprNodeType,
+        upper_comparator: Option<PbExprNodeType>,
         state_table_l: WatermarkCacheParameterizedStateTable<S, USE_WATERMARK_CACHE>,
         state_table_r: StateTable<S>,
         metrics: Arc<StreamingMetrics>,
@@ -78,6 +83,7 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
             source_r: Some(source_r),
             key_l,
             comparator,
+            upper_comparator,
             left_table: state_table_l,
             right_table: state_table_r,
             metrics,
@@ -90,6 +96,7 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
         &mut self,
         chunk: &StreamChunk,
         filter_condition: Option<NonStrictExpression>,
+        upper_filter_condition: Option<NonStrictExpression>,
         below_watermark_condition: Option<NonStrictExpression>,
     ) -> Result<(Vec<Op>, Bitmap), StreamExecutorError> {
         let mut new_ops = Vec::with_capacity(chunk.capacity());
@@ -102,6 +109,14 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
             None
         };
 
+        // Only present for a BETWEEN-style predicate with two dynamic bounds; `None` here is
+        // treated as "vacuously satisfied" so the single-bound behavior above is unaffected.
+        let upper_filter_results = if let Some(cond) = upper_filter_condition {
+            Some(cond.eval_infallible(chunk).await)
+        } else {
+            None
+        };
+
         let below_watermark = if let Some(cond) = below_watermark_condition {
             Some(cond.eval_infallible(chunk).await)
         } else {
@@ -121,6 +136,16 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                 // A NULL right value implies a false evaluation for all rows
                 false
             };
+            let satisfied_upper_filter_cond = if let Some(array) = &upper_filter_results {
+                if let ArrayImpl::Bool(results) = &**array {
+                    results.value_at(idx).unwrap_or(false)
+                } else {
+                    panic!("dynamic filter condition eval must return bool array")
+                }
+            } else {
+                true
+            };
+            let satisfied_dyn_filter_cond = satisfied_dyn_filter_cond && satisfied_upper_filter_cond;
             let below_watermark = if let Some(array) = &below_watermark {
                 if let ArrayImpl::Bool(results) = &**array {
                     results.value_at(idx).unwrap_or(false)
@@ -261,6 +286,110 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
         bound.map(|s| once(Some(s)))
     }
 
+    /// The set of `key_l` values currently satisfying a BETWEEN-style predicate with dynamic
+    /// `lower_value`/`upper_value` bounds. `None` means the set is empty, which happens whenever
+    /// either bound is currently unknown (no RHS row, or a `NULL` RHS value).
+    fn between_window(
+        &self,
+        lower_value: Datum,
+        upper_value: Datum,
+    ) -> Option<(Bound<ScalarImpl>, Bound<ScalarImpl>)> {
+        let lo = match self.comparator {
+            GreaterThan => Excluded(lower_value?),
+            GreaterThanOrEqual => Included(lower_value?),
+            _ => unreachable!(),
+        };
+        let hi = match self.upper_comparator.unwrap() {
+            LessThan => Excluded(upper_value?),
+            LessThanOrEqual => Included(upper_value?),
+            _ => unreachable!(),
+        };
+        Some((lo, hi))
+    }
+
+    /// Whether `value` falls within `window` (as returned by `between_window`).
+    fn window_contains(
+        window: &Option<(Bound<ScalarImpl>, Bound<ScalarImpl>)>,
+        value: &Datum,
+    ) -> bool {
+        let Some((lo, hi)) = window else {
+            return false;
+        };
+        let Some(v) = value else {
+            return false;
+        };
+        let lo_ok = match lo {
+            Unbounded => true,
+            Included(b) => v.default_cmp(b).is_ge(),
+            Excluded(b) => v.default_cmp(b).is_gt(),
+        };
+        let hi_ok = match hi {
+            Unbounded => true,
+            Included(b) => v.default_cmp(b).is_le(),
+            Excluded(b) => v.default_cmp(b).is_lt(),
+        };
+        lo_ok && hi_ok
+    }
+
+    /// The smallest window containing both `a` and `b`, used to compute a range to scan that is
+    /// guaranteed to cover every row whose membership in the window may have changed.
+    fn union_window(
+        a: Option<(Bound<ScalarImpl>, Bound<ScalarImpl>)>,
+        b: Option<(Bound<ScalarImpl>, Bound<ScalarImpl>)>,
+    ) -> Option<(Bound<ScalarImpl>, Bound<ScalarImpl>)> {
+        match (a, b) {
+            (None, None) => None,
+            (Some(w), None) | (None, Some(w)) => Some(w),
+            (Some((a_lo, a_hi)), Some((b_lo, b_hi))) => {
+                Some((Self::most_permissive_lower(a_lo, b_lo), Self::most_permissive_upper(a_hi, b_hi)))
+            }
+        }
+    }
+
+    fn most_permissive_lower(
+        a: Bound<ScalarImpl>,
+        b: Bound<ScalarImpl>,
+    ) -> Bound<ScalarImpl> {
+        match (a, b) {
+            (Unbounded, _) | (_, Unbounded) => Unbounded,
+            (Included(x), Included(y)) => {
+                Included(if x.default_cmp(&y).is_le() { x } else { y })
+            }
+            (Excluded(x), Excluded(y)) => {
+                Excluded(if x.default_cmp(&y).is_le() { x } else { y })
+            }
+            (Included(x), Excluded(y)) | (Excluded(y), Included(x)) => {
+                if x.default_cmp(&y).is_le() {
+                    Included(x)
+                } else {
+                    Excluded(y)
+                }
+            }
+        }
+    }
+
+    fn most_permissive_upper(
+        a: Bound<ScalarImpl>,
+        b: Bound<ScalarImpl>,
+    ) -> Bound<ScalarImpl> {
+        match (a, b) {
+            (Unbounded, _) | (_, Unbounded) => Unbounded,
+            (Included(x), Included(y)) => {
+                Included(if x.default_cmp(&y).is_ge() { x } else { y })
+            }
+            (Excluded(x), Excluded(y)) => {
+                Excluded(if x.default_cmp(&y).is_ge() { x } else { y })
+            }
+            (Included(x), Excluded(y)) | (Excluded(y), Included(x)) => {
+                if x.default_cmp(&y).is_ge() {
+                    Included(x)
+                } else {
+                    Excluded(y)
+                }
+            }
+        }
+    }
+
     #[try_stream(ok = Message, error = StreamExecutorError)]
     async fn execute_inner(mut self) {
         let input_l = self.source_l.take().unwrap();
@@ -271,6 +400,9 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
         let r_data_type = input_r.schema().data_types()[0].clone();
         // The types are aligned by frontend.
         assert_eq!(l_data_type, r_data_type);
+        if self.upper_comparator.is_some() {
+            assert_eq!(l_data_type, input_r.schema().data_types()[1]);
+        }
 
         let build_cond = {
             let l_data_type = l_data_type.clone();
@@ -311,9 +443,15 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
 
         let recovered_rhs = self.right_table.get_from_one_row_table().await?;
         let recovered_rhs_value = recovered_rhs.as_ref().map(|r| r[0].clone());
+        let recovered_rhs_upper_value = recovered_rhs
+            .as_ref()
+            .filter(|_| self.upper_comparator.is_some())
+            .map(|r| r[1].clone());
         // At the beginning of an epoch, the `committed_rhs_value` == `staging_rhs_value`
         let mut committed_rhs_value: Option<Datum> = recovered_rhs_value.clone();
         let mut staging_rhs_value: Option<Datum> = recovered_rhs_value;
+        let mut committed_rhs_upper_value: Option<Datum> = recovered_rhs_upper_value.clone();
+        let mut staging_rhs_upper_value: Option<Datum> = recovered_rhs_upper_value;
         // This is only required to be some if the row arrived during this epoch.
         let mut committed_rhs_row = recovered_rhs.clone();
         let mut staging_rhs_row = recovered_rhs;
@@ -323,7 +461,11 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
 
         let mut staging_state_watermark = None;
         let mut watermark_to_propagate = None;
-        let can_propagate_watermark = matches!(self.comparator, GreaterThan | GreaterThanOrEqual);
+        // With two dynamic bounds (BETWEEN), a watermark on either right-hand column doesn't by
+        // itself imply a watermark on `key_l`, so conservatively never propagate one (matching
+        // the frontend's `StreamDynamicFilter::derive_watermark_columns`).
+        let can_propagate_watermark = self.upper_comparator.is_none()
+            && matches!(self.comparator, GreaterThan | GreaterThanOrEqual);
 
         #[for_await]
         for msg in aligned_stream {
@@ -338,6 +480,15 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                         build_cond(self.comparator, committed_rhs_value.clone().flatten())
                             .transpose()?;
 
+                    // Only built for a BETWEEN-style predicate with two dynamic bounds.
+                    let upper_filter_condition = self.upper_comparator.map(|upper_comparator| {
+                        build_cond(
+                            upper_comparator,
+                            committed_rhs_upper_value.clone().flatten(),
+                        )
+                    });
+                    let upper_filter_condition = upper_filter_condition.flatten().transpose()?;
+
                     // The condition is `None` if there's no committed state cleaning watermark before.
                     // Note that we should not use `state_cleaning_watermark` variable here, because
                     // it represents outstanding watermark to be applied. Here we need the watermark
@@ -348,7 +499,12 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                             .transpose()?;
 
                     let (new_ops, new_visibility) = self
-                        .apply_batch(&chunk, filter_condition, below_watermark_condition)
+                        .apply_batch(
+                            &chunk,
+                            filter_condition,
+                            upper_filter_condition,
+                            below_watermark_condition,
+                        )
                         .await?;
 
                     let columns = chunk.into_parts().0.into_parts().0;
@@ -368,6 +524,10 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                         match *op {
                             Op::UpdateInsert | Op::Insert => {
                                 staging_rhs_value = Some(row.datum_at(0).to_owned_datum());
+                                if self.upper_comparator.is_some() {
+                                    staging_rhs_upper_value =
+                                        Some(row.datum_at(1).to_owned_datum());
+                                }
                                 staging_rhs_row = Some(row.into_owned_row());
                             }
                             Op::UpdateDelete | Op::Delete => {
@@ -384,6 +544,9 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                                     );
                                 }
                                 staging_rhs_value = None;
+                                if self.upper_comparator.is_some() {
+                                    staging_rhs_upper_value = None;
+                                }
                                 staging_rhs_row = None;
                             }
                         }
@@ -411,38 +574,98 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                     // barrier.
                     let curr: Datum = staging_rhs_value.clone().flatten();
                     let prev: Datum = committed_rhs_value.flatten();
-                    if prev != curr {
-                        let (range, _latest_is_lower, is_insert) = self.get_range(&curr, prev);
-
-                        let range = (Self::to_row_bound(range.0), Self::to_row_bound(range.1));
-                        // TODO: prefetching for append-only case.
-                        let streams = futures::future::try_join_all(
-                            self.left_table.vnodes().iter_vnodes().map(|vnode| {
-                                self.left_table.iter_with_vnode(
-                                    vnode,
-                                    &range,
-                                    PrefetchOptions::prefetch_for_small_range_scan(),
-                                )
-                            }),
-                        )
-                        .await?
-                        .into_iter()
-                        .map(Box::pin);
-
-                        #[for_await]
-                        for res in stream::select_all(streams) {
-                            let row = res?;
-                            if let Some(chunk) = stream_chunk_builder.append_row(
-                                // All rows have a single identity at this point
-                                if is_insert { Op::Insert } else { Op::Delete },
-                                row.as_ref(),
-                            ) {
+                    let curr_upper: Datum = staging_rhs_upper_value.clone().flatten();
+                    let prev_upper: Datum = committed_rhs_upper_value.flatten();
+
+                    if self.upper_comparator.is_none() {
+                        if prev != curr {
+                            let (range, _latest_is_lower, is_insert) =
+                                self.get_range(&curr, prev);
+
+                            let range = (Self::to_row_bound(range.0), Self::to_row_bound(range.1));
+                            // TODO: prefetching for append-only case.
+                            let streams = futures::future::try_join_all(
+                                self.left_table.vnodes().iter_vnodes().map(|vnode| {
+                                    self.left_table.iter_with_vnode(
+                                        vnode,
+                                        &range,
+                                        PrefetchOptions::prefetch_for_small_range_scan(),
+                                    )
+                                }),
+                            )
+                            .await?
+                            .into_iter()
+                            .map(Box::pin);
+
+                            #[for_await]
+                            for res in stream::select_all(streams) {
+                                let row = res?;
+                                if let Some(chunk) = stream_chunk_builder.append_row(
+                                    // All rows have a single identity at this point
+                                    if is_insert { Op::Insert } else { Op::Delete },
+                                    row.as_ref(),
+                                ) {
+                                    yield Message::Chunk(chunk);
+                                }
+                            }
+
+                            if let Some(chunk) = stream_chunk_builder.take() {
                                 yield Message::Chunk(chunk);
                             }
                         }
+                    } else if (&prev, &prev_upper) != (&curr, &curr_upper) {
+                        // BETWEEN with two dynamic bounds: unlike the single-bound case above,
+                        // both endpoints can move independently in the same epoch, so the set of
+                        // rows whose membership changed is not necessarily a single contiguous
+                        // range on either side. Instead, scan a range that is guaranteed to cover
+                        // every row that could have changed membership (the union of the old and
+                        // new windows), and decide insert/delete per row by checking it against
+                        // both windows directly.
+                        let old_window = self.between_window(prev, prev_upper);
+                        let new_window = self.between_window(curr.clone(), curr_upper.clone());
+
+                        if let Some(scan_range) = Self::union_window(old_window, new_window) {
+                            let scan_range = (
+                                Self::to_row_bound(scan_range.0),
+                                Self::to_row_bound(scan_range.1),
+                            );
+                            let streams = futures::future::try_join_all(
+                                self.left_table.vnodes().iter_vnodes().map(|vnode| {
+                                    self.left_table.iter_with_vnode(
+                                        vnode,
+                                        &scan_range,
+                                        PrefetchOptions::prefetch_for_small_range_scan(),
+                                    )
+                                }),
+                            )
+                            .await?
+                            .into_iter()
+                            .map(Box::pin);
+
+                            #[for_await]
+                            for res in stream::select_all(streams) {
+                                let row = res?;
+                                let value = row.datum_at(self.key_l).to_owned_datum();
+                                let in_old = Self::window_contains(&old_window, &value);
+                                let in_new = Self::window_contains(&new_window, &value);
+                                let op = if in_new && !in_old {
+                                    Some(Op::Insert)
+                                } else if in_old && !in_new {
+                                    Some(Op::Delete)
+                                } else {
+                                    None
+                                };
+                                if let Some(op) = op
+                                    && let Some(chunk) =
+                                        stream_chunk_builder.append_row(op, row.as_ref())
+                                {
+                                    yield Message::Chunk(chunk);
+                                }
+                            }
 
-                        if let Some(chunk) = stream_chunk_builder.take() {
-                            yield Message::Chunk(chunk);
+                            if let Some(chunk) = stream_chunk_builder.take() {
+                                yield Message::Chunk(chunk);
+                            }
                         }
                     }
 
@@ -478,6 +701,7 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                     // Update the last committed RHS row and value.
                     committed_rhs_row.clone_from(&staging_rhs_row);
                     committed_rhs_value = Some(curr);
+                    committed_rhs_upper_value = Some(curr_upper);
 
                     let update_vnode_bitmap = barrier.as_update_vnode_bitmap(self.ctx.id);
                     yield Message::Barrier(barrier);
@@ -569,6 +793,7 @@ mod tests {
             source_r,
             0,
             comparator,
+            None,
             mem_state_l,
             mem_state_r,
             Arc::new(StreamingMetrics::unused()),
@@ -1284,4 +1509,153 @@ mod tests {
 
         Ok(())
     }
+
+    async fn create_between_executor(
+        store: MemoryStateStore,
+    ) -> (MessageSender, MessageSender, BoxedMessageStream) {
+        let column_descs = vec![ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64)];
+        let order_types = vec![OrderType::ascending()];
+        let pk_indices = vec![0];
+        let state_table_l = StateTable::from_table_catalog(
+            &gen_pbtable(TableId::new(0), column_descs, order_types, pk_indices, 0),
+            store.clone(),
+            None,
+        )
+        .await;
+        let state_table_r = StateTable::from_table_catalog(
+            &gen_pbtable(
+                TableId::new(1),
+                vec![
+                    ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64),
+                    ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64),
+                ],
+                vec![],
+                vec![],
+                0,
+            ),
+            store,
+            None,
+        )
+        .await;
+
+        let l_schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+        let r_schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let (tx_l, source_l) = MockSource::channel();
+        let source_l = source_l.into_executor(l_schema.clone(), vec![0]);
+        let (tx_r, source_r) = MockSource::channel();
+        let source_r = source_r.into_executor(r_schema, vec![]);
+
+        let ctx = ActorContext::for_test(123);
+        let eval_error_report = ActorEvalErrorReport {
+            actor_context: ctx.clone(),
+            identity: "DynamicFilterExecutor".into(),
+        };
+        let executor = DynamicFilterExecutor::<MemoryStateStore, false>::new(
+            ctx,
+            eval_error_report,
+            l_schema,
+            source_l,
+            source_r,
+            0,
+            PbExprNodeType::GreaterThanOrEqual,
+            Some(PbExprNodeType::LessThanOrEqual),
+            state_table_l,
+            state_table_r,
+            Arc::new(StreamingMetrics::unused()),
+            1024,
+            false,
+        );
+        (tx_l, tx_r, executor.boxed().execute())
+    }
+
+    /// Drives `DynamicFilterExecutor` directly through a two-bound `BETWEEN` predicate as both
+    /// bounds move, exercising `between_window`/`union_window`/`window_contains` end to end
+    /// rather than only through the planner-level `dynamic_filter.yaml` test.
+    #[tokio::test]
+    async fn test_dynamic_filter_between() -> StreamExecutorResult<()> {
+        let chunk_l1 = StreamChunk::from_pretty(
+            "  I
+             +  1
+             +  2
+             +  3
+             +  4
+             +  5
+             +  6
+             +  7
+             +  8
+             +  9
+             + 10",
+        );
+        // RHS row is (lower, upper); the right-hand "table" only ever holds one row at a time.
+        let chunk_r1 = StreamChunk::from_pretty(
+            "  I I
+             +  3 7",
+        );
+        let chunk_r2 = StreamChunk::from_pretty(
+            "  I I
+             -  3 7
+             +  5 9",
+        );
+
+        let mem_store = MemoryStateStore::new();
+        let (mut tx_l, mut tx_r, mut dynamic_filter) =
+            create_between_executor(mem_store).await;
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(test_epoch(1), false);
+        tx_r.push_barrier(test_epoch(1), false);
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // push the left rows before the window is known: nothing should be emitted yet.
+        tx_l.push_chunk(chunk_l1);
+
+        // narrow the window in from both sides: [3, 7]
+        tx_r.push_chunk(chunk_r1);
+
+        tx_l.push_barrier(test_epoch(2), false);
+        tx_r.push_barrier(test_epoch(2), false);
+
+        let chunk = dynamic_filter.next_unwrap_ready_chunk()?.compact();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I
+                + 3
+                + 4
+                + 5
+                + 6
+                + 7"
+            )
+        );
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // shift the window to [5, 9]: the lower bound rises (3, 4 leave) while the upper bound
+        // rises too (8, 9 enter).
+        tx_r.push_chunk(chunk_r2);
+
+        tx_l.push_barrier(test_epoch(3), false);
+        tx_r.push_barrier(test_epoch(3), false);
+
+        let chunk = dynamic_filter.next_unwrap_ready_chunk()?.compact();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I
+                - 3
+                - 4
+                + 8
+                + 9"
+            )
+        );
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        Ok(())
+    }
 }
@@ -845,6 +845,11 @@ impl<S: StateStore, SD: ValueRowSerde> MaterializeExecutor<S, SD> {
                         && cache_may_stale
                     {
                         self.materialize_cache.lru_cache.clear();
+                        self.local_barrier_manager.report_table_cache_stale(
+                            b_epoch,
+                            self.actor_context.id,
+                            self.state_table.table_id(),
+                        );
                     }
 
                     // Handle staging table post commit
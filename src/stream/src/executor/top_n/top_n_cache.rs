@@ -267,7 +267,7 @@ impl<const WITH_TIES: bool> TopNCache<WITH_TIES> {
         self.high.len() >= self.high_cache_capacity
     }
 
-    fn high_is_synced(&self) -> bool {
+    pub(super) fn high_is_synced(&self) -> bool {
         if !self.high.is_empty() {
             true
         } else {
@@ -278,7 +278,7 @@ impl<const WITH_TIES: bool> TopNCache<WITH_TIES> {
         }
     }
 
-    fn last_cache_key_before_high(&self) -> Option<&CacheKey> {
+    pub(super) fn last_cache_key_before_high(&self) -> Option<&CacheKey> {
         let middle_last_key = self.middle.last_key_value().map(|(k, _)| k);
         middle_last_key.or_else(|| {
             self.low
@@ -525,4 +525,5 @@ mod tests {
             .await
             .unwrap();
     }
+
 }
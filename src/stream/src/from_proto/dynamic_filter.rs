@@ -40,7 +40,29 @@ impl ExecutorBuilder for DynamicFilterExecutorBuilder {
 
         let prost_condition = node.get_condition()?;
         let comparator = prost_condition.get_function_type()?;
-        if !matches!(
+
+        let upper_comparator = node
+            .upper_condition
+            .as_ref()
+            .map(|c| c.get_function_type())
+            .transpose()?;
+
+        if let Some(upper_comparator) = upper_comparator {
+            // `condition` is the lower bound and `upper_condition` is the upper bound of a
+            // BETWEEN-style predicate.
+            if !matches!(comparator, GreaterThan | GreaterThanOrEqual) {
+                bail!(
+                    "`DynamicFilterExecutor`'s lower bound only supports comparators:\
+                    GreaterThan | GreaterThanOrEqual",
+                );
+            }
+            if !matches!(upper_comparator, LessThan | LessThanOrEqual) {
+                bail!(
+                    "`DynamicFilterExecutor`'s upper bound only supports comparators:\
+                    LessThan | LessThanOrEqual",
+                );
+            }
+        } else if !matches!(
             comparator,
             GreaterThan | GreaterThanOrEqual | LessThan | LessThanOrEqual
         ) {
@@ -72,6 +94,7 @@ impl ExecutorBuilder for DynamicFilterExecutorBuilder {
                 source_r,
                 key_l,
                 comparator,
+                upper_comparator,
                 state_table_l,
                 state_table_r,
                 params.executor_stats,
@@ -93,6 +116,7 @@ impl ExecutorBuilder for DynamicFilterExecutorBuilder {
                 source_r,
                 key_l,
                 comparator,
+                upper_comparator,
                 state_table_l,
                 state_table_r,
                 params.executor_stats,
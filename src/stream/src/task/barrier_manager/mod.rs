@@ -67,6 +67,11 @@ pub(super) enum LocalBarrierEvent {
         epoch: EpochPair,
         state: CdcTableBackfillState,
     },
+    ReportTableCacheStale {
+        epoch: EpochPair,
+        actor_id: ActorId,
+        table_id: u32,
+    },
 }
 
 /// Can send [`LocalBarrierEvent`] to [`super::barrier_worker::managed_state::DatabaseManagedBarrierState::poll_next_event`]
@@ -192,6 +197,16 @@ impl LocalBarrierManager {
             staging_table_id,
         });
     }
+
+    /// Report that a state table observed `cache_may_stale` while updating its vnode bitmap,
+    /// so that the count can be surfaced via `MetaMetrics::state_table_cache_stale_total`.
+    pub fn report_table_cache_stale(&self, epoch: EpochPair, actor_id: ActorId, table_id: u32) {
+        self.send_event(LocalBarrierEvent::ReportTableCacheStale {
+            epoch,
+            actor_id,
+            table_id,
+        });
+    }
 }
 
 #[cfg(test)]
@@ -71,6 +71,9 @@ enum ManagedBarrierStateInner {
         cdc_table_backfill_progress: Vec<PbCdcTableBackfillProgress>,
         truncate_tables: Vec<u32>,
         refresh_finished_tables: Vec<u32>,
+        table_cache_stale_stats: Vec<(u32, u32)>,
+        /// Time from barrier injection until all actors of this partial graph collected it.
+        barrier_inflight_latency_secs: f64,
     },
 }
 
@@ -330,6 +333,10 @@ pub(crate) struct PartialGraphManagedBarrierState {
     /// Used for materialized view refresh completion reporting.
     pub(crate) refresh_finished_tables: HashMap<u64, HashSet<u32>>,
 
+    /// Record the number of `cache_may_stale` events observed by state tables, keyed by epoch
+    /// and then by table id, for each epoch of concurrent checkpoints.
+    pub(crate) table_cache_stale_stats: HashMap<u64, HashMap<u32, u32>>,
+
     state_store: StateStoreImpl,
 
     streaming_metrics: Arc<StreamingMetrics>,
@@ -353,6 +360,7 @@ impl PartialGraphManagedBarrierState {
             cdc_table_backfill_progress: Default::default(),
             truncate_tables: Default::default(),
             refresh_finished_tables: Default::default(),
+            table_cache_stale_stats: Default::default(),
             state_store,
             streaming_metrics,
         }
@@ -1014,6 +1022,13 @@ impl DatabaseManagedBarrierState {
                 } => {
                     self.update_cdc_table_backfill_progress(epoch, actor_id, state);
                 }
+                LocalBarrierEvent::ReportTableCacheStale {
+                    epoch,
+                    actor_id,
+                    table_id,
+                } => {
+                    self.report_table_cache_stale(epoch, actor_id, table_id);
+                }
             }
         }
 
@@ -1168,6 +1183,29 @@ impl DatabaseManagedBarrierState {
             .or_default()
             .insert(staging_table_id);
     }
+
+    /// Report that a state table observed `cache_may_stale` for a specific epoch.
+    pub(super) fn report_table_cache_stale(
+        &mut self,
+        epoch: EpochPair,
+        actor_id: ActorId,
+        table_id: u32,
+    ) {
+        // Find the correct partial graph state by matching the actor's partial graph id
+        if let Some(actor_state) = self.actor_states.get(&actor_id)
+            && let Some(partial_graph_id) = actor_state.inflight_barriers.get(&epoch.prev)
+            && let Some(graph_state) = self.graph_states.get_mut(partial_graph_id)
+        {
+            *graph_state
+                .table_cache_stale_stats
+                .entry(epoch.curr)
+                .or_default()
+                .entry(table_id)
+                .or_default() += 1;
+        } else {
+            warn!(?epoch, actor_id, table_id, "ignore table cache stale report");
+        }
+    }
 }
 
 impl PartialGraphManagedBarrierState {
@@ -1226,6 +1264,12 @@ impl PartialGraphManagedBarrierState {
                 .unwrap_or_default()
                 .into_iter()
                 .collect();
+            let table_cache_stale_stats = self
+                .table_cache_stale_stats
+                .remove(&barrier_state.barrier.epoch.curr)
+                .unwrap_or_default()
+                .into_iter()
+                .collect();
             let prev_state = replace(
                 &mut barrier_state.inner,
                 ManagedBarrierStateInner::AllCollected {
@@ -1234,14 +1278,23 @@ impl PartialGraphManagedBarrierState {
                     truncate_tables,
                     refresh_finished_tables,
                     cdc_table_backfill_progress,
+                    table_cache_stale_stats,
+                    // Filled in below once the `Issued` state is extracted.
+                    barrier_inflight_latency_secs: 0.0,
                 },
             );
 
-            must_match!(prev_state, ManagedBarrierStateInner::Issued(IssuedState {
+            let barrier_inflight_latency_secs = must_match!(prev_state, ManagedBarrierStateInner::Issued(IssuedState {
                 barrier_inflight_latency: timer,
                 ..
             }) => {
-                timer.observe_duration();
+                timer.stop_and_record()
+            });
+            must_match!(&mut barrier_state.inner, ManagedBarrierStateInner::AllCollected {
+                barrier_inflight_latency_secs: secs,
+                ..
+            } => {
+                *secs = barrier_inflight_latency_secs;
             });
 
             return Some(barrier_state.barrier.clone());
@@ -1263,14 +1316,18 @@ impl PartialGraphManagedBarrierState {
             cdc_table_backfill_progress,
             truncate_tables,
             refresh_finished_tables,
+            table_cache_stale_stats,
+            barrier_inflight_latency_secs,
         ) = must_match!(barrier_state.inner, ManagedBarrierStateInner::AllCollected {
             create_mview_progress,
             load_finished_source_ids,
             truncate_tables,
             refresh_finished_tables,
             cdc_table_backfill_progress,
+            table_cache_stale_stats,
+            barrier_inflight_latency_secs,
         } => {
-            (create_mview_progress, load_finished_source_ids, cdc_table_backfill_progress, truncate_tables, refresh_finished_tables)
+            (create_mview_progress, load_finished_source_ids, cdc_table_backfill_progress, truncate_tables, refresh_finished_tables, table_cache_stale_stats, barrier_inflight_latency_secs)
         });
         BarrierToComplete {
             barrier: barrier_state.barrier,
@@ -1280,6 +1337,8 @@ impl PartialGraphManagedBarrierState {
             truncate_tables,
             refresh_finished_tables,
             cdc_table_backfill_progress,
+            table_cache_stale_stats,
+            barrier_inflight_latency_secs,
         }
     }
 }
@@ -1292,6 +1351,8 @@ pub(crate) struct BarrierToComplete {
     pub truncate_tables: Vec<u32>,
     pub refresh_finished_tables: Vec<u32>,
     pub cdc_table_backfill_progress: Vec<PbCdcTableBackfillProgress>,
+    pub table_cache_stale_stats: Vec<(u32, u32)>,
+    pub barrier_inflight_latency_secs: f64,
 }
 
 impl PartialGraphManagedBarrierState {
@@ -93,6 +93,12 @@ pub struct BarrierCompleteResult {
     pub truncate_tables: Vec<u32>,
     /// The table IDs that have finished refresh.
     pub refresh_finished_tables: Vec<u32>,
+    /// The number of `cache_may_stale` events observed by state tables, keyed by table id.
+    pub table_cache_stale_stats: Vec<(u32, u32)>,
+    /// Time from barrier injection until all actors of this partial graph collected it.
+    pub barrier_inflight_latency_secs: f64,
+    /// State store sync latency for this epoch, `Some` only for checkpoint barriers.
+    pub barrier_sync_latency_secs: Option<f64>,
 }
 
 /// Lives in [`crate::task::barrier_worker::LocalBarrierWorker`],
@@ -639,7 +645,7 @@ mod await_epoch_completed_future {
     #[expect(clippy::too_many_arguments)]
     pub(super) fn instrument_complete_barrier_future(
         partial_graph_id: PartialGraphId,
-        complete_barrier_future: Option<BoxFuture<'static, StreamResult<SyncResult>>>,
+        complete_barrier_future: Option<BoxFuture<'static, StreamResult<(SyncResult, f64)>>>,
         barrier: Barrier,
         barrier_await_tree_reg: Option<&await_tree::Registry>,
         create_mview_progress: Vec<PbCreateMviewProgress>,
@@ -647,6 +653,8 @@ mod await_epoch_completed_future {
         cdc_table_backfill_progress: Vec<PbCdcTableBackfillProgress>,
         truncate_tables: Vec<u32>,
         refresh_finished_tables: Vec<u32>,
+        table_cache_stale_stats: Vec<(u32, u32)>,
+        barrier_inflight_latency_secs: f64,
     ) -> AwaitEpochCompletedFuture {
         let prev_epoch = barrier.epoch.prev;
         let future = async move {
@@ -661,13 +669,22 @@ mod await_epoch_completed_future {
             (
                 partial_graph_id,
                 barrier,
-                result.map(|sync_result| BarrierCompleteResult {
-                    sync_result,
-                    create_mview_progress,
-                    load_finished_source_ids,
-                    cdc_table_backfill_progress,
-                    truncate_tables,
-                    refresh_finished_tables,
+                result.map(|sync_result| {
+                    let (sync_result, barrier_sync_latency_secs) = match sync_result {
+                        Some((sync_result, secs)) => (Some(sync_result), Some(secs)),
+                        None => (None, None),
+                    };
+                    BarrierCompleteResult {
+                        sync_result,
+                        create_mview_progress,
+                        load_finished_source_ids,
+                        cdc_table_backfill_progress,
+                        truncate_tables,
+                        refresh_finished_tables,
+                        table_cache_stale_stats,
+                        barrier_inflight_latency_secs,
+                        barrier_sync_latency_secs,
+                    }
                 }),
             )
         });
@@ -696,7 +713,7 @@ fn sync_epoch(
     streaming_metrics: &StreamingMetrics,
     prev_epoch: u64,
     table_ids: HashSet<TableId>,
-) -> BoxFuture<'static, StreamResult<SyncResult>> {
+) -> BoxFuture<'static, StreamResult<(SyncResult, f64)>> {
     let timer = streaming_metrics.barrier_sync_latency.start_timer();
 
     let state_store = state_store.clone();
@@ -708,9 +725,7 @@ fn sync_epoch(
 
     future
         .instrument_await(await_tree::span!("sync_epoch (epoch {})", prev_epoch))
-        .inspect_ok(move |_| {
-            timer.observe_duration();
-        })
+        .map_ok(move |sync_result| (sync_result, timer.stop_and_record()))
         .map_err(move |e| {
             tracing::error!(
                 prev_epoch,
@@ -747,6 +762,8 @@ impl LocalBarrierWorker {
                 cdc_table_backfill_progress,
                 truncate_tables,
                 refresh_finished_tables,
+                table_cache_stale_stats,
+                barrier_inflight_latency_secs,
             } = database_state.pop_barrier_to_complete(partial_graph_id, prev_epoch);
 
             let complete_barrier_future = match &barrier.kind {
@@ -782,6 +799,8 @@ impl LocalBarrierWorker {
                         cdc_table_backfill_progress,
                         truncate_tables,
                         refresh_finished_tables,
+                        table_cache_stale_stats,
+                        barrier_inflight_latency_secs,
                     )
                 });
         }
@@ -801,6 +820,9 @@ impl LocalBarrierWorker {
             cdc_table_backfill_progress,
             truncate_tables,
             refresh_finished_tables,
+            table_cache_stale_stats,
+            barrier_inflight_latency_secs,
+            barrier_sync_latency_secs,
         } = result;
 
         let (synced_sstables, table_watermarks, old_value_ssts, vector_index_adds) = sync_result
@@ -862,6 +884,9 @@ impl LocalBarrierWorker {
                         cdc_table_backfill_progress,
                         truncate_tables,
                         refresh_finished_tables,
+                        table_cache_stale_stats: table_cache_stale_stats.into_iter().collect(),
+                        barrier_inflight_latency_secs: Some(barrier_inflight_latency_secs),
+                        barrier_sync_latency_secs,
                     },
                 )
             }